@@ -5,17 +5,35 @@ use tendermint_proto::Protobuf;
 
 use crate::downcast;
 use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::error::{Error, Kind};
 use crate::ics02_client::header::Header;
+use crate::ics02_client::light_client::{
+    RegisteredClient, RegisteredClientState, RegisteredConsensusState, RegisteredHeader,
+};
+use crate::ics02_client::misbehaviour::Misbehaviour;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_def::SoloMachineClient;
+use crate::ics06_solomachine::client_state::ClientState as SoloMachineClientState;
+use crate::ics06_solomachine::consensus_state::ConsensusState as SoloMachineConsensusState;
+use crate::ics06_solomachine::header::Header as SoloMachineHeader;
+use crate::ics06_solomachine::misbehaviour::Misbehaviour as SoloMachineMisbehaviour;
 use crate::ics07_tendermint as tendermint;
 use crate::ics07_tendermint::client_def::TendermintClient;
 use crate::ics07_tendermint::client_state::ClientState as TendermintClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState as TendermintConsensusState;
 use crate::ics07_tendermint::header::Header as TendermintHeader;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour as TendermintMisbehaviour;
+use crate::ics09_localhost::client_def::LocalhostClient;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState as LocalhostConsensusState;
+use crate::ics09_localhost::header::Header as LocalhostHeader;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
-use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[cfg(any(test, feature = "mocks"))]
@@ -23,32 +41,79 @@ use crate::mock::{
     client_def::MockClient,
     client_state::{MockClientState, MockConsensusState},
     header::MockHeader,
+    misbehaviour::MockMisbehaviour,
 };
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str =
     "/ibc.lightclients.tendermint.v1.ConsensusState";
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
+pub const TENDERMINT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Misbehaviour";
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.ClientState";
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v1.ConsensusState";
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.Header";
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v1.Misbehaviour";
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v1.ClientState";
+pub const LOCALHOST_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.localhost.v1.ConsensusState";
+pub const LOCALHOST_HEADER_TYPE_URL: &str = "/ibc.lightclients.localhost.v1.Header";
 
 pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 pub const MOCK_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.mock.ConsensusState";
 pub const MOCK_HEADER_TYPE_URL: &str = "/ibc.mock.Header";
+pub const MOCK_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.mock.Misbehaviour";
 
 pub trait ClientDef: Clone {
     type Header: Header;
+    type Misbehaviour: Misbehaviour;
     type ClientState: ClientState;
     type ConsensusState: ConsensusState;
 
-    /// TODO
+    /// Verifies an incoming `header` against the consensus states stored by the client `client_id`
+    /// in `ctx`, and returns the updated client state along with the consensus state to be stored
+    /// for the height of `header`.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
 
+    /// Verifies that `misbehaviour` is valid evidence of misbehaviour of the chain tracked by the
+    /// client `client_id`, and returns the client state frozen at the height of the misbehaviour.
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>>;
+
+    /// Verifies that the counterparty chain committed `upgraded_client_state` under the upgrade
+    /// path of the client `client_id`, in its state at `upgrade_height` (i.e., the last height
+    /// before the upgrade). Returns the upgraded client state, along with the consensus state to
+    /// be stored for its latest height. `MsgUpgradeClient` carries no upgraded consensus state, so
+    /// only the upgraded client state is proven.
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgrade_height: Height,
+        proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>>;
+
     /// Verification functions as specified in:
     /// https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics
     ///
+    /// All proofs are verified against `root`, i.e., the commitment root of the consensus state that
+    /// the client stores for `height`.
+    ///
     /// Verify a `proof` that the consensus state of a given client (at height `consensus_height`)
     /// matches the input `consensus_state`. The parameter `counterparty_height` represent the
     /// height of the counterparty chain that this proof assumes (i.e., the height at which this
@@ -58,6 +123,7 @@ pub trait ClientDef: Clone {
         &self,
         client_state: &Self::ClientState,
         height: Height,
+        root: &CommitmentRoot,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProof,
         client_id: &ClientId,
@@ -66,16 +132,95 @@ pub trait ClientDef: Clone {
     ) -> Result<(), Box<dyn std::error::Error>>;
 
     /// Verify a `proof` that a connection state matches that of the input `connection_end`.
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
         client_state: &Self::ClientState,
         height: Height,
+        root: &CommitmentRoot,
         prefix: &CommitmentPrefix,
         proof: &CommitmentProof,
         connection_id: &ConnectionId,
         expected_connection_end: &ConnectionEnd,
     ) -> Result<(), Box<dyn std::error::Error>>;
 
+    /// Verify a `proof` that a channel state matches that of the input `channel_end`.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that a packet with the given `sequence` was committed to on the
+    /// counterparty chain, i.e., that the packet `commitment` is stored there under the given
+    /// `port_id` and `channel_id`.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that the counterparty chain stored the acknowledgement `ack_commitment`
+    /// for the packet with the given `sequence`.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that the next sequence the counterparty chain expects to receive on an
+    /// ordered channel is `next_sequence_recv`.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// Verify a `proof` that the counterparty chain has no receipt for the packet with the given
+    /// `sequence`, i.e., that it did not receive this packet on an unordered channel.
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+
     /// Verify the client state for this chain that it is stored on the counterparty chain.
     #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
@@ -94,6 +239,9 @@ pub trait ClientDef: Clone {
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(tendermint::header::Header),
+    SoloMachine(SoloMachineHeader),
+    Localhost(LocalhostHeader),
+    Registered(RegisteredHeader),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockHeader),
@@ -103,6 +251,9 @@ impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::SoloMachine(header) => header.client_type(),
+            Self::Localhost(header) => header.client_type(),
+            Self::Registered(header) => header.client_type(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(header) => header.client_type(),
@@ -112,6 +263,9 @@ impl Header for AnyHeader {
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::SoloMachine(header) => header.height(),
+            Self::Localhost(header) => header.height(),
+            Self::Registered(header) => header.height(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(header) => header.height(),
@@ -135,6 +289,13 @@ impl TryFrom<Any> for AnyHeader {
                     .map_err(|e| Kind::InvalidRawHeader.context(e))?,
             )),
 
+            SOLOMACHINE_HEADER_TYPE_URL => Ok(AnyHeader::SoloMachine(
+                SoloMachineHeader::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawHeader.context(e))?,
+            )),
+
+            LOCALHOST_HEADER_TYPE_URL => Ok(AnyHeader::Localhost(LocalhostHeader)),
+
             #[cfg(any(test, feature = "mocks"))]
             MOCK_HEADER_TYPE_URL => Ok(AnyHeader::Mock(
                 MockHeader::decode_vec(&raw.value)
@@ -153,6 +314,18 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: header.encode_vec().unwrap(),
             },
+            AnyHeader::SoloMachine(header) => Any {
+                type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+                value: header.encode_vec().unwrap(),
+            },
+            AnyHeader::Localhost(_) => Any {
+                type_url: LOCALHOST_HEADER_TYPE_URL.to_string(),
+                value: Vec::new(),
+            },
+            AnyHeader::Registered(header) => Any {
+                type_url: header.type_url().to_string(),
+                value: header.value().to_vec(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyHeader::Mock(header) => Any {
                 type_url: MOCK_HEADER_TYPE_URL.to_string(),
@@ -162,9 +335,106 @@ impl From<AnyHeader> for Any {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum AnyMisbehaviour {
+    Tendermint(TendermintMisbehaviour),
+    SoloMachine(SoloMachineMisbehaviour),
+
+    #[cfg(any(test, feature = "mocks"))]
+    Mock(MockMisbehaviour),
+}
+
+impl Misbehaviour for AnyMisbehaviour {
+    fn client_type(&self) -> ClientType {
+        match self {
+            Self::Tendermint(misbehaviour) => misbehaviour.client_type(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.client_type(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(misbehaviour) => misbehaviour.client_type(),
+        }
+    }
+
+    fn client_id(&self) -> &ClientId {
+        match self {
+            Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.client_id(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(misbehaviour) => misbehaviour.client_id(),
+        }
+    }
+
+    fn height(&self) -> Height {
+        match self {
+            Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::SoloMachine(misbehaviour) => misbehaviour.height(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(misbehaviour) => misbehaviour.height(),
+        }
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        self
+    }
+}
+
+impl Protobuf<Any> for AnyMisbehaviour {}
+
+impl TryFrom<Any> for AnyMisbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Tendermint(
+                TendermintMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::SoloMachine(
+                SoloMachineMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+
+            #[cfg(any(test, feature = "mocks"))]
+            MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
+                MockMisbehaviour::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            )),
+
+            _ => Err(Kind::UnknownMisbehaviourType(raw.type_url).into()),
+        }
+    }
+}
+
+impl From<AnyMisbehaviour> for Any {
+    fn from(value: AnyMisbehaviour) -> Self {
+        match value {
+            AnyMisbehaviour::Tendermint(misbehaviour) => Any {
+                type_url: TENDERMINT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+            AnyMisbehaviour::SoloMachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+            #[cfg(any(test, feature = "mocks"))]
+            AnyMisbehaviour::Mock(misbehaviour) => Any {
+                type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec().unwrap(),
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyClientState {
     Tendermint(TendermintClientState),
+    SoloMachine(SoloMachineClientState),
+    Localhost(LocalhostClientState),
+    Registered(RegisteredClientState),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClientState),
@@ -174,6 +444,9 @@ impl AnyClientState {
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::SoloMachine(state) => state.latest_height(),
+            Self::Localhost(state) => state.latest_height(),
+            Self::Registered(state) => state.latest_height(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(mock_state) => mock_state.latest_height(),
@@ -182,11 +455,23 @@ impl AnyClientState {
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::SoloMachine(state) => state.client_type(),
+            Self::Localhost(state) => state.client_type(),
+            Self::Registered(state) => state.client_type(),
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(state) => state.client_type(),
         }
     }
+
+    /// The prefix of the identifiers of the clients of this state: the one of the built-in client
+    /// type, or the type supplied by the registered client.
+    pub fn client_id_prefix(&self) -> &str {
+        match self {
+            Self::Registered(state) => state.client_id_prefix(),
+            _ => ClientId::prefix(self.client_type()).unwrap_or_default(),
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -202,6 +487,16 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(|e| Kind::InvalidRawClientState.context(e))?,
             )),
 
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::SoloMachine(
+                SoloMachineClientState::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            )),
+
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Localhost(
+                LocalhostClientState::decode_vec(&raw.value)
+                    .map_err(|e| Kind::InvalidRawClientState.context(e))?,
+            )),
+
             #[cfg(any(test, feature = "mocks"))]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 MockClientState::decode_vec(&raw.value)
@@ -220,6 +515,18 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: value.encode_vec().unwrap(),
             },
+            AnyClientState::SoloMachine(value) => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
+            AnyClientState::Localhost(value) => Any {
+                type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
+            AnyClientState::Registered(value) => Any {
+                type_url: value.type_url().to_string(),
+                value: value.value().to_vec(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
@@ -245,6 +552,9 @@ impl ClientState for AnyClientState {
     fn is_frozen(&self) -> bool {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.is_frozen(),
+            AnyClientState::SoloMachine(state) => state.is_frozen(),
+            AnyClientState::Localhost(state) => state.is_frozen(),
+            AnyClientState::Registered(state) => state.is_frozen(),
 
             #[cfg(any(test, feature = "mocks"))]
             AnyClientState::Mock(mock_state) => mock_state.is_frozen(),
@@ -259,6 +569,9 @@ impl ClientState for AnyClientState {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyConsensusState {
     Tendermint(crate::ics07_tendermint::consensus_state::ConsensusState),
+    SoloMachine(SoloMachineConsensusState),
+    Localhost(LocalhostConsensusState),
+    Registered(RegisteredConsensusState),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockConsensusState),
@@ -268,6 +581,9 @@ impl AnyConsensusState {
     pub fn client_type(&self) -> ClientType {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
+            AnyConsensusState::SoloMachine(_cs) => ClientType::SoloMachine,
+            AnyConsensusState::Localhost(_cs) => ClientType::Localhost,
+            AnyConsensusState::Registered(_cs) => ClientType::Registered,
 
             #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
@@ -287,6 +603,15 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
             )),
 
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::SoloMachine(
+                SoloMachineConsensusState::decode_vec(&value.value)
+                    .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
+            )),
+
+            LOCALHOST_CONSENSUS_STATE_TYPE_URL => {
+                Ok(AnyConsensusState::Localhost(LocalhostConsensusState))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 MockConsensusState::decode_vec(&value.value)
@@ -305,6 +630,18 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: value.encode_vec().unwrap(),
             },
+            AnyConsensusState::SoloMachine(value) => Any {
+                type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: value.encode_vec().unwrap(),
+            },
+            AnyConsensusState::Localhost(_) => Any {
+                type_url: LOCALHOST_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Vec::new(),
+            },
+            AnyConsensusState::Registered(value) => Any {
+                type_url: value.type_url().to_string(),
+                value: value.value().to_vec(),
+            },
             #[cfg(any(test, feature = "mocks"))]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
@@ -320,7 +657,27 @@ impl ConsensusState for AnyConsensusState {
     }
 
     fn root(&self) -> &CommitmentRoot {
-        todo!()
+        match self {
+            AnyConsensusState::Tendermint(cs) => cs.root(),
+            AnyConsensusState::SoloMachine(cs) => cs.root(),
+            AnyConsensusState::Localhost(cs) => cs.root(),
+            AnyConsensusState::Registered(cs) => cs.root(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyConsensusState::Mock(cs) => cs.root(),
+        }
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        match self {
+            AnyConsensusState::Tendermint(cs) => cs.timestamp(),
+            AnyConsensusState::SoloMachine(cs) => cs.timestamp(),
+            AnyConsensusState::Localhost(cs) => cs.timestamp(),
+            AnyConsensusState::Registered(cs) => cs.timestamp(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyConsensusState::Mock(cs) => cs.timestamp(),
+        }
     }
 
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -335,18 +692,27 @@ impl ConsensusState for AnyConsensusState {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AnyClient {
     Tendermint(TendermintClient),
+    SoloMachine(SoloMachineClient),
+    Localhost(LocalhostClient),
+    Registered(RegisteredClient),
 
     #[cfg(any(test, feature = "mocks"))]
     Mock(MockClient),
 }
 
 impl AnyClient {
-    pub fn from_client_type(client_type: ClientType) -> AnyClient {
-        match client_type {
-            ClientType::Tendermint => Self::Tendermint(TendermintClient),
+    /// Returns the definition of the client whose state is `client_state`.
+    pub fn from_client_state(client_state: &AnyClientState) -> AnyClient {
+        match client_state {
+            AnyClientState::Tendermint(_) => Self::Tendermint(TendermintClient),
+            AnyClientState::SoloMachine(_) => Self::SoloMachine(SoloMachineClient),
+            AnyClientState::Localhost(_) => Self::Localhost(LocalhostClient),
+            AnyClientState::Registered(state) => {
+                Self::Registered(RegisteredClient::new(state.client()))
+            }
 
             #[cfg(any(test, feature = "mocks"))]
-            ClientType::Mock => Self::Mock(MockClient),
+            AnyClientState::Mock(_) => Self::Mock(MockClient),
         }
     }
 }
@@ -354,12 +720,15 @@ impl AnyClient {
 // ⚠️  Beware of the awful boilerplate below ⚠️
 impl ClientDef for AnyClient {
     type Header = AnyHeader;
+    type Misbehaviour = AnyMisbehaviour;
     type ClientState = AnyClientState;
     type ConsensusState = AnyConsensusState;
 
-    /// Validates an incoming `header` against the latest consensus state of this client.
+    /// Validates an incoming `header` against the trusted consensus states of this client.
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: AnyClientState,
         header: AnyHeader,
     ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
@@ -372,7 +741,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
 
                 Ok((
                     AnyClientState::Tendermint(new_state),
@@ -380,6 +749,54 @@ impl ClientDef for AnyClient {
                 ))
             }
 
+            Self::SoloMachine(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    header => AnyHeader::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::Localhost,
+                    header => AnyHeader::Localhost,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::Localhost(new_state),
+                    AnyConsensusState::Localhost(new_consensus),
+                ))
+            }
+
+            Self::Registered(client) => {
+                let (client_state, header) = downcast!(
+                    client_state => AnyClientState::Registered,
+                    header => AnyHeader::Registered,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                let (new_state, new_consensus) =
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
+
+                Ok((
+                    AnyClientState::Registered(new_state),
+                    AnyConsensusState::Registered(new_consensus),
+                ))
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let (client_state, header) = downcast!(
@@ -389,7 +806,7 @@ impl ClientDef for AnyClient {
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
                 let (new_state, new_consensus) =
-                    client.check_header_and_update_state(client_state, header)?;
+                    client.check_header_and_update_state(ctx, client_id, client_state, header)?;
 
                 Ok((
                     AnyClientState::Mock(new_state),
@@ -399,95 +816,910 @@ impl ClientDef for AnyClient {
         }
     }
 
-    fn verify_client_consensus_state(
+    /// Validates the `misbehaviour` evidence against the trusted consensus states of this client.
+    fn check_misbehaviour_and_update_state(
         &self,
-        client_state: &Self::ClientState,
-        height: Height,
-        prefix: &CommitmentPrefix,
-        proof: &CommitmentProof,
-        client_id: &ClientId,
-        consensus_height: Height,
-        expected_consensus_state: &AnyConsensusState,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        misbehaviour: AnyMisbehaviour,
+    ) -> Result<AnyClientState, Box<dyn std::error::Error>> {
         match self {
             Self::Tendermint(client) => {
-                let client_state = downcast!(
-                    client_state => AnyClientState::Tendermint
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    misbehaviour => AnyMisbehaviour::Tendermint,
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_client_consensus_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
                     client_id,
-                    consensus_height,
-                    expected_consensus_state,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Tendermint(new_state))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    misbehaviour => AnyMisbehaviour::SoloMachine,
                 )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::SoloMachine(new_state))
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Localhost(new_state))
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Registered(new_state))
             }
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
-                let client_state = downcast!(
-                    client_state => AnyClientState::Mock
+                let (client_state, misbehaviour) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    misbehaviour => AnyMisbehaviour::Mock,
                 )
                 .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_client_consensus_state(
-                    client_state,
-                    height,
-                    prefix,
-                    proof,
+                let new_state = client.check_misbehaviour_and_update_state(
+                    ctx,
                     client_id,
-                    consensus_height,
-                    expected_consensus_state,
-                )
+                    client_state,
+                    misbehaviour,
+                )?;
+
+                Ok(AnyClientState::Mock(new_state))
             }
         }
     }
 
-    fn verify_connection_state(
+    /// Validates the upgrade of this client against its consensus state at `upgrade_height`.
+    fn verify_upgrade_and_update_state(
         &self,
-        client_state: &AnyClientState,
-        height: Height,
-        prefix: &CommitmentPrefix,
-        proof: &CommitmentProof,
-        connection_id: &ConnectionId,
-        expected_connection_end: &ConnectionEnd,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: AnyClientState,
+        upgraded_client_state: AnyClientState,
+        upgrade_height: Height,
+        proof_upgrade: &CommitmentProof,
+    ) -> Result<(AnyClientState, AnyConsensusState), Box<dyn std::error::Error>> {
         match self {
             Self::Tendermint(client) => {
-                let client_state = downcast!(client_state => AnyClientState::Tendermint)
-                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::Tendermint,
+                    upgraded_client_state => AnyClientState::Tendermint,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
 
-                client.verify_connection_state(
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
                     client_state,
-                    height,
-                    prefix,
-                    proof,
+                    upgraded_client_state,
+                    upgrade_height,
+                    proof_upgrade,
+                )?;
+
+                Ok((
+                    AnyClientState::Tendermint(new_state),
+                    AnyConsensusState::Tendermint(new_consensus),
+                ))
+            }
+
+            Self::SoloMachine(client) => {
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::SoloMachine,
+                    upgraded_client_state => AnyClientState::SoloMachine,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgrade_height,
+                    proof_upgrade,
+                )?;
+
+                Ok((
+                    AnyClientState::SoloMachine(new_state),
+                    AnyConsensusState::SoloMachine(new_consensus),
+                ))
+            }
+
+            Self::Localhost(client) => {
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::Localhost,
+                    upgraded_client_state => AnyClientState::Localhost,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgrade_height,
+                    proof_upgrade,
+                )?;
+
+                Ok((
+                    AnyClientState::Localhost(new_state),
+                    AnyConsensusState::Localhost(new_consensus),
+                ))
+            }
+
+            Self::Registered(client) => {
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::Registered,
+                    upgraded_client_state => AnyClientState::Registered,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgrade_height,
+                    proof_upgrade,
+                )?;
+
+                Ok((
+                    AnyClientState::Registered(new_state),
+                    AnyConsensusState::Registered(new_consensus),
+                ))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let (client_state, upgraded_client_state) = downcast!(
+                    client_state => AnyClientState::Mock,
+                    upgraded_client_state => AnyClientState::Mock,
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                let (new_state, new_consensus) = client.verify_upgrade_and_update_state(
+                    ctx,
+                    client_id,
+                    client_state,
+                    upgraded_client_state,
+                    upgrade_height,
+                    proof_upgrade,
+                )?;
+
+                Ok((
+                    AnyClientState::Mock(new_state),
+                    AnyConsensusState::Mock(new_consensus),
+                ))
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_consensus_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Tendermint
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_client_consensus_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_consensus_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_consensus_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Registered
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_client_consensus_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Mock
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_client_consensus_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    client_id,
+                    consensus_height,
+                    expected_consensus_state,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_connection_state(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_connection_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_connection_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_connection_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
                     connection_id,
                     expected_connection_end,
                 )
             }
 
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_connection_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_connection_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    connection_id,
+                    expected_connection_end,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_channel_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_channel_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_channel_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_channel_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_channel_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    expected_channel_end,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_packet_data(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    commitment,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_data(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    commitment,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_data(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    commitment,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_packet_data(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    commitment,
+                )
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let client_state = downcast!(client_state => AnyClientState::Mock)
                     .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
 
-                client.verify_connection_state(
+                client.verify_packet_data(
                     client_state,
                     height,
+                    root,
                     prefix,
                     proof,
-                    connection_id,
-                    expected_connection_end,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    commitment,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_packet_acknowledgement(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    ack_commitment,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_acknowledgement(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    ack_commitment,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_acknowledgement(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    ack_commitment,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_packet_acknowledgement(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    ack_commitment,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_packet_acknowledgement(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                    ack_commitment,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_next_sequence_recv(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    next_sequence_recv,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_next_sequence_recv(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    next_sequence_recv,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_next_sequence_recv(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    next_sequence_recv,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_next_sequence_recv(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    next_sequence_recv,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_next_sequence_recv(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    next_sequence_recv,
+                )
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        client_state: &AnyClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Tendermint(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Tendermint)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Tendermint))?;
+
+                client.verify_packet_receipt_absence(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                )
+            }
+
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(client_state => AnyClientState::SoloMachine)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_packet_receipt_absence(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Localhost)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_packet_receipt_absence(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Registered)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_packet_receipt_absence(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
+                )
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(client) => {
+                let client_state = downcast!(client_state => AnyClientState::Mock)
+                    .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Mock))?;
+
+                client.verify_packet_receipt_absence(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    proof,
+                    port_id,
+                    channel_id,
+                    sequence,
                 )
             }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
         client_state: &Self::ClientState,
@@ -516,6 +1748,57 @@ impl ClientDef for AnyClient {
                 )
             }
 
+            Self::SoloMachine(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::SoloMachine
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::SoloMachine))?;
+
+                client.verify_client_full_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::Localhost(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Localhost
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Localhost))?;
+
+                client.verify_client_full_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
+            Self::Registered(client) => {
+                let client_state = downcast!(
+                    client_state => AnyClientState::Registered
+                )
+                .ok_or_else(|| Kind::ClientArgsTypeMismatch(ClientType::Registered))?;
+
+                client.verify_client_full_state(
+                    client_state,
+                    height,
+                    root,
+                    prefix,
+                    client_id,
+                    proof,
+                    client_state_on_counterparty,
+                )
+            }
+
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock(client) => {
                 let client_state = downcast!(
@@ -5,6 +5,11 @@ use serde_derive::{Deserialize, Serialize};
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ClientType {
     Tendermint = 1,
+    SoloMachine = 6,
+    Localhost = 9,
+
+    /// The type of all the clients registered by downstream crates, see `ClientRegistry`.
+    Registered,
 
     #[cfg(any(test, feature = "mocks"))]
     Mock = 9999,
@@ -15,6 +20,9 @@ impl ClientType {
     pub fn as_string(&self) -> &'static str {
         match self {
             Self::Tendermint => "Tendermint",
+            Self::SoloMachine => "SoloMachine",
+            Self::Localhost => "Localhost",
+            Self::Registered => "Registered",
 
             #[cfg(any(test, feature = "mocks"))]
             Self::Mock => "mock",
@@ -28,6 +36,9 @@ impl std::str::FromStr for ClientType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "Tendermint" => Ok(Self::Tendermint),
+            "SoloMachine" => Ok(Self::SoloMachine),
+            "Localhost" => Ok(Self::Localhost),
+            "Registered" => Ok(Self::Registered),
 
             #[cfg(any(test, feature = "mocks"))]
             "mock" => Ok(Self::Mock),
@@ -52,6 +63,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_solomachine_client_type() {
+        let client_type = ClientType::from_str("SoloMachine");
+
+        match client_type {
+            Ok(ClientType::SoloMachine) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_localhost_client_type() {
+        let client_type = ClientType::from_str("Localhost");
+
+        match client_type {
+            Ok(ClientType::Localhost) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
+    #[test]
+    fn parse_registered_client_type() {
+        let client_type = ClientType::from_str("Registered");
+
+        match client_type {
+            Ok(ClientType::Registered) => (),
+            _ => panic!("parse failed"),
+        }
+    }
+
     #[test]
     fn parse_mock_client_type() {
         let client_type = ClientType::from_str("mock");
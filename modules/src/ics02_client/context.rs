@@ -6,8 +6,9 @@ use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::error::Error;
 use crate::ics02_client::handler::ClientResult;
-use crate::ics02_client::handler::ClientResult::{Create, Update};
+use crate::ics02_client::handler::ClientResult::{Create, Misbehaviour, Update, Upgrade};
 use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// Defines the read-only part of ICS2 (client functions) context.
@@ -15,6 +16,18 @@ pub trait ClientReader {
     fn client_type(&self, client_id: &ClientId) -> Option<ClientType>;
     fn client_state(&self, client_id: &ClientId) -> Option<AnyClientState>;
     fn consensus_state(&self, client_id: &ClientId, height: Height) -> Option<AnyConsensusState>;
+
+    /// Returns the current timestamp of the host chain, used by light clients to check that
+    /// headers are neither expired nor too far in the future.
+    fn host_timestamp(&self) -> Timestamp;
+
+    /// Returns the current height of the host chain, which loopback clients catch up with upon
+    /// each update.
+    fn host_height(&self) -> Height;
+
+    /// Returns a natural number, counting how many clients have been created thus far.
+    /// The value of this counter should increase only via method `ClientKeeper::increase_client_counter`.
+    fn client_counter(&self) -> u64;
 }
 
 /// Defines the write-only part of ICS2 (client functions) context.
@@ -30,18 +43,61 @@ pub trait ClientKeeper {
             Create(res) => {
                 self.store_client_state(res.client_id.clone(), res.client_state.clone())?;
                 self.store_consensus_state(
-                    res.client_id,
+                    res.client_id.clone(),
                     res.client_state.latest_height(),
                     res.consensus_state,
                 )?;
+                self.store_update_time(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
+                    res.client_id,
+                    res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
+                // The handler generated the identifier of the client out of the counter.
+                self.increase_client_counter();
             }
             Update(res) => {
                 self.store_client_state(res.client_id.clone(), res.client_state.clone())?;
                 self.store_consensus_state(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.consensus_state,
+                )?;
+                self.store_update_time(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
                     res.client_id,
                     res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
+            }
+            Upgrade(res) => {
+                self.store_client_state(res.client_id.clone(), res.client_state.clone())?;
+                self.store_consensus_state(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
                     res.consensus_state,
                 )?;
+                self.store_update_time(
+                    res.client_id.clone(),
+                    res.client_state.latest_height(),
+                    res.processed_time,
+                )?;
+                self.store_update_height(
+                    res.client_id,
+                    res.client_state.latest_height(),
+                    res.processed_height,
+                )?;
+            }
+            Misbehaviour(res) => {
+                self.store_client_state(res.client_id, res.client_state)?;
             }
         }
         Ok(())
@@ -59,4 +115,27 @@ pub trait ClientKeeper {
         height: Height,
         consensus_state: AnyConsensusState,
     ) -> Result<(), Error>;
+
+    /// Records the time of the host chain at which the client got its consensus state at `height`,
+    /// from which the delay period of the connections over this client counts.
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<(), Error>;
+
+    /// Records the height of the host chain at which the client got its consensus state at
+    /// `height`, from which the delay period of the connections over this client counts in blocks.
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), Error>;
+
+    /// Called upon client creation.
+    /// Increases the counter which keeps track of how many clients have been created.
+    /// Should never fail.
+    fn increase_client_counter(&mut self);
 }
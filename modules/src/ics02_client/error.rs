@@ -15,6 +15,9 @@ pub enum Kind {
     #[error("client already exists: {0}")]
     ClientAlreadyExists(ClientId),
 
+    #[error("client identifier constructor failed for type {0} with counter {1}")]
+    ClientIdentifierConstructor(ClientType, u64),
+
     #[error("client not found: {0}")]
     ClientNotFound(ClientId),
 
@@ -27,6 +30,15 @@ pub enum Kind {
     #[error("header verification failed")]
     HeaderVerificationFailure,
 
+    #[error("misbehaviour handling failed")]
+    MisbehaviourHandlingFailure,
+
+    #[error("client upgrade verification failed")]
+    UpgradeVerificationFailure,
+
+    #[error("client is frozen: {0}")]
+    ClientFrozen(ClientId),
+
     #[error("unknown client state type: {0}")]
     UnknownClientStateType(String),
 
@@ -36,6 +48,9 @@ pub enum Kind {
     #[error("unknown header type: {0}")]
     UnknownHeaderType(String),
 
+    #[error("unknown misbehaviour type: {0}")]
+    UnknownMisbehaviourType(String),
+
     #[error("invalid raw client state")]
     InvalidRawClientState,
 
@@ -48,12 +63,24 @@ pub enum Kind {
     #[error("invalid raw header")]
     InvalidRawHeader,
 
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid raw upgrade height")]
+    InvalidRawUpgradeHeight,
+
     #[error("invalid height result")]
     InvalidHeightResult,
 
     #[error("invalid address")]
     InvalidAddress,
 
+    #[error("a light client is already registered for client states of type {0}")]
+    ClientAlreadyRegistered(String),
+
+    #[error("the light client for client states of type {0} does not support this operation")]
+    UnsupportedClientOperation(String),
+
     #[error("mismatch between client and arguments types, expected: {0:?}")]
     ClientArgsTypeMismatch(ClientType),
 
@@ -8,18 +8,24 @@ use crate::ics24_host::identifier::ClientId;
 use crate::ics02_client::context::ClientReader;
 
 pub mod create_client;
+pub mod misbehaviour;
 pub mod update_client;
+pub mod upgrade_client;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ClientEvent {
     ClientCreated(ClientId),
     ClientUpdated(ClientId),
+    ClientUpgraded(ClientId),
+    ClientMisbehaviour(ClientId),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ClientResult {
     Create(create_client::Result),
     Update(update_client::Result),
+    Upgrade(upgrade_client::Result),
+    Misbehaviour(misbehaviour::Result),
 }
 
 impl From<ClientEvent> for Event {
@@ -33,6 +39,14 @@ impl From<ClientEvent> for Event {
                 EventType::Custom("ClientUpdated".to_string()),
                 vec![("client_id".to_string(), client_id.to_string())],
             ),
+            ClientEvent::ClientUpgraded(client_id) => Event::new(
+                EventType::Custom("ClientUpgraded".to_string()),
+                vec![("client_id".to_string(), client_id.to_string())],
+            ),
+            ClientEvent::ClientMisbehaviour(client_id) => Event::new(
+                EventType::Custom("ClientMisbehaviour".to_string()),
+                vec![("client_id".to_string(), client_id.to_string())],
+            ),
         }
     }
 }
@@ -45,5 +59,7 @@ where
     Ok(match msg {
         ClientMsg::CreateClient(msg) => create_client::process(ctx, msg)?,
         ClientMsg::UpdateClient(msg) => update_client::process(ctx, msg)?,
+        ClientMsg::UpgradeClient(msg) => upgrade_client::process(ctx, msg)?,
+        ClientMsg::Misbehaviour(msg) => misbehaviour::process(ctx, msg)?,
     })
 }
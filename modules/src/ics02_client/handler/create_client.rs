@@ -8,6 +8,8 @@ use crate::ics02_client::error::{Error, Kind};
 use crate::ics02_client::handler::{ClientEvent, ClientResult};
 use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
 use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
 
 /// The result following the successful processing of a `MsgCreateAnyClient` message. Preferably
 /// this data type should be used with a qualified name `create_client::Result` to avoid ambiguity.
@@ -17,6 +19,8 @@ pub struct Result {
     pub client_type: ClientType,
     pub client_state: AnyClientState,
     pub consensus_state: AnyConsensusState,
+    pub processed_time: Timestamp,
+    pub processed_height: Height,
 }
 
 pub fn process(
@@ -25,19 +29,29 @@ pub fn process(
 ) -> HandlerResult<ClientResult, Error> {
     let mut output = HandlerOutput::builder();
 
-    if ctx.client_state(&msg.client_id()).is_some() {
-        return Err(Kind::ClientAlreadyExists(msg.client_id()).into());
+    // Construct this client's identifier out of the client counter of the host chain. The
+    // identifier in the message is not used.
+    let client_state = msg.client_state();
+    let client_type = client_state.client_type();
+    let id_counter = ctx.client_counter();
+    let client_id = ClientId::with_prefix(client_state.client_id_prefix(), id_counter)
+        .map_err(|e| Kind::ClientIdentifierConstructor(client_type, id_counter).context(e))?;
+
+    if ctx.client_state(&client_id).is_some() {
+        return Err(Kind::ClientAlreadyExists(client_id).into());
     }
 
     output.log("success: no client state found");
 
-    output.emit(ClientEvent::ClientCreated(msg.client_id()));
+    output.emit(ClientEvent::ClientCreated(client_id.clone()));
 
     Ok(output.with_result(ClientResult::Create(Result {
-        client_id: msg.client_id(),
-        client_type: msg.client_state().client_type(),
-        client_state: msg.client_state(),
+        client_id,
+        client_type,
+        client_state,
         consensus_state: msg.consensus_state(),
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
     })))
 }
 
@@ -51,6 +65,7 @@ mod tests {
     use crate::handler::HandlerOutput;
     use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
     use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientKeeper;
     use crate::ics02_client::error::Kind;
     use crate::ics02_client::handler::{dispatch, ClientEvent, ClientResult};
     use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
@@ -68,20 +83,21 @@ mod tests {
 
     #[test]
     fn test_create_client_ok() {
-        let client_id = ClientId::from_str("mockclient").unwrap();
         let ctx = MockContext::default();
         let signer = get_dummy_account_id();
         let height = Height::new(0, 42);
 
         let msg = MsgCreateAnyClient::new(
-            client_id,
             MockClientState(MockHeader(height)).into(),
             MockConsensusState(MockHeader(height)).into(),
             signer,
         )
         .unwrap();
 
-        let output = dispatch(&ctx, ClientMsg::CreateClient(msg.clone()));
+        let output = dispatch(&ctx, ClientMsg::CreateClient(msg));
+
+        // The host generates the identifier of the client.
+        let expected_client_id = ClientId::new(ClientType::Mock, 0).unwrap();
 
         match output {
             Ok(HandlerOutput {
@@ -91,9 +107,10 @@ mod tests {
             }) => match result {
                 ClientResult::Create(create_result) => {
                     assert_eq!(create_result.client_type, ClientType::Mock);
+                    assert_eq!(create_result.client_id, expected_client_id);
                     assert_eq!(
                         events,
-                        vec![ClientEvent::ClientCreated(msg.client_id()).into()]
+                        vec![ClientEvent::ClientCreated(expected_client_id).into()]
                     );
                     assert_eq!(log, vec!["success: no client state found".to_string(),]);
                 }
@@ -109,7 +126,8 @@ mod tests {
 
     #[test]
     fn test_create_client_existing_client_state() {
-        let client_id = ClientId::from_str("mockclient").unwrap();
+        // The identifier which the host generates for the next client is already in use.
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
         let signer = get_dummy_account_id();
         let height = Height::new(0, 30);
 
@@ -122,7 +140,6 @@ mod tests {
         let height = Height::new(0, 30);
 
         let msg = MsgCreateAnyClient::new(
-            client_id,
             MockClientState(MockHeader(Height {
                 version_height: 42,
                 ..height
@@ -137,10 +154,10 @@ mod tests {
         )
         .unwrap();
 
-        let output = dispatch(&ctx, ClientMsg::CreateClient(msg.clone()));
+        let output = dispatch(&ctx, ClientMsg::CreateClient(msg));
 
         if let Err(err) = output {
-            assert_eq!(err.kind(), &Kind::ClientAlreadyExists(msg.client_id()));
+            assert_eq!(err.kind(), &Kind::ClientAlreadyExists(client_id));
         } else {
             panic!("expected an error");
         }
@@ -152,11 +169,10 @@ mod tests {
         let signer = get_dummy_account_id();
         let height = Height::new(0, 80);
 
-        let ctx = MockContext::default().with_client(&existing_client_id, height);
+        let mut ctx = MockContext::default().with_client(&existing_client_id, height);
 
         let create_client_msgs: Vec<MsgCreateAnyClient> = vec![
             MsgCreateAnyClient::new(
-                "newmockclient1".parse().unwrap(),
                 MockClientState(MockHeader(Height {
                     version_height: 42,
                     ..height
@@ -171,7 +187,6 @@ mod tests {
             )
             .unwrap(),
             MsgCreateAnyClient::new(
-                "newmockclient2".parse().unwrap(),
                 MockClientState(MockHeader(Height {
                     version_height: 42,
                     ..height
@@ -186,7 +201,6 @@ mod tests {
             )
             .unwrap(),
             MsgCreateAnyClient::new(
-                "newmockclient3".parse().unwrap(),
                 MockClientState(MockHeader(Height {
                     version_height: 50,
                     ..height
@@ -204,9 +218,13 @@ mod tests {
         .into_iter()
         .collect();
 
-        for msg in create_client_msgs {
+        for (counter, msg) in create_client_msgs.into_iter().enumerate() {
             let output = dispatch(&ctx, ClientMsg::CreateClient(msg.clone()));
 
+            // Each new client gets the next identifier.
+            let expected_client_id =
+                ClientId::new(msg.client_state().client_type(), counter as u64).unwrap();
+
             match output {
                 Ok(HandlerOutput {
                     result,
@@ -215,11 +233,14 @@ mod tests {
                 }) => match result {
                     ClientResult::Create(create_res) => {
                         assert_eq!(create_res.client_type, msg.client_state().client_type());
+                        assert_eq!(create_res.client_id, expected_client_id);
                         assert_eq!(
                             events,
-                            vec![ClientEvent::ClientCreated(msg.client_id()).into()]
+                            vec![ClientEvent::ClientCreated(expected_client_id).into()]
                         );
                         assert_eq!(log, vec!["success: no client state found".to_string(),]);
+                        ctx.store_client_result(ClientResult::Create(create_res))
+                            .unwrap();
                     }
                     _ => {
                         panic!("expected result of type ClientResult::CreateResult");
@@ -234,7 +255,6 @@ mod tests {
 
     #[test]
     fn test_tm_create_client_ok() {
-        let client_id = ClientId::from_str("Tendermint").unwrap();
         let signer = get_dummy_account_id();
 
         let ctx = MockContext::default();
@@ -258,14 +278,15 @@ mod tests {
         });
 
         let msg = MsgCreateAnyClient::new(
-            client_id,
             tm_client_state,
             AnyConsensusState::Tendermint(tm_header.try_into().unwrap()),
             signer,
         )
         .unwrap();
 
-        let output = dispatch(&ctx, ClientMsg::CreateClient(msg.clone()));
+        let output = dispatch(&ctx, ClientMsg::CreateClient(msg));
+
+        let expected_client_id = ClientId::new(ClientType::Tendermint, 0).unwrap();
 
         match output {
             Ok(HandlerOutput {
@@ -275,9 +296,10 @@ mod tests {
             }) => match result {
                 ClientResult::Create(create_res) => {
                     assert_eq!(create_res.client_type, ClientType::Tendermint);
+                    assert_eq!(create_res.client_id, expected_client_id);
                     assert_eq!(
                         events,
-                        vec![ClientEvent::ClientCreated(msg.client_id()).into()]
+                        vec![ClientEvent::ClientCreated(expected_client_id).into()]
                     );
                     assert_eq!(log, vec!["success: no client state found".to_string(),]);
                 }
@@ -0,0 +1,251 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgSubmitAnyMisbehaviour`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, AnyClientState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::handler::{ClientEvent, ClientResult};
+use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+
+/// The result following the successful processing of a `MsgSubmitAnyMisbehaviour` message.
+/// Preferably this data type should be used with a qualified name `misbehaviour::Result` to avoid
+/// ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgSubmitAnyMisbehaviour,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgSubmitAnyMisbehaviour {
+        client_id,
+        misbehaviour,
+        signer: _,
+    } = msg;
+
+    // Read client type from the host chain store. The client should already exist.
+    ctx.client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_state(&client_state);
+
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    // Verify the misbehaviour evidence against the trusted consensus states of the client. This
+    // returns the client state frozen at the height of the misbehaviour.
+    let client_state = client_def
+        .check_misbehaviour_and_update_state(ctx, client_id.clone(), client_state, misbehaviour)
+        .map_err(|e| Kind::MisbehaviourHandlingFailure.context(e.to_string()))?;
+
+    output.emit(ClientEvent::ClientMisbehaviour(client_id.clone()));
+
+    Ok(output.with_result(ClientResult::Misbehaviour(Result {
+        client_id,
+        client_state,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::ics02_client::client_def::{AnyHeader, AnyMisbehaviour};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::ClientKeeper;
+    use crate::ics02_client::error::Kind;
+    use crate::ics02_client::handler::{dispatch, ClientEvent, ClientResult};
+    use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+    use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics02_client::state::ClientState;
+    use crate::ics07_tendermint::header::Header as TMHeader;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+    use crate::ics24_host::identifier::{ChainId, ClientId};
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostBlock;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn test_misbehaviour() {
+        struct Test {
+            name: String,
+            msg: MsgSubmitAnyMisbehaviour,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::from_str("tmclient").unwrap();
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_height = Height::new(1, 20);
+
+        let ctx = MockContext::default().with_client_parametrized_with_chain_id(
+            &client_id,
+            client_height,
+            Some(ClientType::Tendermint),
+            Some(client_height),
+            chain_id.clone(),
+        );
+
+        // Headers at height 21, verifiable against the consensus state at the client height.
+        let header = |chain_id: ChainId, time: u64| {
+            let mut header =
+                TMHeader::from(HostBlock::generate_tm_block_with_time(chain_id, 21, time));
+            header.trusted_height = client_height;
+            header
+        };
+        let time = HostBlock::tm_block_time(21);
+
+        let misbehaviour_msg = |header1: TMHeader, header2: TMHeader| {
+            MsgSubmitAnyMisbehaviour::new(
+                client_id.clone(),
+                AnyMisbehaviour::Tendermint(Misbehaviour {
+                    client_id: client_id.clone(),
+                    header1,
+                    header2,
+                }),
+                get_dummy_account_id(),
+            )
+        };
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters: conflicting headers at the same height".to_string(),
+                msg: misbehaviour_msg(
+                    header(chain_id.clone(), time),
+                    header(chain_id.clone(), time + 1),
+                ),
+                want_pass: true,
+            },
+            Test {
+                name: "Identical headers are not misbehaviour".to_string(),
+                msg: misbehaviour_msg(
+                    header(chain_id.clone(), time),
+                    header(chain_id.clone(), time),
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Headers from a different chain".to_string(),
+                msg: misbehaviour_msg(
+                    header(ChainId::new("mockgaiaC".to_string(), 1), time),
+                    header(ChainId::new("mockgaiaC".to_string(), 1), time + 1),
+                ),
+                want_pass: false,
+            },
+            Test {
+                name: "Misbehaviour of another client".to_string(),
+                msg: MsgSubmitAnyMisbehaviour::new(
+                    client_id.clone(),
+                    AnyMisbehaviour::Tendermint(Misbehaviour {
+                        client_id: ClientId::from_str("otherclient").unwrap(),
+                        header1: header(chain_id.clone(), time),
+                        header2: header(chain_id.clone(), time + 1),
+                    }),
+                    get_dummy_account_id(),
+                ),
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&ctx, ClientMsg::Misbehaviour(test.msg.clone()));
+
+            match res {
+                Ok(output) => {
+                    assert_eq!(
+                        test.want_pass, true,
+                        "misbehaviour: test passed but was supposed to fail for test: {}",
+                        test.name,
+                    );
+                    assert_eq!(
+                        output.events,
+                        vec![ClientEvent::ClientMisbehaviour(client_id.clone()).into()]
+                    );
+
+                    match output.result {
+                        ClientResult::Misbehaviour(res) => {
+                            assert_eq!(res.client_id, client_id);
+                            assert!(res.client_state.is_frozen());
+                        }
+                        _ => panic!("unexpected result type: expected ClientResult::Misbehaviour"),
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass, false,
+                        "misbehaviour: failed for test: {}, \nmsg {:?} with error {:?}",
+                        test.name, test.msg, e,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_frozen_client_rejects_update() {
+        let client_id = ClientId::from_str("tmclient").unwrap();
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_height = Height::new(1, 20);
+
+        let mut ctx = MockContext::default().with_client_parametrized_with_chain_id(
+            &client_id,
+            client_height,
+            Some(ClientType::Tendermint),
+            Some(client_height),
+            chain_id.clone(),
+        );
+
+        let header = |time: u64| {
+            let mut header = TMHeader::from(HostBlock::generate_tm_block_with_time(
+                chain_id.clone(),
+                21,
+                time,
+            ));
+            header.trusted_height = client_height;
+            header
+        };
+        let time = HostBlock::tm_block_time(21);
+
+        // Freeze the client with evidence of misbehaviour.
+        let misbehaviour = Misbehaviour {
+            client_id: client_id.clone(),
+            header1: header(time),
+            header2: header(time + 1),
+        };
+        let msg = MsgSubmitAnyMisbehaviour::new(
+            client_id.clone(),
+            AnyMisbehaviour::Tendermint(misbehaviour),
+            get_dummy_account_id(),
+        );
+        let output = dispatch(&ctx, ClientMsg::Misbehaviour(msg)).unwrap();
+        ctx.store_client_result(output.result).unwrap();
+
+        // Even a valid header is now rejected.
+        let msg = MsgUpdateAnyClient::new(
+            client_id.clone(),
+            AnyHeader::Tendermint(header(time)),
+            get_dummy_account_id(),
+        );
+        let res = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+        assert_eq!(
+            res.err().map(|e| e.kind().clone()),
+            Some(Kind::ClientFrozen(client_id))
+        );
+    }
+}
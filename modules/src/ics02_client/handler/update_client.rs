@@ -7,7 +7,10 @@ use crate::ics02_client::error::{Error, Kind};
 use crate::ics02_client::handler::{ClientEvent, ClientResult};
 
 use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use crate::ics02_client::state::ClientState;
 use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
 
 /// The result following the successful processing of a `MsgUpdateAnyClient` message. Preferably
 /// this data type should be used with a qualified name `update_client::Result` to avoid ambiguity.
@@ -16,6 +19,8 @@ pub struct Result {
     pub client_id: ClientId,
     pub client_state: AnyClientState,
     pub consensus_state: AnyConsensusState,
+    pub processed_time: Timestamp,
+    pub processed_height: Height,
 }
 
 pub fn process(
@@ -31,17 +36,21 @@ pub fn process(
     } = msg;
 
     // Read client type from the host chain store. The client should already exist.
-    let client_type = ctx
-        .client_type(&client_id)
+    ctx.client_type(&client_id)
         .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
 
-    let client_def = AnyClient::from_client_type(client_type);
-
     // Read client state from the host chain store.
     let client_state = ctx
         .client_state(&client_id)
         .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
 
+    let client_def = AnyClient::from_client_state(&client_state);
+
+    // A frozen client accepts no more updates.
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
     let latest_height = client_state.latest_height();
     ctx.consensus_state(&client_id, latest_height)
         .ok_or_else(|| Kind::ConsensusStateNotFound(client_id.clone(), latest_height))?;
@@ -50,7 +59,7 @@ pub fn process(
     // This function will return the new client_state (its latest_height changed) and a
     // consensus_state obtained from header. These will be later persisted by the keeper.
     let (new_client_state, new_consensus_state) = client_def
-        .check_header_and_update_state(client_state, header)
+        .check_header_and_update_state(ctx, client_id.clone(), client_state, header)
         .map_err(|e| Kind::HeaderVerificationFailure.context(e.to_string()))?;
 
     output.emit(ClientEvent::ClientUpdated(client_id.clone()));
@@ -59,6 +68,8 @@ pub fn process(
         client_id,
         client_state: new_client_state,
         consensus_state: new_consensus_state,
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
     })))
 }
 
@@ -66,18 +77,23 @@ pub fn process(
 mod tests {
     use std::str::FromStr;
 
+    use tendermint_testgen::Validator;
+
     use crate::handler::HandlerOutput;
-    use crate::ics02_client::client_def::AnyClientState;
+    use crate::ics02_client::client_def::{AnyClientState, AnyHeader};
+    use crate::ics02_client::client_type::ClientType;
     use crate::ics02_client::error::Kind;
-    use crate::ics02_client::handler::ClientResult::{Create, Update};
+    use crate::ics02_client::handler::ClientResult::Update;
     use crate::ics02_client::handler::{dispatch, ClientEvent};
     use crate::ics02_client::header::Header;
     use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
     use crate::ics02_client::msgs::ClientMsg;
-    use crate::ics24_host::identifier::ClientId;
+    use crate::ics07_tendermint::header::Header as TMHeader;
+    use crate::ics24_host::identifier::{ChainId, ClientId};
     use crate::mock::client_state::MockClientState;
     use crate::mock::context::MockContext;
     use crate::mock::header::MockHeader;
+    use crate::mock::host::{HostBlock, HostType};
     use crate::test_utils::get_dummy_account_id;
     use crate::Height;
 
@@ -116,7 +132,7 @@ mod tests {
                             AnyClientState::Mock(MockClientState(MockHeader(msg.header.height())))
                         )
                     }
-                    Create(_) => panic!("update handler result has type CreateResult"),
+                    _ => panic!("unexpected result type: expected ClientResult::Update"),
                 }
             }
             Err(err) => {
@@ -194,4 +210,126 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_update_tendermint_client() {
+        struct Test {
+            name: String,
+            header_chain_id: ChainId,
+            trusted_height: Height,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::from_str("tmclient").unwrap();
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_height = Height::new(1, 20);
+
+        // The host must not lag behind the headers, whose time grows with their height.
+        let ctx = MockContext::new(
+            ChainId::new("mockgaia".to_string(), 1),
+            HostType::Mock,
+            5,
+            Height::new(1, 21),
+        )
+        .with_client_parametrized_with_chain_id(
+            &client_id,
+            client_height,
+            Some(ClientType::Tendermint),
+            Some(client_height),
+            chain_id.clone(),
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good header, verified against the trusted consensus state".to_string(),
+                header_chain_id: chain_id.clone(),
+                trusted_height: client_height,
+                want_pass: true,
+            },
+            Test {
+                name: "Header from a different chain".to_string(),
+                header_chain_id: ChainId::new("mockgaiaC".to_string(), 1),
+                trusted_height: client_height,
+                want_pass: false,
+            },
+            Test {
+                name: "No consensus state at the trusted height".to_string(),
+                header_chain_id: chain_id,
+                trusted_height: Height::new(1, 19),
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let mut header = TMHeader::from(HostBlock::generate_tm_block(test.header_chain_id, 21));
+            header.trusted_height = test.trusted_height;
+
+            let msg = MsgUpdateAnyClient {
+                client_id: client_id.clone(),
+                header: AnyHeader::Tendermint(header),
+                signer: get_dummy_account_id(),
+            };
+
+            let res = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "update_client: failed for test: {}, \ntrusted height {:?} with error {:?}",
+                test.name,
+                test.trusted_height,
+                res.err(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_tendermint_client_adjacent_rotated_validators() {
+        let client_id = ClientId::from_str("tmclient").unwrap();
+        let chain_id = ChainId::new("mockgaiaB".to_string(), 1);
+        let client_height = Height::new(1, 20);
+
+        // The host must not lag behind the headers, whose time grows with their height.
+        let ctx = MockContext::new(
+            ChainId::new("mockgaia".to_string(), 1),
+            HostType::Mock,
+            5,
+            Height::new(1, 21),
+        )
+        .with_client_parametrized_with_chain_id(
+            &client_id,
+            client_height,
+            Some(ClientType::Tendermint),
+            Some(client_height),
+            chain_id.clone(),
+        );
+
+        // The header right above the trusted height is signed by a validator set which is not the
+        // one the trusted consensus state designated. The trusted validators still signed most of
+        // the commit, so only the adjacency check rejects the header.
+        let trusted_validator_set =
+            TMHeader::from(HostBlock::generate_tm_block(chain_id.clone(), 21))
+                .trusted_validator_set;
+        let mut header = TMHeader::from(HostBlock::generate_tm_block_with_new_validators(
+            chain_id,
+            21,
+            &[Validator::new("rotated").voting_power(50)],
+        ));
+        header.trusted_height = client_height;
+        header.trusted_validator_set = trusted_validator_set;
+
+        let msg = MsgUpdateAnyClient {
+            client_id,
+            header: AnyHeader::Tendermint(header),
+            signer: get_dummy_account_id(),
+        };
+
+        let res = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+        assert!(
+            res.is_err(),
+            "update_client: adjacent header with rotated validators was accepted"
+        );
+    }
 }
@@ -0,0 +1,177 @@
+//! Protocol logic specific to processing ICS2 messages of type `MsgUpgradeAnyClient`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::client_def::{AnyClient, AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::handler::{ClientEvent, ClientResult};
+use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+use crate::ics02_client::state::ClientState;
+use crate::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The result following the successful processing of a `MsgUpgradeAnyClient` message. Preferably
+/// this data type should be used with a qualified name `upgrade_client::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Result {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+    pub consensus_state: AnyConsensusState,
+    pub processed_time: Timestamp,
+    pub processed_height: Height,
+}
+
+pub fn process(
+    ctx: &dyn ClientReader,
+    msg: MsgUpgradeAnyClient,
+) -> HandlerResult<ClientResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let MsgUpgradeAnyClient {
+        client_id,
+        client_state: upgraded_client_state,
+        upgrade_height,
+        proof_upgrade,
+        signer: _,
+    } = msg;
+
+    // Read client type from the host chain store. The client should already exist.
+    ctx.client_type(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    // Read client state from the host chain store.
+    let client_state = ctx
+        .client_state(&client_id)
+        .ok_or_else(|| Kind::ClientNotFound(client_id.clone()))?;
+
+    let client_def = AnyClient::from_client_state(&client_state);
+
+    // A frozen client cannot be upgraded.
+    if client_state.is_frozen() {
+        return Err(Kind::ClientFrozen(client_id).into());
+    }
+
+    // Verify that the counterparty chain committed to the upgraded client state. This returns the
+    // upgraded client state, and the consensus state to be stored for its latest height.
+    let (new_client_state, new_consensus_state) = client_def
+        .verify_upgrade_and_update_state(
+            ctx,
+            client_id.clone(),
+            client_state,
+            upgraded_client_state,
+            upgrade_height,
+            &proof_upgrade,
+        )
+        .map_err(|e| Kind::UpgradeVerificationFailure.context(e.to_string()))?;
+
+    output.emit(ClientEvent::ClientUpgraded(client_id.clone()));
+
+    Ok(output.with_result(ClientResult::Upgrade(Result {
+        client_id,
+        client_state: new_client_state,
+        consensus_state: new_consensus_state,
+        processed_time: ctx.host_timestamp(),
+        processed_height: ctx.host_height(),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::handler::HandlerOutput;
+    use crate::ics02_client::handler::{dispatch, ClientEvent, ClientResult};
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::MockClientState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn test_upgrade_client() {
+        struct Test {
+            name: String,
+            msg: MsgUpgradeAnyClient,
+            want_pass: bool,
+        }
+
+        let client_id = ClientId::from_str("mockclient").unwrap();
+        let upgrade_height = Height::new(0, 42);
+
+        let ctx = MockContext::default().with_client(&client_id, upgrade_height);
+
+        let msg = |client_id: ClientId, upgraded_height: Height| {
+            MsgUpgradeAnyClient::new(
+                client_id,
+                MockClientState(MockHeader(upgraded_height)).into(),
+                upgrade_height,
+                vec![1].into(),
+                get_dummy_account_id(),
+            )
+        };
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters: upgrade to the next revision".to_string(),
+                msg: msg(client_id.clone(), Height::new(1, 1)),
+                want_pass: true,
+            },
+            Test {
+                name: "Upgraded client is not above the client latest height".to_string(),
+                msg: msg(client_id.clone(), Height::new(0, 40)),
+                want_pass: false,
+            },
+            Test {
+                name: "Client does not exist".to_string(),
+                msg: msg(
+                    ClientId::from_str("nonexistingclient").unwrap(),
+                    Height::new(1, 1),
+                ),
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&ctx, ClientMsg::UpgradeClient(test.msg.clone()));
+
+            match res {
+                Ok(HandlerOutput {
+                    result,
+                    events,
+                    log: _,
+                }) => {
+                    assert_eq!(
+                        test.want_pass, true,
+                        "upgrade_client: test passed but was supposed to fail for test: {}",
+                        test.name,
+                    );
+                    assert_eq!(
+                        events,
+                        vec![ClientEvent::ClientUpgraded(test.msg.client_id.clone()).into()]
+                    );
+
+                    match result {
+                        ClientResult::Upgrade(res) => {
+                            assert_eq!(res.client_id, test.msg.client_id);
+                            assert_eq!(res.client_state, test.msg.client_state);
+                        }
+                        _ => panic!("unexpected result type: expected ClientResult::Upgrade"),
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass, false,
+                        "upgrade_client: failed for test: {}, \nmsg {:?} with error {:?}",
+                        test.name, test.msg, e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,733 @@
+//! The light clients which downstream crates supply to a host chain, on top of the clients built
+//! into this crate. Such a client handles its own encoding: the routing of messages needs only a
+//! few facts about its states and headers (e.g., their heights), which the client extracts from
+//! their encoded form.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::ics02_client::client_def::{
+    AnyClientState, AnyConsensusState, AnyHeader, AnyMisbehaviour, ClientDef,
+};
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::context::ClientReader;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// A light client supplied by a downstream crate, which registers it with a `ClientRegistry`. The
+/// client works on the protobuf encodings of its client states, consensus states and headers, as
+/// found in the `value` of the `Any` messages carrying them.
+///
+/// By default, the client does not track time, rejects upgrades and fails to verify any proof.
+pub trait LightClient: Send + Sync {
+    /// The type of this client, e.g., "10-wasm", which prefixes the identifiers of its clients.
+    fn client_type(&self) -> &str;
+
+    /// The type URL of the client states of this client, under which the client is registered.
+    fn client_state_type_url(&self) -> &str;
+
+    /// The type URL of the consensus states of this client.
+    fn consensus_state_type_url(&self) -> &str;
+
+    /// The type URL of the headers of this client.
+    fn header_type_url(&self) -> &str;
+
+    /// Decodes `client_state` and returns its latest height.
+    fn latest_height(&self, client_state: &[u8]) -> Result<Height, Box<dyn std::error::Error>>;
+
+    /// Decodes `client_state` and tells whether it is frozen.
+    fn is_frozen(&self, client_state: &[u8]) -> Result<bool, Box<dyn std::error::Error>>;
+
+    /// Decodes `consensus_state` and returns its commitment root.
+    fn root(&self, consensus_state: &[u8]) -> Result<CommitmentRoot, Box<dyn std::error::Error>>;
+
+    /// Decodes `consensus_state` and returns its timestamp, against which packet timeouts are
+    /// checked.
+    fn consensus_timestamp(
+        &self,
+        _consensus_state: &[u8],
+    ) -> Result<Timestamp, Box<dyn std::error::Error>> {
+        Ok(Timestamp::none())
+    }
+
+    /// Decodes `header` and returns its height.
+    fn header_height(&self, header: &[u8]) -> Result<Height, Box<dyn std::error::Error>>;
+
+    /// Verifies `header` like `ClientDef::check_header_and_update_state`, and returns the encoded
+    /// client state and consensus state to store.
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: &ClientId,
+        client_state: &[u8],
+        header: &[u8],
+    ) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>>;
+
+    /// Verifies the upgrade like `ClientDef::verify_upgrade_and_update_state`, and returns the
+    /// encoded client state and consensus state to store.
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: &ClientId,
+        _client_state: &[u8],
+        _upgraded_client_state: &[u8],
+        _upgrade_height: Height,
+        _proof_upgrade: &CommitmentProof,
+    ) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_consensus_state(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _client_id: &ClientId,
+        _consensus_height: Height,
+        _expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_connection_state(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _connection_id: &ConnectionId,
+        _expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_full_state(
+        &self,
+        _client_state: &[u8],
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _client_id: &ClientId,
+        _proof: &CommitmentProof,
+        _expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(self.unsupported())
+    }
+
+    /// The error of the operations which this client does not support.
+    fn unsupported(&self) -> Box<dyn std::error::Error> {
+        Kind::UnsupportedClientOperation(self.client_state_type_url().to_string()).into()
+    }
+}
+
+/// The client state of a registered light client, along with that client, which decoded the facts
+/// about this state (its latest height and freeze status) upon construction.
+#[derive(Clone)]
+pub struct RegisteredClientState {
+    client: Arc<dyn LightClient>,
+    value: Vec<u8>,
+    latest_height: Height,
+    frozen: bool,
+}
+
+impl RegisteredClientState {
+    pub fn new(client: Arc<dyn LightClient>, value: Vec<u8>) -> Result<Self, Error> {
+        let latest_height = client
+            .latest_height(&value)
+            .map_err(|e| Kind::InvalidRawClientState.context(e.to_string()))?;
+        let frozen = client
+            .is_frozen(&value)
+            .map_err(|e| Kind::InvalidRawClientState.context(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            value,
+            latest_height,
+            frozen,
+        })
+    }
+
+    pub fn client(&self) -> Arc<dyn LightClient> {
+        self.client.clone()
+    }
+
+    pub fn type_url(&self) -> &str {
+        self.client.client_state_type_url()
+    }
+
+    /// The prefix of the identifiers of the clients of this state, i.e., the type of its client.
+    pub fn client_id_prefix(&self) -> &str {
+        self.client.client_type()
+    }
+
+    /// The encoded client state.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl crate::ics02_client::state::ClientState for RegisteredClientState {
+    fn chain_id(&self) -> String {
+        // Only the registered client could tell, if it tracks a chain at all.
+        String::new()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Registered
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::Registered(self)
+    }
+}
+
+impl PartialEq for RegisteredClientState {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_url() == other.type_url() && self.value == other.value
+    }
+}
+
+impl Eq for RegisteredClientState {}
+
+impl fmt::Debug for RegisteredClientState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredClientState")
+            .field("type_url", &self.type_url())
+            .field("value", &self.value)
+            .field("latest_height", &self.latest_height)
+            .field("frozen", &self.frozen)
+            .finish()
+    }
+}
+
+/// The consensus state of a registered light client, along with that client, which decoded the
+/// commitment root and the timestamp of this state upon construction.
+#[derive(Clone)]
+pub struct RegisteredConsensusState {
+    client: Arc<dyn LightClient>,
+    value: Vec<u8>,
+    root: CommitmentRoot,
+    timestamp: Timestamp,
+}
+
+impl RegisteredConsensusState {
+    pub fn new(client: Arc<dyn LightClient>, value: Vec<u8>) -> Result<Self, Error> {
+        let root = client
+            .root(&value)
+            .map_err(|e| Kind::InvalidRawConsensusState.context(e.to_string()))?;
+        let timestamp = client
+            .consensus_timestamp(&value)
+            .map_err(|e| Kind::InvalidRawConsensusState.context(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            value,
+            root,
+            timestamp,
+        })
+    }
+
+    pub fn type_url(&self) -> &str {
+        self.client.consensus_state_type_url()
+    }
+
+    /// The encoded consensus state.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl crate::ics02_client::state::ConsensusState for RegisteredConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Registered
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::Registered(self)
+    }
+}
+
+impl PartialEq for RegisteredConsensusState {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_url() == other.type_url() && self.value == other.value
+    }
+}
+
+impl Eq for RegisteredConsensusState {}
+
+impl fmt::Debug for RegisteredConsensusState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredConsensusState")
+            .field("type_url", &self.type_url())
+            .field("value", &self.value)
+            .field("root", &self.root)
+            .finish()
+    }
+}
+
+/// The header of a registered light client, along with that client, which decoded the height of
+/// this header upon construction.
+#[derive(Clone)]
+pub struct RegisteredHeader {
+    client: Arc<dyn LightClient>,
+    value: Vec<u8>,
+    height: Height,
+}
+
+impl RegisteredHeader {
+    pub fn new(client: Arc<dyn LightClient>, value: Vec<u8>) -> Result<Self, Error> {
+        let height = client
+            .header_height(&value)
+            .map_err(|e| Kind::InvalidRawHeader.context(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            value,
+            height,
+        })
+    }
+
+    pub fn type_url(&self) -> &str {
+        self.client.header_type_url()
+    }
+
+    /// The encoded header.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl crate::ics02_client::header::Header for RegisteredHeader {
+    fn client_type(&self) -> ClientType {
+        ClientType::Registered
+    }
+
+    fn height(&self) -> Height {
+        self.height
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::Registered(self)
+    }
+}
+
+impl PartialEq for RegisteredHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_url() == other.type_url() && self.value == other.value
+    }
+}
+
+impl fmt::Debug for RegisteredHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegisteredHeader")
+            .field("type_url", &self.type_url())
+            .field("value", &self.value)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// The definition of the clients of a registered type, which hands every operation over to the
+/// registered light client.
+#[derive(Clone)]
+pub struct RegisteredClient(Arc<dyn LightClient>);
+
+impl RegisteredClient {
+    pub fn new(client: Arc<dyn LightClient>) -> Self {
+        Self(client)
+    }
+
+    fn type_url(&self) -> &str {
+        self.0.client_state_type_url()
+    }
+}
+
+impl PartialEq for RegisteredClient {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_url() == other.type_url()
+    }
+}
+
+impl Eq for RegisteredClient {}
+
+impl fmt::Debug for RegisteredClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RegisteredClient")
+            .field(&self.type_url())
+            .finish()
+    }
+}
+
+impl ClientDef for RegisteredClient {
+    type Header = RegisteredHeader;
+    type Misbehaviour = AnyMisbehaviour;
+    type ClientState = RegisteredClientState;
+    type ConsensusState = RegisteredConsensusState;
+
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        // The header may belong to another registered client.
+        if header.type_url() != self.0.header_type_url() {
+            return Err(Kind::UnknownHeaderType(header.type_url().to_string()).into());
+        }
+
+        let (client_state, consensus_state) = self.0.check_header_and_update_state(
+            ctx,
+            &client_id,
+            client_state.value(),
+            header.value(),
+        )?;
+
+        Ok((
+            RegisteredClientState::new(self.0.clone(), client_state)?,
+            RegisteredConsensusState::new(self.0.clone(), consensus_state)?,
+        ))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        // There is no registration for the misbehaviour of registered clients yet.
+        Err(self.0.unsupported())
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgrade_height: Height,
+        proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if upgraded_client_state.type_url() != self.type_url() {
+            return Err(
+                Kind::UnknownClientStateType(upgraded_client_state.type_url().to_string()).into(),
+            );
+        }
+
+        let (client_state, consensus_state) = self.0.verify_upgrade_and_update_state(
+            ctx,
+            &client_id,
+            client_state.value(),
+            upgraded_client_state.value(),
+            upgrade_height,
+            proof_upgrade,
+        )?;
+
+        Ok((
+            RegisteredClientState::new(self.0.clone(), client_state)?,
+            RegisteredConsensusState::new(self.0.clone(), consensus_state)?,
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_consensus_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_client_consensus_state(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            client_id,
+            consensus_height,
+            expected_consensus_state,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_connection_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_connection_state(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            connection_id,
+            expected_connection_end,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_channel_state(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            port_id,
+            channel_id,
+            expected_channel_end,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_packet_data(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            port_id,
+            channel_id,
+            sequence,
+            commitment,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_packet_acknowledgement(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            port_id,
+            channel_id,
+            sequence,
+            ack_commitment,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_next_sequence_recv(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            port_id,
+            channel_id,
+            next_sequence_recv,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_packet_receipt_absence(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            proof,
+            port_id,
+            channel_id,
+            sequence,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_full_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProof,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.0.verify_client_full_state(
+            client_state.value(),
+            height,
+            root,
+            prefix,
+            client_id,
+            proof,
+            expected_client_state,
+        )
+    }
+}
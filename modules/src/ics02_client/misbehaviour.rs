@@ -0,0 +1,21 @@
+use crate::ics02_client::client_type::ClientType;
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+use super::client_def::AnyMisbehaviour;
+
+/// Evidence that the counterparty chain of a client misbehaved
+#[dyn_clonable::clonable]
+pub trait Misbehaviour: Clone + std::fmt::Debug + Send + Sync {
+    /// The type of client (eg. Tendermint)
+    fn client_type(&self) -> ClientType;
+
+    /// The client which this misbehaviour is submitted to
+    fn client_id(&self) -> &ClientId;
+
+    /// The height of the misbehaviour, at which the client gets frozen
+    fn height(&self) -> Height;
+
+    /// Wrap into an `AnyMisbehaviour`
+    fn wrap_any(self) -> AnyMisbehaviour;
+}
@@ -8,6 +8,9 @@ pub mod events;
 pub mod handler;
 pub mod header;
 pub mod height;
+pub mod light_client;
+pub mod misbehaviour;
 pub mod msgs;
 pub mod raw;
+pub mod registry;
 pub mod state;
@@ -5,7 +5,6 @@
 //! https://github.com/cosmos/ics/tree/master/spec/ics-002-client-semantics#create.
 
 use std::convert::TryFrom;
-use std::str::FromStr;
 
 use tendermint::account::Id as AccountId;
 use tendermint_proto::Protobuf;
@@ -16,7 +15,7 @@ use crate::address::{account_to_string, string_to_account};
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics02_client::error;
 use crate::ics02_client::error::{Error, Kind};
-use crate::ics24_host::identifier::ClientId;
+use crate::ics02_client::registry::ClientRegistry;
 use crate::tx_msg::Msg;
 
 const TYPE_MSG_CREATE_CLIENT: &str = "create_client";
@@ -24,7 +23,6 @@ const TYPE_MSG_CREATE_CLIENT: &str = "create_client";
 /// A type of message that triggers the creation of a new on-chain (IBC) client.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgCreateAnyClient {
-    client_id: ClientId,
     client_state: AnyClientState,
     consensus_state: AnyConsensusState,
     signer: AccountId,
@@ -32,7 +30,6 @@ pub struct MsgCreateAnyClient {
 
 impl MsgCreateAnyClient {
     pub fn new(
-        client_id: ClientId,
         client_state: AnyClientState,
         consensus_state: AnyConsensusState,
         signer: AccountId,
@@ -45,15 +42,11 @@ impl MsgCreateAnyClient {
             .into());
         }
         Ok(MsgCreateAnyClient {
-            client_id,
             client_state,
             consensus_state,
             signer,
         })
     }
-    pub fn client_id(&self) -> ClientId {
-        self.client_id.clone()
-    }
     pub fn client_state(&self) -> AnyClientState {
         self.client_state.clone()
     }
@@ -93,6 +86,13 @@ impl TryFrom<RawMsgCreateClient> for MsgCreateAnyClient {
     type Error = Error;
 
     fn try_from(raw: RawMsgCreateClient) -> Result<Self, Self::Error> {
+        MsgCreateAnyClient::try_from_raw(raw, &ClientRegistry::default())
+    }
+}
+
+impl MsgCreateAnyClient {
+    /// Decodes `raw`, whose states may also be those of a client registered in `registry`.
+    pub fn try_from_raw(raw: RawMsgCreateClient, registry: &ClientRegistry) -> Result<Self, Error> {
         let raw_client_state = raw
             .client_state
             .ok_or_else(|| Kind::InvalidRawClientState.context("missing client state"))?;
@@ -103,12 +103,14 @@ impl TryFrom<RawMsgCreateClient> for MsgCreateAnyClient {
 
         let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
 
+        // The host chain generates the identifier of the client: the one in the message, if any, is
+        // ignored.
         Ok(MsgCreateAnyClient::new(
-            ClientId::from_str(raw.client_id.as_str())
-                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
-            AnyClientState::try_from(raw_client_state)
+            registry
+                .decode_client_state(raw_client_state)
                 .map_err(|e| Kind::InvalidRawClientState.context(e))?,
-            AnyConsensusState::try_from(raw_consensus_state)
+            registry
+                .decode_consensus_state(raw_consensus_state)
                 .map_err(|e| Kind::InvalidRawConsensusState.context(e))?,
             signer,
         )?)
@@ -118,7 +120,7 @@ impl TryFrom<RawMsgCreateClient> for MsgCreateAnyClient {
 impl From<MsgCreateAnyClient> for RawMsgCreateClient {
     fn from(ics_msg: MsgCreateAnyClient) -> Self {
         RawMsgCreateClient {
-            client_id: ics_msg.client_id.to_string(),
+            client_id: String::new(),
             client_state: Some(ics_msg.client_state.into()),
             consensus_state: Some(ics_msg.consensus_state.into()),
             signer: account_to_string(ics_msg.signer).unwrap(),
@@ -134,7 +136,6 @@ mod tests {
 
     use crate::ics02_client::client_def::AnyConsensusState;
     use crate::ics02_client::msgs::MsgCreateAnyClient;
-    use crate::ics24_host::identifier::ClientId;
 
     use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
     use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
@@ -142,14 +143,12 @@ mod tests {
 
     #[test]
     fn msg_create_client_serialization() {
-        let client_id: ClientId = "tendermint".parse().unwrap();
         let signer = get_dummy_account_id();
 
         let tm_header = get_dummy_tendermint_header();
         let tm_client_state = get_dummy_tendermint_client_state(tm_header.clone());
 
         let msg = MsgCreateAnyClient::new(
-            client_id,
             tm_client_state,
             AnyConsensusState::Tendermint(tm_header.try_into().unwrap()),
             signer,
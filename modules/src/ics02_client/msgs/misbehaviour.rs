@@ -0,0 +1,125 @@
+use std::convert::TryFrom;
+
+use tendermint::account::Id as AccountId;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
+
+use crate::address::{account_to_string, string_to_account};
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics24_host::identifier::ClientId;
+use crate::tx_msg::Msg;
+
+const TYPE_MSG_SUBMIT_MISBEHAVIOUR: &str = "submit_misbehaviour";
+
+/// A type of message that submits evidence of misbehaviour of the counterparty chain of a client,
+/// which freezes the client.
+#[derive(Clone, Debug, PartialEq)] // TODO: Add Eq bound when possible
+pub struct MsgSubmitAnyMisbehaviour {
+    pub client_id: ClientId,
+    pub misbehaviour: AnyMisbehaviour,
+    pub signer: AccountId,
+}
+
+impl MsgSubmitAnyMisbehaviour {
+    pub fn new(client_id: ClientId, misbehaviour: AnyMisbehaviour, signer: AccountId) -> Self {
+        MsgSubmitAnyMisbehaviour {
+            client_id,
+            misbehaviour,
+            signer,
+        }
+    }
+}
+
+impl Msg for MsgSubmitAnyMisbehaviour {
+    type ValidationError = crate::ics24_host::error::ValidationError;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn get_type(&self) -> String {
+        TYPE_MSG_SUBMIT_MISBEHAVIOUR.to_string()
+    }
+
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
+        // Nothing to validate since all fields are validated on creation.
+        Ok(())
+    }
+
+    fn get_signers(&self) -> Vec<AccountId> {
+        vec![self.signer]
+    }
+
+    fn type_url(&self) -> String {
+        "/ibc.core.client.v1.MsgSubmitMisbehaviour".to_string()
+    }
+}
+
+impl Protobuf<RawMsgSubmitMisbehaviour> for MsgSubmitAnyMisbehaviour {}
+
+impl TryFrom<RawMsgSubmitMisbehaviour> for MsgSubmitAnyMisbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMsgSubmitMisbehaviour) -> Result<Self, Self::Error> {
+        let raw_misbehaviour = raw.misbehaviour.ok_or(Kind::InvalidRawMisbehaviour)?;
+        let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
+
+        Ok(MsgSubmitAnyMisbehaviour {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            misbehaviour: AnyMisbehaviour::try_from(raw_misbehaviour)?,
+            signer,
+        })
+    }
+}
+
+impl From<MsgSubmitAnyMisbehaviour> for RawMsgSubmitMisbehaviour {
+    fn from(ics_msg: MsgSubmitAnyMisbehaviour) -> Self {
+        RawMsgSubmitMisbehaviour {
+            client_id: ics_msg.client_id.to_string(),
+            misbehaviour: Some(ics_msg.misbehaviour.into()),
+            signer: account_to_string(ics_msg.signer).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour;
+
+    use crate::ics02_client::client_def::AnyMisbehaviour;
+    use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+    use crate::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+    use crate::ics07_tendermint::misbehaviour::Misbehaviour;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn msg_submit_misbehaviour_serialization() {
+        let client_id: ClientId = "tendermint".parse().unwrap();
+        let signer = get_dummy_account_id();
+
+        let misbehaviour = Misbehaviour {
+            client_id: client_id.clone(),
+            header1: get_dummy_ics07_header(),
+            header2: get_dummy_ics07_header(),
+        };
+
+        let msg = MsgSubmitAnyMisbehaviour::new(
+            client_id,
+            AnyMisbehaviour::Tendermint(misbehaviour),
+            signer,
+        );
+        let raw = MsgSubmitMisbehaviour::from(msg.clone());
+        let msg_back = MsgSubmitAnyMisbehaviour::try_from(raw.clone()).unwrap();
+        let raw_back = MsgSubmitMisbehaviour::from(msg_back.clone());
+        assert_eq!(msg, msg_back);
+        assert_eq!(raw, raw_back);
+    }
+}
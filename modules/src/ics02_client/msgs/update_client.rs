@@ -14,6 +14,7 @@ use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 use crate::address::{account_to_string, string_to_account};
 use crate::ics02_client::client_def::AnyHeader;
 use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::registry::ClientRegistry;
 use crate::ics24_host::identifier::ClientId;
 use crate::tx_msg::Msg;
 
@@ -68,12 +69,24 @@ impl TryFrom<RawMsgUpdateClient> for MsgUpdateAnyClient {
     type Error = Error;
 
     fn try_from(raw: RawMsgUpdateClient) -> Result<Self, Self::Error> {
+        MsgUpdateAnyClient::try_from_raw(raw, &ClientRegistry::default())
+    }
+}
+
+impl MsgUpdateAnyClient {
+    /// Decodes `raw`, whose header may also be that of a client registered in `registry`.
+    pub fn try_from_raw(raw: RawMsgUpdateClient, registry: &ClientRegistry) -> Result<Self, Error> {
         let raw_header = raw.header.ok_or(Kind::InvalidRawHeader)?;
         let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
 
         Ok(MsgUpdateAnyClient {
-            client_id: raw.client_id.parse().unwrap(),
-            header: AnyHeader::try_from(raw_header).unwrap(),
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
+            header: registry
+                .decode_header(raw_header)
+                .map_err(|e| Kind::InvalidRawHeader.context(e))?,
             signer,
         })
     }
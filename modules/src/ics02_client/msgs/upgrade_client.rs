@@ -0,0 +1,155 @@
+//! Definition of the message which upgrades an on-chain (IBC) client, following a planned upgrade
+//! of its counterparty chain.
+
+use std::convert::{TryFrom, TryInto};
+
+use tendermint::account::Id as AccountId;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
+
+use crate::address::{account_to_string, string_to_account};
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::registry::ClientRegistry;
+use crate::ics23_commitment::commitment::CommitmentProof;
+use crate::ics24_host::identifier::ClientId;
+use crate::tx_msg::Msg;
+use crate::Height;
+
+const TYPE_MSG_UPGRADE_CLIENT: &str = "upgrade_client";
+
+/// A type of message that upgrades an on-chain (IBC) client to the client state committed by its
+/// counterparty chain at the last height before the upgrade.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsgUpgradeAnyClient {
+    pub client_id: ClientId,
+    pub client_state: AnyClientState,
+    pub upgrade_height: Height,
+    pub proof_upgrade: CommitmentProof,
+    pub signer: AccountId,
+}
+
+impl MsgUpgradeAnyClient {
+    pub fn new(
+        client_id: ClientId,
+        client_state: AnyClientState,
+        upgrade_height: Height,
+        proof_upgrade: CommitmentProof,
+        signer: AccountId,
+    ) -> Self {
+        MsgUpgradeAnyClient {
+            client_id,
+            client_state,
+            upgrade_height,
+            proof_upgrade,
+            signer,
+        }
+    }
+}
+
+impl Msg for MsgUpgradeAnyClient {
+    type ValidationError = crate::ics24_host::error::ValidationError;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn get_type(&self) -> String {
+        TYPE_MSG_UPGRADE_CLIENT.to_string()
+    }
+
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
+        // Nothing to validate since all fields are validated on creation.
+        Ok(())
+    }
+
+    fn get_signers(&self) -> Vec<AccountId> {
+        vec![self.signer]
+    }
+
+    fn type_url(&self) -> String {
+        "/ibc.core.client.v1.MsgUpgradeClient".to_string()
+    }
+}
+
+impl Protobuf<RawMsgUpgradeClient> for MsgUpgradeAnyClient {}
+
+impl TryFrom<RawMsgUpgradeClient> for MsgUpgradeAnyClient {
+    type Error = Error;
+
+    fn try_from(raw: RawMsgUpgradeClient) -> Result<Self, Self::Error> {
+        MsgUpgradeAnyClient::try_from_raw(raw, &ClientRegistry::default())
+    }
+}
+
+impl MsgUpgradeAnyClient {
+    /// Decodes `raw`, whose client state may also be that of a client registered in `registry`.
+    pub fn try_from_raw(
+        raw: RawMsgUpgradeClient,
+        registry: &ClientRegistry,
+    ) -> Result<Self, Error> {
+        let raw_client_state = raw.client_state.ok_or(Kind::InvalidRawClientState)?;
+        let signer = string_to_account(raw.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
+
+        Ok(MsgUpgradeAnyClient {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidIdentifier.context(e))?,
+            client_state: registry.decode_client_state(raw_client_state)?,
+            upgrade_height: raw
+                .upgrade_height
+                .ok_or(Kind::InvalidRawUpgradeHeight)?
+                .try_into()
+                .map_err(|e| Kind::InvalidRawUpgradeHeight.context(e))?,
+            proof_upgrade: raw.proof_upgrade.into(),
+            signer,
+        })
+    }
+}
+
+impl From<MsgUpgradeAnyClient> for RawMsgUpgradeClient {
+    fn from(ics_msg: MsgUpgradeAnyClient) -> Self {
+        RawMsgUpgradeClient {
+            client_id: ics_msg.client_id.to_string(),
+            client_state: Some(ics_msg.client_state.into()),
+            upgrade_height: Some(ics_msg.upgrade_height.into()),
+            proof_upgrade: ics_msg.proof_upgrade.into(),
+            signer: account_to_string(ics_msg.signer).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::core::client::v1::MsgUpgradeClient;
+
+    use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
+    use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn msg_upgrade_client_serialization() {
+        let client_id: ClientId = "tendermint".parse().unwrap();
+        let client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+
+        let msg = MsgUpgradeAnyClient::new(
+            client_id,
+            client_state,
+            Height::new(0, 10),
+            vec![1, 2, 3].into(),
+            get_dummy_account_id(),
+        );
+        let raw = MsgUpgradeClient::from(msg.clone());
+        let msg_back = MsgUpgradeAnyClient::try_from(raw.clone()).unwrap();
+        let raw_back = MsgUpgradeClient::from(msg_back.clone());
+        assert_eq!(msg, msg_back);
+        assert_eq!(raw, raw_back);
+    }
+}
@@ -0,0 +1,440 @@
+//! The registry of the light clients which a host chain supports on top of the built-in ones.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use prost_types::Any;
+
+use crate::ics02_client::client_def::{
+    AnyClientState, AnyConsensusState, AnyHeader, LOCALHOST_CLIENT_STATE_TYPE_URL,
+    SOLOMACHINE_CLIENT_STATE_TYPE_URL, TENDERMINT_CLIENT_STATE_TYPE_URL,
+};
+use crate::ics02_client::error::{Error, Kind};
+use crate::ics02_client::light_client::{
+    LightClient, RegisteredClientState, RegisteredConsensusState, RegisteredHeader,
+};
+
+#[cfg(any(test, feature = "mocks"))]
+use crate::ics02_client::client_def::MOCK_CLIENT_STATE_TYPE_URL;
+
+/// The light clients registered with a host chain, indexed by the type URL of their client states.
+/// The registry decodes the states and headers of these clients; since these values then carry
+/// their client along, the ICS2 handlers route the messages about them to that client.
+#[derive(Clone)]
+pub struct ClientRegistry {
+    clients: HashMap<String, Arc<dyn LightClient>>,
+}
+
+impl ClientRegistry {
+    pub fn new() -> Self {
+        Self {
+            clients: HashMap::new(),
+        }
+    }
+
+    /// Registers `client` under the type URL of its client states, which neither a built-in client
+    /// nor another registered client may use.
+    pub fn register(&mut self, client: Arc<dyn LightClient>) -> Result<(), Error> {
+        let type_url = client.client_state_type_url().to_string();
+        if is_built_in(&type_url) || self.clients.contains_key(&type_url) {
+            return Err(Kind::ClientAlreadyRegistered(type_url).into());
+        }
+        self.clients.insert(type_url, client);
+        Ok(())
+    }
+
+    /// Returns the client registered for client states of type `type_url`, if any.
+    pub fn get(&self, type_url: &str) -> Option<Arc<dyn LightClient>> {
+        self.clients.get(type_url).cloned()
+    }
+
+    /// Decodes the client state `raw` of a registered client, or of a built-in one.
+    pub fn decode_client_state(&self, raw: Any) -> Result<AnyClientState, Error> {
+        match self.get(&raw.type_url) {
+            Some(client) => Ok(AnyClientState::Registered(RegisteredClientState::new(
+                client, raw.value,
+            )?)),
+            None => AnyClientState::try_from(raw),
+        }
+    }
+
+    /// Decodes the consensus state `raw` of a registered client, or of a built-in one.
+    pub fn decode_consensus_state(&self, raw: Any) -> Result<AnyConsensusState, Error> {
+        let client = self
+            .clients
+            .values()
+            .find(|client| client.consensus_state_type_url() == raw.type_url);
+
+        match client {
+            Some(client) => Ok(AnyConsensusState::Registered(
+                RegisteredConsensusState::new(client.clone(), raw.value)?,
+            )),
+            None => AnyConsensusState::try_from(raw),
+        }
+    }
+
+    /// Decodes the header `raw` of a registered client, or of a built-in one.
+    pub fn decode_header(&self, raw: Any) -> Result<AnyHeader, Error> {
+        let client = self
+            .clients
+            .values()
+            .find(|client| client.header_type_url() == raw.type_url);
+
+        match client {
+            Some(client) => Ok(AnyHeader::Registered(RegisteredHeader::new(
+                client.clone(),
+                raw.value,
+            )?)),
+            None => AnyHeader::try_from(raw),
+        }
+    }
+}
+
+fn is_built_in(type_url: &str) -> bool {
+    match type_url {
+        TENDERMINT_CLIENT_STATE_TYPE_URL
+        | SOLOMACHINE_CLIENT_STATE_TYPE_URL
+        | LOCALHOST_CLIENT_STATE_TYPE_URL => true,
+
+        #[cfg(any(test, feature = "mocks"))]
+        MOCK_CLIENT_STATE_TYPE_URL => true,
+
+        _ => false,
+    }
+}
+
+impl Default for ClientRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ClientRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientRegistry")
+            .field("type_urls", &self.clients.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use std::sync::Arc;
+
+    use prost_types::Any;
+
+    use crate::ics02_client::context::ClientReader;
+    use crate::ics02_client::light_client::LightClient;
+    use crate::ics02_client::registry::ClientRegistry;
+    use crate::ics23_commitment::commitment::CommitmentRoot;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::Height;
+
+    pub const DUMMY_CLIENT_TYPE: &str = "99-dummy";
+    pub const DUMMY_CLIENT_STATE_TYPE_URL: &str = "/dummy.v1.ClientState";
+    pub const DUMMY_CONSENSUS_STATE_TYPE_URL: &str = "/dummy.v1.ConsensusState";
+    pub const DUMMY_HEADER_TYPE_URL: &str = "/dummy.v1.Header";
+
+    /// A light client whose states and headers all encode a height, as the two bytes of its
+    /// version number and version height. It accepts any header above its latest height.
+    pub struct DummyClient;
+
+    fn encode(height: Height) -> Vec<u8> {
+        vec![height.version_number as u8, height.version_height as u8]
+    }
+
+    fn decode(value: &[u8]) -> Result<Height, Box<dyn std::error::Error>> {
+        match value {
+            [version_number, version_height] => {
+                Ok(Height::new(*version_number as u64, *version_height as u64))
+            }
+            _ => Err("expected two bytes".into()),
+        }
+    }
+
+    impl LightClient for DummyClient {
+        fn client_type(&self) -> &str {
+            DUMMY_CLIENT_TYPE
+        }
+
+        fn client_state_type_url(&self) -> &str {
+            DUMMY_CLIENT_STATE_TYPE_URL
+        }
+
+        fn consensus_state_type_url(&self) -> &str {
+            DUMMY_CONSENSUS_STATE_TYPE_URL
+        }
+
+        fn header_type_url(&self) -> &str {
+            DUMMY_HEADER_TYPE_URL
+        }
+
+        fn latest_height(&self, client_state: &[u8]) -> Result<Height, Box<dyn std::error::Error>> {
+            decode(client_state)
+        }
+
+        fn is_frozen(&self, _client_state: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+            Ok(false)
+        }
+
+        fn root(
+            &self,
+            consensus_state: &[u8],
+        ) -> Result<CommitmentRoot, Box<dyn std::error::Error>> {
+            Ok(CommitmentRoot::from_bytes(consensus_state))
+        }
+
+        fn header_height(&self, header: &[u8]) -> Result<Height, Box<dyn std::error::Error>> {
+            decode(header)
+        }
+
+        fn check_header_and_update_state(
+            &self,
+            _ctx: &dyn ClientReader,
+            _client_id: &ClientId,
+            client_state: &[u8],
+            header: &[u8],
+        ) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>> {
+            let height = decode(header)?;
+            if height <= decode(client_state)? {
+                return Err("header height is too low".into());
+            }
+            Ok((encode(height), encode(height)))
+        }
+    }
+
+    /// A registry which holds the `DummyClient`.
+    pub fn dummy_registry() -> ClientRegistry {
+        let mut registry = ClientRegistry::new();
+        registry.register(Arc::new(DummyClient)).unwrap();
+        registry
+    }
+
+    /// The encoding of a value of the `DummyClient` at `height`.
+    pub fn dummy_any(type_url: &str, height: Height) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: encode(height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use prost_types::Any;
+
+    use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::context::{ClientKeeper, ClientReader};
+    use crate::ics02_client::error::Kind;
+    use crate::ics02_client::handler::{dispatch, ClientResult};
+    use crate::ics02_client::light_client::LightClient;
+    use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
+    use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics02_client::registry::test_util::{
+        dummy_any, dummy_registry, DummyClient, DUMMY_CLIENT_STATE_TYPE_URL, DUMMY_CLIENT_TYPE,
+        DUMMY_CONSENSUS_STATE_TYPE_URL, DUMMY_HEADER_TYPE_URL,
+    };
+    use crate::ics02_client::state::ClientState;
+    use crate::ics23_commitment::commitment::CommitmentRoot;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::client_state::MockClientState;
+    use crate::mock::context::MockContext;
+    use crate::mock::header::MockHeader;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn register_client() {
+        let mut registry = dummy_registry();
+        assert!(registry.get(DUMMY_CLIENT_STATE_TYPE_URL).is_some());
+
+        // Neither a registered type URL nor a built-in one may be registered again.
+        let res = registry.register(Arc::new(DummyClient));
+        assert!(matches!(
+            res.unwrap_err().kind(),
+            Kind::ClientAlreadyRegistered(_)
+        ));
+
+        struct BuiltInClient;
+
+        impl LightClient for BuiltInClient {
+            fn client_type(&self) -> &str {
+                "07-tendermint"
+            }
+
+            fn client_state_type_url(&self) -> &str {
+                crate::ics02_client::client_def::TENDERMINT_CLIENT_STATE_TYPE_URL
+            }
+
+            fn consensus_state_type_url(&self) -> &str {
+                DUMMY_CONSENSUS_STATE_TYPE_URL
+            }
+
+            fn header_type_url(&self) -> &str {
+                DUMMY_HEADER_TYPE_URL
+            }
+
+            fn latest_height(&self, _: &[u8]) -> Result<Height, Box<dyn std::error::Error>> {
+                Ok(Height::zero())
+            }
+
+            fn is_frozen(&self, _: &[u8]) -> Result<bool, Box<dyn std::error::Error>> {
+                Ok(false)
+            }
+
+            fn root(&self, _: &[u8]) -> Result<CommitmentRoot, Box<dyn std::error::Error>> {
+                Ok(CommitmentRoot::from_bytes(&[]))
+            }
+
+            fn header_height(&self, _: &[u8]) -> Result<Height, Box<dyn std::error::Error>> {
+                Ok(Height::zero())
+            }
+
+            fn check_header_and_update_state(
+                &self,
+                _: &dyn ClientReader,
+                _: &ClientId,
+                _: &[u8],
+                _: &[u8],
+            ) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>> {
+                Err(self.unsupported())
+            }
+        }
+
+        let res = registry.register(Arc::new(BuiltInClient));
+        assert!(matches!(
+            res.unwrap_err().kind(),
+            Kind::ClientAlreadyRegistered(_)
+        ));
+    }
+
+    #[test]
+    fn decode_registered_values() {
+        let registry = dummy_registry();
+        let height = Height::new(1, 10);
+
+        let client_state = registry
+            .decode_client_state(dummy_any(DUMMY_CLIENT_STATE_TYPE_URL, height))
+            .unwrap();
+        assert_eq!(client_state.client_type(), ClientType::Registered);
+        assert_eq!(client_state.client_id_prefix(), DUMMY_CLIENT_TYPE);
+        assert_eq!(client_state.latest_height(), height);
+
+        // The registered client state encodes back into the value it was decoded from.
+        let raw: Any = client_state.into();
+        assert_eq!(raw, dummy_any(DUMMY_CLIENT_STATE_TYPE_URL, height));
+
+        let consensus_state = registry
+            .decode_consensus_state(dummy_any(DUMMY_CONSENSUS_STATE_TYPE_URL, height))
+            .unwrap();
+        assert!(matches!(consensus_state, AnyConsensusState::Registered(_)));
+
+        let header = registry
+            .decode_header(dummy_any(DUMMY_HEADER_TYPE_URL, height))
+            .unwrap();
+        assert!(matches!(header, AnyHeader::Registered(_)));
+
+        // Values of built-in clients still decode, and unknown ones still fail to.
+        let mock_state: Any = AnyClientState::from(MockClientState(MockHeader(height))).into();
+        assert!(matches!(
+            registry.decode_client_state(mock_state).unwrap(),
+            AnyClientState::Mock(_)
+        ));
+        assert!(registry
+            .decode_client_state(dummy_any("/unknown.v1.ClientState", height))
+            .is_err());
+
+        // A value which the client fails to decode is rejected.
+        let malformed = Any {
+            type_url: DUMMY_CLIENT_STATE_TYPE_URL.to_string(),
+            value: vec![],
+        };
+        assert!(registry.decode_client_state(malformed).is_err());
+    }
+
+    #[test]
+    fn route_messages_to_registered_client() {
+        let registry = dummy_registry();
+        let client_id = ClientId::with_prefix(DUMMY_CLIENT_TYPE, 0).unwrap();
+        let signer = get_dummy_account_id();
+        let height = Height::new(1, 10);
+        let mut ctx = MockContext::default();
+
+        let msg = MsgCreateAnyClient::new(
+            registry
+                .decode_client_state(dummy_any(DUMMY_CLIENT_STATE_TYPE_URL, height))
+                .unwrap(),
+            registry
+                .decode_consensus_state(dummy_any(DUMMY_CONSENSUS_STATE_TYPE_URL, height))
+                .unwrap(),
+            signer,
+        )
+        .unwrap();
+
+        let output = dispatch(&ctx, ClientMsg::CreateClient(msg)).unwrap();
+        ctx.store_client_result(output.result).unwrap();
+        assert_eq!(ctx.client_type(&client_id), Some(ClientType::Registered));
+
+        struct Test {
+            name: String,
+            header_height: Height,
+            want_pass: bool,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Update to a greater height".to_string(),
+                header_height: height.increment(),
+                want_pass: true,
+            },
+            Test {
+                name: "Processing fails on a header at the latest height".to_string(),
+                header_height: height,
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let msg = MsgUpdateAnyClient::new(
+                client_id.clone(),
+                registry
+                    .decode_header(dummy_any(DUMMY_HEADER_TYPE_URL, test.header_height))
+                    .unwrap(),
+                signer,
+            );
+
+            let res = dispatch(&ctx, ClientMsg::UpdateClient(msg));
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "update_client: test {} failed, result: {:?}",
+                test.name,
+                res
+            );
+
+            if let Ok(output) = res {
+                match output.result {
+                    ClientResult::Update(upd_res) => {
+                        assert_eq!(upd_res.client_state.latest_height(), test.header_height);
+                        assert_eq!(
+                            upd_res.consensus_state,
+                            registry
+                                .decode_consensus_state(dummy_any(
+                                    DUMMY_CONSENSUS_STATE_TYPE_URL,
+                                    test.header_height
+                                ))
+                                .unwrap()
+                        );
+                    }
+                    _ => panic!("update handler result has incorrect type"),
+                }
+            }
+        }
+    }
+}
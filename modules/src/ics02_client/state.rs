@@ -3,6 +3,7 @@ use super::{
     client_type::ClientType,
 };
 use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[dyn_clonable::clonable]
@@ -13,6 +14,10 @@ pub trait ConsensusState: Clone + std::fmt::Debug + Send + Sync {
     /// Commitment root of the consensus state, which is used for key-value pair verification.
     fn root(&self) -> &CommitmentRoot;
 
+    /// Time of the counterparty chain at the height of this consensus state, against which
+    /// packet timeouts are checked. `Timestamp::none()` if the client does not track time.
+    fn timestamp(&self) -> Timestamp;
+
     /// Performs basic validation of the consensus state
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>>;
 
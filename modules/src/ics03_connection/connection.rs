@@ -1,5 +1,6 @@
 use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
+use std::time::Duration;
 
 use ibc_proto::ibc::core::connection::v1::{
     ConnectionEnd as RawConnectionEnd, Counterparty as RawCounterparty,
@@ -7,7 +8,7 @@ use ibc_proto::ibc::core::connection::v1::{
 use tendermint_proto::Protobuf;
 
 use crate::ics03_connection::error::{Error, Kind};
-use crate::ics03_connection::version::validate_versions;
+use crate::ics03_connection::version::{decode_versions, encode_version, Version};
 use crate::ics23_commitment::commitment::CommitmentPrefix;
 use crate::ics24_host::error::ValidationError;
 use crate::ics24_host::identifier::{ClientId, ConnectionId};
@@ -17,7 +18,8 @@ pub struct ConnectionEnd {
     state: State,
     client_id: ClientId,
     counterparty: Counterparty,
-    versions: Vec<String>,
+    versions: Vec<Version>,
+    delay_period: Duration,
 }
 
 impl Protobuf<RawConnectionEnd> for ConnectionEnd {}
@@ -35,7 +37,8 @@ impl TryFrom<RawConnectionEnd> for ConnectionEnd {
                 .counterparty
                 .ok_or(Kind::MissingCounterparty)?
                 .try_into()?,
-            value.versions,
+            decode_versions(value.versions)?,
+            Duration::from_nanos(value.delay_period),
         )?)
     }
 }
@@ -44,9 +47,14 @@ impl From<ConnectionEnd> for RawConnectionEnd {
     fn from(value: ConnectionEnd) -> Self {
         RawConnectionEnd {
             client_id: value.client_id.to_string(),
-            versions: value.versions,
+            versions: value
+                .versions
+                .iter()
+                .map(|v| encode_version(v).unwrap())
+                .collect(),
             state: value.state as i32,
             counterparty: Some(value.counterparty.into()),
+            delay_period: value.delay_period.as_nanos() as u64,
         }
     }
 }
@@ -56,13 +64,21 @@ impl ConnectionEnd {
         state: State,
         client_id: ClientId,
         counterparty: Counterparty,
-        versions: Vec<String>, // TODO: Use Newtype for aliasing the version to a string
+        versions: Vec<Version>,
+        delay_period: Duration,
     ) -> Result<Self, Error> {
+        if versions.is_empty() {
+            return Err(Kind::InvalidVersion
+                .context("no versions".to_string())
+                .into());
+        }
+
         Ok(Self {
             state,
             client_id,
             counterparty,
-            versions: validate_versions(versions).map_err(|e| Kind::InvalidVersion.context(e))?,
+            versions,
+            delay_period,
         })
     }
 
@@ -76,10 +92,10 @@ impl ConnectionEnd {
         self.state = new_state;
     }
 
-    /// Setter for the `version` field.
-    /// TODO: A ConnectionEnd should only store one version.
-    pub fn set_version(&mut self, new_version: String) {
-        self.versions.insert(0, new_version)
+    /// Setter for the `version` field. Once negotiated, the version of the connection replaces the
+    /// candidate versions.
+    pub fn set_version(&mut self, new_version: Version) {
+        self.versions = vec![new_version];
     }
 
     /// Helper function to compare the counterparty of this end with another counterparty.
@@ -103,10 +119,17 @@ impl ConnectionEnd {
     }
 
     /// Getter for the list of versions in this connection end.
-    pub fn versions(&self) -> Vec<String> {
+    pub fn versions(&self) -> Vec<Version> {
         self.versions.clone()
     }
 
+    /// Getter for the delay period of this connection end. Packet proofs are only accepted once
+    /// this much time, and the matching number of blocks, passed since the update of the client
+    /// at the proof height.
+    pub fn delay_period(&self) -> Duration {
+        self.delay_period
+    }
+
     /// Getter for the counterparty. Returns a `clone()`.
     pub fn counterparty(&self) -> Counterparty {
         self.counterparty.clone()
@@ -232,3 +255,37 @@ impl From<State> for i32 {
         value.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tendermint_proto::Protobuf;
+
+    use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+
+    #[test]
+    fn connection_end_serialization() {
+        let conn_end = ConnectionEnd::new(
+            State::Open,
+            ClientId::from_str("clientidone").unwrap(),
+            Counterparty::new(
+                ClientId::from_str("clientidtwo").unwrap(),
+                Some(ConnectionId::from_str("connectionidtwo").unwrap()),
+                b"ibc".to_vec().into(),
+            ),
+            get_compatible_versions(),
+            Duration::from_secs(3600),
+        )
+        .unwrap();
+
+        // The delay period of the connection goes over the wire.
+        let conn_end_back =
+            ConnectionEnd::decode(conn_end.encode_vec().unwrap().as_slice()).unwrap();
+        assert_eq!(conn_end_back.delay_period(), Duration::from_secs(3600));
+        assert_eq!(conn_end, conn_end_back);
+    }
+}
@@ -5,8 +5,8 @@
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
 use crate::ics03_connection::connection::{ConnectionEnd, State};
 use crate::ics03_connection::error::Error;
-use crate::ics03_connection::handler::ConnectionResult;
-use crate::ics03_connection::version::{get_compatible_versions, pick_version};
+use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
+use crate::ics03_connection::version::{get_compatible_versions, pick_version, Version};
 use crate::ics23_commitment::commitment::CommitmentPrefix;
 use crate::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::Height;
@@ -41,7 +41,7 @@ pub trait ConnectionReader {
 
     /// Function required by ICS 03. Returns the list of all possible versions that the connection
     /// handshake protocol supports.
-    fn get_compatible_versions(&self) -> Vec<String> {
+    fn get_compatible_versions(&self) -> Vec<Version> {
         get_compatible_versions()
     }
 
@@ -49,11 +49,16 @@ pub trait ConnectionReader {
     /// connection handshake protocol prefers.
     fn pick_version(
         &self,
-        supported_versions: Vec<String>,
-        counterparty_candidate_versions: Vec<String>,
-    ) -> Result<String, Error> {
+        supported_versions: Vec<Version>,
+        counterparty_candidate_versions: Vec<Version>,
+    ) -> Result<Version, Error> {
         pick_version(supported_versions, counterparty_candidate_versions)
     }
+
+    /// Returns a counter on how many connections have been created thus far.
+    /// The value of this counter should increase only via method
+    /// `ConnectionKeeper::increase_connection_counter`.
+    fn connection_counter(&self) -> u64;
 }
 
 /// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
@@ -74,6 +79,11 @@ pub trait ConnectionKeeper {
                 self.store_connection(&result.connection_id, &result.connection_end)?;
             }
         }
+
+        // The handler generated the identifier of the connection out of the counter.
+        if result.connection_id_state == ConnectionIdState::Generated {
+            self.increase_connection_counter();
+        }
         Ok(())
     }
 
@@ -90,4 +100,9 @@ pub trait ConnectionKeeper {
         connection_id: &ConnectionId,
         client_id: &ClientId,
     ) -> Result<(), Error>;
+
+    /// Called upon connection identifier creation (Init or Try process).
+    /// Increases the counter which keeps track of how many connections have been created.
+    /// Should never fail.
+    fn increase_connection_counter(&mut self);
 }
@@ -4,6 +4,7 @@ use thiserror::Error;
 pub type Error = anomaly::Error<Kind>;
 
 use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 #[derive(Clone, Debug, Error)]
@@ -65,6 +66,9 @@ pub enum Kind {
     #[error("missing counterparty prefix")]
     MissingCounterpartyPrefix,
 
+    #[error("missing counterparty connection identifier")]
+    MissingCounterpartyConnectionId,
+
     #[error("the client id does not match any client state: {0}")]
     MissingClient(ClientId),
 
@@ -88,6 +92,12 @@ pub enum Kind {
 
     #[error("the client state proof verification failed")]
     ClientStateVerificationFailure,
+
+    #[error("not enough time elapsed, current timestamp {0} is still less than earliest acceptable timestamp {1}")]
+    NotEnoughTimeElapsed(Timestamp, Timestamp),
+
+    #[error("not enough blocks elapsed, current height {0} is still less than earliest acceptable height {1}")]
+    NotEnoughBlocksElapsed(Height, Height),
 }
 
 impl Kind {
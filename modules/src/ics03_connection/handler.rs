@@ -11,7 +11,7 @@ pub mod conn_open_ack;
 pub mod conn_open_confirm;
 pub mod conn_open_init;
 pub mod conn_open_try;
-mod verify;
+pub(crate) mod verify;
 
 #[derive(Clone, Debug)]
 pub enum ConnectionEvent {
@@ -21,9 +21,28 @@ pub enum ConnectionEvent {
     ConnOpenConfirm(ConnectionResult),
 }
 
+/// Defines the possible states of a connection identifier in a `ConnectionResult`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConnectionIdState {
+    /// Specifies that the handler allocated a new connection identifier. This happens during the
+    /// processing of either the `MsgConnectionOpenInit` or `MsgConnectionOpenTry` message.
+    Generated,
+
+    /// Specifies that the handler reused a previously-allocated connection identifier.
+    Reused,
+}
+
 #[derive(Clone, Debug)]
 pub struct ConnectionResult {
+    /// The identifier for the connection which the handler processed. Typically this represents the
+    /// newly-generated connection id (e.g., when processing `MsgConnectionOpenInit`) or
+    /// an existing connection id (e.g., for `MsgConnectionOpenAck`).
     pub connection_id: ConnectionId,
+
+    /// The state of the connection identifier (whether it was newly-generated or not).
+    pub connection_id_state: ConnectionIdState,
+
+    /// The connection end, which the handler produced as a result of processing the message.
     pub connection_end: ConnectionEnd,
 }
 
@@ -6,7 +6,7 @@ use crate::ics03_connection::context::ConnectionReader;
 use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::handler::verify::{check_client_consensus_height, verify_proofs};
 use crate::ics03_connection::handler::ConnectionEvent::ConnOpenAck;
-use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
 
 pub(crate) fn process(
@@ -23,9 +23,9 @@ pub(crate) fn process(
         // A connection end must exist and must be Init or TryOpen; otherwise we return an error.
         Some(old_conn_end) => {
             // Check if the connection state is either Init or TryOpen and message version
-            // is compatible.
+            // is compatible, i.e., picked out of the versions proposed upon Init.
             let state_is_consistent = old_conn_end.state_matches(&State::Init)
-                && old_conn_end.versions().contains(msg.version())
+                && msg.version().is_supported_by(&old_conn_end.versions())
                 || old_conn_end.state_matches(&State::TryOpen)
                     && old_conn_end.versions().get(0).eq(&Some(msg.version()));
 
@@ -62,6 +62,7 @@ pub(crate) fn process(
             ctx.commitment_prefix(),                   // Local commitment prefix.
         ),
         vec![msg.version().clone()],
+        new_conn_end.delay_period(),
     )?;
     // 2. Pass the details to the verification function.
     verify_proofs(
@@ -81,6 +82,7 @@ pub(crate) fn process(
     let result = ConnectionResult {
         connection_end: new_conn_end,
         connection_id: msg.connection_id().clone(),
+        connection_id_state: ConnectionIdState::Reused,
     };
 
     output.emit(ConnOpenAck(result.clone()));
@@ -92,6 +94,7 @@ pub(crate) fn process(
 mod tests {
     use std::convert::TryFrom;
     use std::str::FromStr;
+    use std::time::Duration;
 
     use crate::handler::EventType;
     use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
@@ -131,6 +134,7 @@ mod tests {
             client_id.clone(),
             counterparty,
             incorrect_context.get_compatible_versions(),
+            Duration::from_secs(0),
         )
         .unwrap();
 
@@ -156,6 +160,7 @@ mod tests {
             client_id.clone(),
             correct_counterparty,
             vec![msg_ack.version().clone()],
+            Duration::from_secs(0),
         )
         .unwrap();
 
@@ -6,7 +6,7 @@ use crate::ics03_connection::context::ConnectionReader;
 use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::handler::verify::verify_proofs;
 use crate::ics03_connection::handler::ConnectionEvent::ConnOpenConfirm;
-use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
 
 pub(crate) fn process(
@@ -47,6 +47,7 @@ pub(crate) fn process(
             ctx.commitment_prefix(),          // Local commitment prefix.
         ),
         new_conn_end.versions(),
+        new_conn_end.delay_period(),
     )?;
     // 2. Pass the details to the verification function.
     verify_proofs(
@@ -65,6 +66,7 @@ pub(crate) fn process(
 
     let result = ConnectionResult {
         connection_id: msg.connection_id().clone(),
+        connection_id_state: ConnectionIdState::Reused,
         connection_end: new_conn_end,
     };
     output.emit(ConnOpenConfirm(result.clone()));
@@ -76,6 +78,7 @@ pub(crate) fn process(
 mod tests {
     use std::convert::TryFrom;
     use std::str::FromStr;
+    use std::time::Duration;
 
     use crate::handler::EventType;
     use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
@@ -114,6 +117,7 @@ mod tests {
             client_id.clone(),
             counterparty,
             context.get_compatible_versions(),
+            Duration::from_secs(0),
         )
         .unwrap();
 
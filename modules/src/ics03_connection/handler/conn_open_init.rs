@@ -5,8 +5,9 @@ use crate::ics03_connection::connection::{ConnectionEnd, State};
 use crate::ics03_connection::context::ConnectionReader;
 use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::handler::ConnectionEvent::ConnOpenInit;
-use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::ics24_host::identifier::ConnectionId;
 
 pub(crate) fn process(
     ctx: &dyn ConnectionReader,
@@ -14,9 +15,13 @@ pub(crate) fn process(
 ) -> HandlerResult<ConnectionResult, Error> {
     let mut output = HandlerOutput::builder();
 
+    // Construct the identifier for the new connection out of the connection counter of the host
+    // chain. The identifier in the message is not used.
+    let connection_id = ConnectionId::new(ctx.connection_counter());
+
     // No connection should exist.
-    if ctx.connection_end(msg.connection_id()).is_some() {
-        return Err(Kind::ConnectionExistsAlready(msg.connection_id().clone()).into());
+    if ctx.connection_end(&connection_id).is_some() {
+        return Err(Kind::ConnectionExistsAlready(connection_id).into());
     }
 
     // An IBC client running on the local (host) chain should exist.
@@ -29,12 +34,14 @@ pub(crate) fn process(
         msg.client_id().clone(),
         msg.counterparty().clone(),
         ctx.get_compatible_versions(),
+        msg.delay_period(),
     )?;
 
     output.log("success: no connection found");
 
     let result = ConnectionResult {
-        connection_id: msg.connection_id().clone(),
+        connection_id,
+        connection_id_state: ConnectionIdState::Generated,
         connection_end: new_connection_end,
     };
 
@@ -46,14 +53,16 @@ pub(crate) fn process(
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
+    use std::time::Duration;
 
     use crate::handler::EventType;
     use crate::ics03_connection::connection::{ConnectionEnd, State};
     use crate::ics03_connection::context::ConnectionReader;
-    use crate::ics03_connection::handler::{dispatch, ConnectionResult};
+    use crate::ics03_connection::handler::{dispatch, ConnectionIdState, ConnectionResult};
     use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_msg_conn_open_init;
     use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
     use crate::ics03_connection::msgs::ConnectionMsg;
+    use crate::ics24_host::identifier::ConnectionId;
     use crate::mock::context::MockContext;
     use crate::Height;
 
@@ -75,6 +84,7 @@ mod tests {
             msg_conn_init.client_id().clone(),
             msg_conn_init.counterparty().clone(),
             context.get_compatible_versions(),
+            Duration::from_secs(0),
         )
         .unwrap();
 
@@ -84,7 +94,8 @@ mod tests {
                     .to_string(),
                 ctx: context
                     .clone()
-                    .with_connection(msg_conn_init.connection_id().clone(), init_conn_end.clone()),
+                    .with_client(msg_conn_init.client_id(), Height::new(0, 10))
+                    .with_connection(ConnectionId::new(0), init_conn_end.clone()),
                 msg: ConnectionMsg::ConnectionOpenInit(msg_conn_init.clone()),
                 want_pass: false,
             },
@@ -119,9 +130,11 @@ mod tests {
                     );
                     assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
 
-                    // The object in the output is a ConnectionEnd, should have init state.
+                    // The object in the output is a ConnectionEnd, should have init state. Its
+                    // identifier is the first one which the host generates.
                     let res: ConnectionResult = proto_output.result;
-                    assert_eq!(res.connection_id, msg_conn_init.connection_id().clone());
+                    assert_eq!(res.connection_id, ConnectionId::new(0));
+                    assert_eq!(res.connection_id_state, ConnectionIdState::Generated);
                     assert_eq!(res.connection_end.state().clone(), State::Init);
 
                     for e in proto_output.events.iter() {
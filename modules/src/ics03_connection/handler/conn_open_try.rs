@@ -6,8 +6,9 @@ use crate::ics03_connection::context::ConnectionReader;
 use crate::ics03_connection::error::{Error, Kind};
 use crate::ics03_connection::handler::verify::{check_client_consensus_height, verify_proofs};
 use crate::ics03_connection::handler::ConnectionEvent::ConnOpenTry;
-use crate::ics03_connection::handler::ConnectionResult;
+use crate::ics03_connection::handler::{ConnectionIdState, ConnectionResult};
 use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
+use crate::ics24_host::identifier::ConnectionId;
 
 pub(crate) fn process(
     ctx: &dyn ConnectionReader,
@@ -27,33 +28,52 @@ pub(crate) fn process(
         }
     }
 
-    // Unwrap the old connection end (if any) and validate it against the message.
-    let mut new_connection_end = match ctx.connection_end(msg.connection_id()) {
-        Some(old_conn_end) => {
-            // TODO - change validation to take into account the new `counterparty_chosen_connection_id`
-            // Validate that existing connection end matches with the one we're trying to establish.
-            if old_conn_end.state_matches(&State::Init)
-                && old_conn_end.counterparty_matches(&msg.counterparty())
-                && old_conn_end.client_id_matches(msg.client_id())
-            {
-                // A ConnectionEnd already exists and all validation passed.
-                Ok(old_conn_end)
-            } else {
-                // A ConnectionEnd already exists and validation failed.
-                Err(Into::<Error>::into(
-                    Kind::ConnectionMismatch(msg.connection_id().clone())
-                        .context(old_conn_end.client_id().to_string()),
+    // Unwrap the old connection end (if any) and validate it against the message. An old end exists
+    // when both chains initialized the connection; the message then names this end. Otherwise,
+    // the new connection end gets an identifier out of the connection counter of the host chain.
+    let (mut new_connection_end, conn_id, conn_id_state) =
+        match ctx.connection_end(msg.connection_id()) {
+            Some(old_conn_end) => {
+                // TODO - change validation to take into account the new `counterparty_chosen_connection_id`
+                // Validate that existing connection end matches with the one we're trying to establish.
+                if old_conn_end.state_matches(&State::Init)
+                    && old_conn_end.counterparty_matches(&msg.counterparty())
+                    && old_conn_end.client_id_matches(msg.client_id())
+                {
+                    // A ConnectionEnd already exists and all validation passed.
+                    Ok((
+                        old_conn_end,
+                        msg.connection_id().clone(),
+                        ConnectionIdState::Reused,
+                    ))
+                } else {
+                    // A ConnectionEnd already exists and validation failed.
+                    Err(Into::<Error>::into(
+                        Kind::ConnectionMismatch(msg.connection_id().clone())
+                            .context(old_conn_end.client_id().to_string()),
+                    ))
+                }
+            }
+            // No ConnectionEnd exists for this ConnectionId. Create & return a new one.
+            None => {
+                let conn_id = ConnectionId::new(ctx.connection_counter());
+                if ctx.connection_end(&conn_id).is_some() {
+                    return Err(Kind::ConnectionExistsAlready(conn_id).into());
+                }
+
+                Ok((
+                    ConnectionEnd::new(
+                        State::Init,
+                        msg.client_id().clone(),
+                        msg.counterparty(),
+                        msg.counterparty_versions(),
+                        msg.delay_period(),
+                    )?,
+                    conn_id,
+                    ConnectionIdState::Generated,
                 ))
             }
-        }
-        // No ConnectionEnd exists for this ConnectionId. Create & return a new one.
-        None => Ok(ConnectionEnd::new(
-            State::Init,
-            msg.client_id().clone(),
-            msg.counterparty(),
-            msg.counterparty_versions(),
-        )?),
-    }?;
+        }?;
 
     // Proof verification in two steps:
     // 1. Setup: build the ConnectionEnd as we expect to find it on the other party.
@@ -66,12 +86,13 @@ pub(crate) fn process(
             ctx.commitment_prefix(),
         ),
         msg.counterparty_versions(),
+        msg.delay_period(),
     )?;
 
     // 2. Pass the details to the verification function.
     verify_proofs(
         ctx,
-        msg.connection_id(),
+        &conn_id,
         msg.client_state(),
         &new_connection_end,
         &expected_conn,
@@ -88,7 +109,8 @@ pub(crate) fn process(
     output.log("success: connection verification passed");
 
     let result = ConnectionResult {
-        connection_id: msg.connection_id().clone(),
+        connection_id: conn_id,
+        connection_id_state: conn_id_state,
         connection_end: new_connection_end,
     };
 
@@ -100,15 +122,16 @@ pub(crate) fn process(
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
+    use std::time::Duration;
 
     use crate::handler::EventType;
     use crate::ics03_connection::connection::{ConnectionEnd, State};
     use crate::ics03_connection::context::ConnectionReader;
-    use crate::ics03_connection::handler::{dispatch, ConnectionResult};
+    use crate::ics03_connection::handler::{dispatch, ConnectionIdState, ConnectionResult};
     use crate::ics03_connection::msgs::conn_open_try::test_util::get_dummy_msg_conn_open_try;
     use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
     use crate::ics03_connection::msgs::ConnectionMsg;
-    use crate::ics24_host::identifier::ChainId;
+    use crate::ics24_host::identifier::{ChainId, ConnectionId};
     use crate::mock::context::MockContext;
     use crate::mock::host::HostType;
     use crate::Height;
@@ -167,6 +190,7 @@ mod tests {
             msg_conn_try.client_id().clone(),
             msg_conn_try.counterparty(),
             context.get_compatible_versions(),
+            Duration::from_secs(0),
         )
         .unwrap();
 
@@ -229,9 +253,11 @@ mod tests {
                     );
                     assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
 
-                    // The object in the output is a ConnectionEnd, should have TryOpen state.
+                    // The object in the output is a ConnectionEnd, should have TryOpen state. No
+                    // connection end existed, so the end got the first identifier of the host.
                     let res: ConnectionResult = proto_output.result;
-                    assert_eq!(res.connection_id, msg_conn_try.connection_id().clone());
+                    assert_eq!(res.connection_id, ConnectionId::new(0));
+                    assert_eq!(res.connection_id_state, ConnectionIdState::Generated);
                     assert_eq!(res.connection_end.state().clone(), State::TryOpen);
 
                     for e in proto_output.events.iter() {
@@ -1,14 +1,18 @@
 //! ICS3 verification functions, common across all four handlers of ICS3.
 
+use std::time::Duration;
+
 use crate::ics02_client::client_def::AnyClientState;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics03_connection::context::ConnectionReader;
 use crate::ics03_connection::error::{Error, Kind};
+use crate::ics09_localhost::verify as localhost;
 use crate::ics23_commitment::commitment::CommitmentProof;
 use crate::ics24_host::identifier::ConnectionId;
 use crate::proofs::{ConsensusProof, Proofs};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// Entry point for verifying all proofs bundled in any ICS3 message.
@@ -79,28 +83,43 @@ pub fn verify_connection_proof(
             .into());
     }
 
+    // The counterparty connection id is set by the handshake messages which prove the
+    // counterparty connection state, so its absence indicates a malformed connection end.
+    let counterparty_connection_id = connection_end
+        .counterparty()
+        .connection_id()
+        .ok_or(Kind::MissingCounterpartyConnectionId)?;
+
+    // A loopback client reads the counterparty connection end from the host store instead.
+    if let AnyClientState::Localhost(client_state) = &client_state {
+        return Ok(localhost::verify_connection_state(
+            ctx,
+            client_state,
+            proof_height,
+            counterparty_connection_id,
+            expected_conn,
+        )
+        .map_err(|_| Kind::InvalidProof.context(id.to_string()))?);
+    }
+
     // The client must have the consensus state for the height where this proof was created.
-    if ctx
+    let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
-        .is_none()
-    {
-        return Err(Kind::MissingClientConsensusState
-            .context(connection_end.client_id().to_string())
-            .into());
-    }
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState.context(connection_end.client_id().to_string())
+        })?;
 
-    let client_def = AnyClient::from_client_type(client_state.client_type());
+    let client_def = AnyClient::from_client_state(&client_state);
 
     // Verify the proof for the connection state against the expected connection end.
-    // A counterparty connection id of None causes `unwrap()` below and indicates an internal
-    // error as this is the connection id on the counterparty chain that must always be present.
     Ok(client_def
         .verify_connection_state(
             &client_state,
             proof_height,
+            consensus_state.root(),
             connection_end.counterparty().prefix(),
             proof,
-            &connection_end.counterparty().connection_id().unwrap(),
+            counterparty_connection_id,
             expected_conn,
         )
         .map_err(|_| Kind::InvalidProof.context(id.to_string()))?)
@@ -120,13 +139,26 @@ pub fn verify_client_proof(
 
     // TODO: Client frozen check?
 
+    if let AnyClientState::Localhost(client_state) = &client_state {
+        return Ok(localhost::verify_client_full_state(
+            ctx,
+            client_state,
+            proof_height,
+            connection_end.counterparty().client_id(),
+            &expected_client_state,
+        )
+        .map_err(|_| {
+            Kind::ClientStateVerificationFailure.context(connection_end.client_id().to_string())
+        })?);
+    }
+
     let consensus_state = ctx
         .client_consensus_state(connection_end.client_id(), proof_height)
         .ok_or_else(|| {
             Kind::MissingClientConsensusState.context(connection_end.client_id().to_string())
         })?;
 
-    let client_def = AnyClient::from_client_type(client_state.client_type());
+    let client_def = AnyClient::from_client_state(&client_state);
 
     Ok(client_def
         .verify_client_full_state(
@@ -160,17 +192,43 @@ pub fn verify_consensus_proof(
             .into());
     }
 
+    if let AnyClientState::Localhost(client_state) = &client_state {
+        let expected_consensus = ctx
+            .host_consensus_state(proof.height())
+            .ok_or_else(|| Kind::MissingLocalConsensusState.context(proof.height().to_string()))?;
+
+        return Ok(localhost::verify_client_consensus_state(
+            ctx,
+            client_state,
+            proof_height,
+            connection_end.counterparty().client_id(),
+            proof.height(),
+            &expected_consensus,
+        )
+        .map_err(|e| {
+            Kind::ConsensusStateVerificationFailure(proof.height()).context(e.to_string())
+        })?);
+    }
+
+    // The client must have the consensus state for the height where this proof was created.
+    let consensus_state = ctx
+        .client_consensus_state(connection_end.client_id(), proof_height)
+        .ok_or_else(|| {
+            Kind::MissingClientConsensusState.context(connection_end.client_id().to_string())
+        })?;
+
     // Fetch the expected consensus state from the historical (local) header data.
     let expected_consensus = ctx
         .host_consensus_state(proof.height())
         .ok_or_else(|| Kind::MissingLocalConsensusState.context(proof.height().to_string()))?;
 
-    let client = AnyClient::from_client_type(client_state.client_type());
+    let client = AnyClient::from_client_state(&client_state);
 
     Ok(client
         .verify_client_consensus_state(
             &client_state,
             proof_height,
+            consensus_state.root(),
             connection_end.counterparty().prefix(),
             proof.proof(),
             connection_end.counterparty().client_id(),
@@ -204,3 +262,132 @@ pub fn check_client_consensus_height(
     // Height check is within normal bounds, check passes.
     Ok(())
 }
+
+/// Checks that the delay period of a connection passed since the client got the consensus state
+/// against which a proof verifies, i.e., since `processed_time` and `processed_height` on the host
+/// chain. Both the time and the number of blocks of the delay must have elapsed.
+pub fn verify_delay_passed(
+    current_timestamp: Timestamp,
+    current_height: Height,
+    processed_time: Timestamp,
+    processed_height: Height,
+    delay_period_time: Duration,
+    delay_period_blocks: u64,
+) -> Result<(), Error> {
+    let earliest_time = processed_time.add(delay_period_time);
+    if current_timestamp < earliest_time {
+        return Err(Kind::NotEnoughTimeElapsed(current_timestamp, earliest_time).into());
+    }
+
+    let earliest_height = processed_height.add(delay_period_blocks);
+    if current_height < earliest_height {
+        return Err(Kind::NotEnoughBlocksElapsed(current_height, earliest_height).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::{ConnectionEnd, Counterparty, State};
+    use crate::ics03_connection::context::ConnectionReader;
+    use crate::ics03_connection::error::Kind;
+    use crate::ics03_connection::handler::verify::{verify_connection_proof, verify_delay_passed};
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof};
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn connection_proof_without_counterparty_connection_id() {
+        let client_id = ClientId::default();
+        let height = Height::new(0, 5);
+        let context = MockContext::default().with_client_parametrized(
+            &client_id,
+            height,
+            Some(ClientType::Localhost),
+            Some(height),
+        );
+
+        let connection_end = ConnectionEnd::new(
+            State::TryOpen,
+            client_id.clone(),
+            Counterparty::new(client_id, None, CommitmentPrefix::from(b"ibc".to_vec())),
+            context.get_compatible_versions(),
+            Duration::from_secs(0),
+        )
+        .unwrap();
+
+        let res = verify_connection_proof(
+            &context,
+            &ConnectionId::default(),
+            &connection_end,
+            &connection_end,
+            height,
+            &CommitmentProof::from(Vec::<u8>::new()),
+        );
+        match res {
+            Err(e) => assert!(matches!(e.kind(), Kind::MissingCounterpartyConnectionId)),
+            Ok(()) => panic!("verified a connection end without counterparty connection id"),
+        }
+    }
+
+    #[test]
+    fn delay_period_checks() {
+        struct Test {
+            name: String,
+            current_timestamp: Timestamp,
+            current_height: Height,
+            want_pass: bool,
+        }
+
+        let processed_time = Timestamp::from_nanoseconds(1_000_000_000_000);
+        let processed_height = Height::new(1, 10);
+        let delay_period_time = Duration::from_secs(60);
+        let delay_period_blocks = 2;
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Both the time and the blocks of the delay elapsed".to_string(),
+                current_timestamp: processed_time.add(delay_period_time),
+                current_height: Height::new(1, 12),
+                want_pass: true,
+            },
+            Test {
+                name: "Not enough time elapsed".to_string(),
+                current_timestamp: processed_time.add(Duration::from_secs(59)),
+                current_height: Height::new(1, 12),
+                want_pass: false,
+            },
+            Test {
+                name: "Not enough blocks elapsed".to_string(),
+                current_timestamp: processed_time.add(delay_period_time),
+                current_height: Height::new(1, 11),
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res = verify_delay_passed(
+                test.current_timestamp,
+                test.current_height,
+                processed_time,
+                processed_height,
+                delay_period_time,
+                delay_period_blocks,
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "verify_delay_passed: test {} failed with {:?}",
+                test.name,
+                res.err(),
+            );
+        }
+    }
+}
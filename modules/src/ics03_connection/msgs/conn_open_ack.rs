@@ -9,7 +9,7 @@ use tendermint::account::Id as AccountId;
 use crate::address::{account_to_string, string_to_account};
 use crate::ics02_client::client_def::AnyClientState;
 use crate::ics03_connection::error::{Error, Kind};
-use crate::ics03_connection::version::validate_version;
+use crate::ics03_connection::version::{decode_version, encode_version, Version};
 use crate::ics23_commitment::commitment::CommitmentProof;
 use crate::ics24_host::identifier::ConnectionId;
 use crate::proofs::{ConsensusProof, Proofs};
@@ -26,7 +26,7 @@ pub struct MsgConnectionOpenAck {
     pub counterparty_connection_id: Option<ConnectionId>,
     pub client_state: Option<AnyClientState>,
     pub proofs: Proofs,
-    pub version: String,
+    pub version: Version,
     pub signer: AccountId,
 }
 
@@ -52,7 +52,7 @@ impl MsgConnectionOpenAck {
     }
 
     /// Getter for the version field.
-    pub fn version(&self) -> &String {
+    pub fn version(&self) -> &Version {
         &self.version
     }
 
@@ -133,7 +133,7 @@ impl TryFrom<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {
                 .map(AnyClientState::try_from)
                 .transpose()
                 .map_err(|e| Kind::InvalidProof.context(e))?,
-            version: validate_version(msg.version).map_err(|e| Kind::InvalidVersion.context(e))?,
+            version: decode_version(&msg.version)?,
             proofs: Proofs::new(
                 msg.proof_try.into(),
                 client_proof,
@@ -171,7 +171,7 @@ impl From<MsgConnectionOpenAck> for RawMsgConnectionOpenAck {
                 .proofs
                 .consensus_proof()
                 .map_or_else(|| None, |h| Some(h.height().into())),
-            version: ics_msg.version,
+            version: encode_version(&ics_msg.version).unwrap(),
             signer: account_to_string(ics_msg.signer).unwrap(),
         }
     }
@@ -179,7 +179,7 @@ impl From<MsgConnectionOpenAck> for RawMsgConnectionOpenAck {
 
 #[cfg(test)]
 pub mod test_util {
-    use crate::ics03_connection::version::default_version_string;
+    use crate::ics03_connection::version::{encode_version, Version};
     use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
@@ -200,7 +200,7 @@ pub mod test_util {
             }),
             client_state: None,
             proof_client: vec![],
-            version: default_version_string(),
+            version: encode_version(&Version::default()).unwrap(),
             signer: get_dummy_bech32_account(),
         }
     }
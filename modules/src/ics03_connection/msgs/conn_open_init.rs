@@ -1,4 +1,5 @@
 use std::convert::{TryFrom, TryInto};
+use std::time::Duration;
 
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
 use tendermint_proto::Protobuf;
@@ -8,8 +9,8 @@ use tendermint::account::Id as AccountId;
 use crate::address::{account_to_string, string_to_account};
 use crate::ics03_connection::connection::Counterparty;
 use crate::ics03_connection::error::{Error, Kind};
-use crate::ics03_connection::version::validate_version;
-use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::ics03_connection::version::{decode_version, encode_version, Version};
+use crate::ics24_host::identifier::ClientId;
 use crate::tx_msg::Msg;
 
 /// Message type for the `MsgConnectionOpenInit` message.
@@ -19,19 +20,14 @@ pub const TYPE_MSG_CONNECTION_OPEN_INIT: &str = "connection_open_init";
 ///
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MsgConnectionOpenInit {
-    pub connection_id: ConnectionId,
     pub client_id: ClientId,
     pub counterparty: Counterparty,
-    pub version: String,
+    pub version: Version,
+    pub delay_period: Duration,
     pub signer: AccountId,
 }
 
 impl MsgConnectionOpenInit {
-    /// Getter: borrow the `connection_id` from this message.
-    pub fn connection_id(&self) -> &ConnectionId {
-        &self.connection_id
-    }
-
     /// Getter: borrow the `client_id` from this message.
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
@@ -42,6 +38,16 @@ impl MsgConnectionOpenInit {
         &self.counterparty
     }
 
+    /// Getter: borrow the `version` from this message.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Getter for the `delay_period` of the connection which this message initializes.
+    pub fn delay_period(&self) -> Duration {
+        self.delay_period
+    }
+
     /// Setter for `client_id`. Amenable to chaining, since it consumes the input message.
     pub fn with_client_id(self, client_id: ClientId) -> Self {
         MsgConnectionOpenInit { client_id, ..self }
@@ -83,11 +89,9 @@ impl TryFrom<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {
     fn try_from(msg: RawMsgConnectionOpenInit) -> Result<Self, Self::Error> {
         let signer = string_to_account(msg.signer).map_err(|e| Kind::InvalidAddress.context(e))?;
 
+        // The host chain generates the identifier of the connection: the one in the message, if
+        // any, is ignored.
         Ok(Self {
-            connection_id: msg
-                .connection_id
-                .parse()
-                .map_err(|e| Kind::IdentifierError.context(e))?,
             client_id: msg
                 .client_id
                 .parse()
@@ -96,7 +100,8 @@ impl TryFrom<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {
                 .counterparty
                 .ok_or(Kind::MissingCounterparty)?
                 .try_into()?,
-            version: validate_version(msg.version).map_err(|e| Kind::InvalidVersion.context(e))?,
+            version: decode_version(&msg.version)?,
+            delay_period: Duration::from_nanos(msg.delay_period),
             signer,
         })
     }
@@ -106,10 +111,11 @@ impl From<MsgConnectionOpenInit> for RawMsgConnectionOpenInit {
     fn from(ics_msg: MsgConnectionOpenInit) -> Self {
         RawMsgConnectionOpenInit {
             client_id: ics_msg.client_id.as_str().to_string(),
-            connection_id: ics_msg.connection_id.as_str().to_string(),
+            connection_id: String::new(),
             counterparty: Some(ics_msg.counterparty.into()),
-            version: ics_msg.version,
+            version: encode_version(&ics_msg.version).unwrap(),
             signer: account_to_string(ics_msg.signer).unwrap(),
+            delay_period: ics_msg.delay_period.as_nanos() as u64,
         }
     }
 }
@@ -119,7 +125,7 @@ pub mod test_util {
     use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
 
     use crate::ics03_connection::msgs::test_util::get_dummy_counterparty;
-    use crate::ics03_connection::version::default_version_string;
+    use crate::ics03_connection::version::{encode_version, Version};
     use crate::test_utils::get_dummy_bech32_account;
 
     /// Returns a dummy message, for testing only.
@@ -127,10 +133,11 @@ pub mod test_util {
     pub fn get_dummy_msg_conn_open_init() -> RawMsgConnectionOpenInit {
         RawMsgConnectionOpenInit {
             client_id: "srcclient".to_string(),
-            connection_id: "srcconnection".to_string(),
+            connection_id: String::new(),
             counterparty: Some(get_dummy_counterparty()),
-            version: default_version_string(),
+            version: encode_version(&Version::default()).unwrap(),
             signer: get_dummy_bech32_account(),
+            delay_period: 0,
         }
     }
 }
@@ -138,6 +145,9 @@ pub mod test_util {
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
+    use std::time::Duration;
+
+    use tendermint_proto::Protobuf;
 
     use ibc_proto::ibc::core::connection::v1::Counterparty as RawCounterparty;
     use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
@@ -163,14 +173,6 @@ mod tests {
                 raw: default_init_msg.clone(),
                 want_pass: true,
             },
-            Test {
-                name: "Bad connection id, non-alpha".to_string(),
-                raw: RawMsgConnectionOpenInit {
-                    connection_id: "con007".to_string(),
-                    ..default_init_msg.clone()
-                },
-                want_pass: false,
-            },
             Test {
                 name: "Bad client id, name too short".to_string(),
                 raw: RawMsgConnectionOpenInit {
@@ -218,5 +220,14 @@ mod tests {
         let msg_back = MsgConnectionOpenInit::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+
+        // The delay period of the connection goes over the wire.
+        let delayed_msg = MsgConnectionOpenInit {
+            delay_period: Duration::from_secs(3600),
+            ..msg
+        };
+        let delayed_msg_back =
+            MsgConnectionOpenInit::decode(delayed_msg.encode_vec().unwrap().as_slice()).unwrap();
+        assert_eq!(delayed_msg, delayed_msg_back);
     }
 }
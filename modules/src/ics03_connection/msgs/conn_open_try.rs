@@ -1,5 +1,6 @@
 use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
+use std::time::Duration;
 
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
 use tendermint_proto::Protobuf;
@@ -10,7 +11,7 @@ use crate::address::{account_to_string, string_to_account};
 use crate::ics02_client::client_def::AnyClientState;
 use crate::ics03_connection::connection::Counterparty;
 use crate::ics03_connection::error::{Error, Kind};
-use crate::ics03_connection::version::validate_versions;
+use crate::ics03_connection::version::{decode_versions, encode_version, Version};
 use crate::ics23_commitment::commitment::CommitmentProof;
 use crate::ics24_host::identifier::{ClientId, ConnectionId};
 use crate::proofs::{ConsensusProof, Proofs};
@@ -30,8 +31,9 @@ pub struct MsgConnectionOpenTry {
     pub client_state: Option<AnyClientState>,
     pub counterparty_chosen_connection_id: Option<ConnectionId>,
     pub counterparty: Counterparty,
-    pub counterparty_versions: Vec<String>,
+    pub counterparty_versions: Vec<Version>,
     pub proofs: Proofs,
+    pub delay_period: Duration,
     pub signer: AccountId,
 }
 
@@ -62,7 +64,7 @@ impl MsgConnectionOpenTry {
     }
 
     /// Getter for accessing the versions from this message. Returns a `clone()`.
-    pub fn counterparty_versions(&self) -> Vec<String> {
+    pub fn counterparty_versions(&self) -> Vec<Version> {
         self.counterparty_versions.clone()
     }
 
@@ -71,6 +73,11 @@ impl MsgConnectionOpenTry {
         &self.proofs
     }
 
+    /// Getter for the `delay_period` of the connection which this message opens.
+    pub fn delay_period(&self) -> Duration {
+        self.delay_period
+    }
+
     /// Getter for accessing the `consensus_height` field from this message. Returns the special
     /// value `0` if this field is not set.
     pub fn consensus_height(&self) -> Height {
@@ -157,8 +164,7 @@ impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
                 .counterparty
                 .ok_or(Kind::MissingCounterparty)?
                 .try_into()?,
-            counterparty_versions: validate_versions(msg.counterparty_versions)
-                .map_err(|e| Kind::InvalidVersion.context(e))?,
+            counterparty_versions: decode_versions(msg.counterparty_versions)?,
             proofs: Proofs::new(
                 msg.proof_init.into(),
                 client_proof,
@@ -166,6 +172,7 @@ impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
                 proof_height,
             )
             .map_err(|e| Kind::InvalidProof.context(e))?,
+            delay_period: Duration::from_nanos(msg.delay_period),
             signer: string_to_account(msg.signer).map_err(|e| Kind::InvalidAddress.context(e))?,
         })
     }
@@ -180,7 +187,11 @@ impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
                 .client_state
                 .map_or_else(|| None, |v| Some(v.into())),
             counterparty: Some(ics_msg.counterparty.into()),
-            counterparty_versions: ics_msg.counterparty_versions,
+            counterparty_versions: ics_msg
+                .counterparty_versions
+                .iter()
+                .map(|v| encode_version(v).unwrap())
+                .collect(),
             proof_height: Some(ics_msg.proofs.height().into()),
             counterparty_chosen_connection_id: ics_msg
                 .counterparty_chosen_connection_id
@@ -200,6 +211,7 @@ impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
                 .consensus_proof()
                 .map_or_else(|| None, |h| Some(h.height().into())),
             signer: account_to_string(ics_msg.signer).unwrap(),
+            delay_period: ics_msg.delay_period.as_nanos() as u64,
         }
     }
 }
@@ -207,7 +219,7 @@ impl From<MsgConnectionOpenTry> for RawMsgConnectionOpenTry {
 #[cfg(test)]
 pub mod test_util {
     use crate::ics03_connection::msgs::test_util::get_dummy_counterparty;
-    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics03_connection::version::{encode_version, get_compatible_versions};
     use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
@@ -225,7 +237,10 @@ pub mod test_util {
             desired_connection_id: "srcconnection".to_string(),
             client_state: None,
             counterparty: Some(get_dummy_counterparty()),
-            counterparty_versions: get_compatible_versions(),
+            counterparty_versions: get_compatible_versions()
+                .iter()
+                .map(|v| encode_version(v).unwrap())
+                .collect(),
             counterparty_chosen_connection_id: "srcconnection".to_string(),
             proof_init: get_dummy_proof(),
             proof_height: Some(Height {
@@ -239,6 +254,7 @@ pub mod test_util {
             }),
             proof_client: vec![],
             signer: get_dummy_bech32_account(),
+            delay_period: 0,
         }
     }
 }
@@ -246,6 +262,9 @@ pub mod test_util {
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
+    use std::time::Duration;
+
+    use tendermint_proto::Protobuf;
 
     use ibc_proto::ibc::core::client::v1::Height;
     use ibc_proto::ibc::core::connection::v1::Counterparty as RawCounterparty;
@@ -380,5 +399,14 @@ mod tests {
         let msg_back = MsgConnectionOpenTry::try_from(raw_back.clone()).unwrap();
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
+
+        // The delay period of the connection goes over the wire.
+        let delayed_msg = MsgConnectionOpenTry {
+            delay_period: Duration::from_secs(3600),
+            ..msg
+        };
+        let delayed_msg_back =
+            MsgConnectionOpenTry::decode(delayed_msg.encode_vec().unwrap().as_slice()).unwrap();
+        assert_eq!(delayed_msg, delayed_msg_back);
     }
 }
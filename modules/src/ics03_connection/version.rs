@@ -4,25 +4,87 @@ use ibc_proto::ibc::core::connection::v1::Version as RawVersion;
 use tendermint_proto::Protobuf;
 
 use crate::ics03_connection::error::{Error, Kind};
-use std::str::FromStr;
+use crate::ics04_channel::channel::Order;
 
+/// The features of the default version, i.e., the orderings of the channels which connections
+/// of this version support.
+const ORDER_ORDERED: &str = "ORDER_ORDERED";
+const ORDER_UNORDERED: &str = "ORDER_UNORDERED";
+
+/// A connection version, i.e., a version identifier along with the features which it supports.
+/// Both ends of a connection negotiate its version during the handshake.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Version {
+pub struct Version {
     /// unique version identifier
     identifier: String,
     /// list of features compatible with the specified identifier
     features: Vec<String>,
 }
 
+impl Version {
+    /// Builds a version, which must have an encoding on the wire (see `encode_version`).
+    pub fn new(identifier: String, features: Vec<String>) -> Result<Self, Error> {
+        let version = Self {
+            identifier,
+            features,
+        };
+        encode_version(&version)?;
+        Ok(version)
+    }
+
+    /// Getter for the identifier of this version.
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Getter for the features of this version.
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    /// Checks whether this version supports the given feature.
+    pub fn is_supported_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Checks whether this version is one of `versions`, possibly with fewer features, as when the
+    /// counterparty picked it out of these versions.
+    pub fn is_supported_by(&self, versions: &[Version]) -> bool {
+        versions.iter().any(|v| {
+            v.identifier == self.identifier
+                && self.features.iter().all(|f| v.is_supported_feature(f))
+        })
+    }
+
+    /// Checks whether channels of the given ordering may be opened over a connection of this
+    /// version.
+    pub fn supports_order(&self, order: &Order) -> bool {
+        match order {
+            Order::Ordered => self.is_supported_feature(ORDER_ORDERED),
+            Order::Unordered => self.is_supported_feature(ORDER_UNORDERED),
+            Order::None => false,
+        }
+    }
+}
+
 impl Protobuf<RawVersion> for Version {}
 
 impl TryFrom<RawVersion> for Version {
     type Error = anomaly::Error<Kind>;
     fn try_from(value: RawVersion) -> Result<Self, Self::Error> {
-        Ok(Version {
-            identifier: value.identifier,
-            features: value.features,
-        })
+        if value.identifier.trim().is_empty() {
+            return Err(Kind::InvalidVersion
+                .context("empty version string".to_string())
+                .into());
+        }
+        for feature in value.features.iter() {
+            if feature.trim().is_empty() {
+                return Err(Kind::InvalidVersion
+                    .context("empty feature string".to_string())
+                    .into());
+            }
+        }
+        Version::new(value.identifier, value.features)
     }
 }
 
@@ -39,191 +101,177 @@ impl Default for Version {
     fn default() -> Self {
         Version {
             identifier: "1".to_string(),
-            features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+            features: vec![ORDER_ORDERED.to_string(), ORDER_UNORDERED.to_string()],
         }
     }
 }
 
-impl FromStr for Version {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Version::decode(s.as_bytes()).map_err(|e| Kind::InvalidVersion.context(e))?)
-    }
-}
-
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(
-            f,
-            "{}",
-            String::from_utf8(Version::encode_vec(&self).unwrap()).unwrap()
-        )
+        write!(f, "{}: [{}]", self.identifier, self.features.join(", "))
     }
 }
 
-pub fn default_version_string() -> String {
-    Version::default().to_string()
-}
-
-pub fn get_compatible_versions() -> Vec<String> {
-    vec![default_version_string()]
+/// Parses a version out of its encoding in the messages and connection ends on the wire, i.e.,
+/// the string of its protobuf encoding.
+pub fn decode_version(raw_version: &str) -> Result<Version, Error> {
+    Ok(Version::decode(raw_version.as_bytes()).map_err(|e| Kind::InvalidVersion.context(e))?)
 }
 
-pub fn pick_version(
-    supported_versions: Vec<String>,
-    counterparty_versions: Vec<String>,
-) -> Result<String, Error> {
-    let mut intersection: Vec<Version> = vec![];
-    for s in supported_versions.iter() {
-        let supported_version =
-            Version::decode(s.as_bytes()).map_err(|e| Kind::InvalidVersion.context(e))?;
-        for c in counterparty_versions.iter() {
-            let counterparty_version = Version::from_str(c.as_str())?;
-            if supported_version.identifier != counterparty_version.identifier {
-                continue;
-            }
-            // TODO - perform feature intersection and error if empty
-            intersection.append(&mut vec![supported_version.clone()]);
-        }
-    }
-    intersection.sort_by(|a, b| a.identifier.cmp(&b.identifier));
-    if intersection.is_empty() {
-        return Err(Kind::NoCommonVersion.into());
-    }
-    Ok(intersection[0].to_string())
+/// Encodes a version as in the messages and connection ends on the wire, i.e., as the string of its
+/// protobuf encoding. Fails if that encoding is not UTF-8, e.g., because the length of a field of
+/// 128 bytes or more does not fit in an ASCII byte. Versions are checked on construction, so the
+/// encoding of any `Version` succeeds.
+pub fn encode_version(version: &Version) -> Result<String, Error> {
+    let bytes = version
+        .encode_vec()
+        .map_err(|e| Kind::InvalidVersion.context(e))?;
+    Ok(String::from_utf8(bytes).map_err(|e| Kind::InvalidVersion.context(e))?)
 }
 
-pub fn validate_versions(versions: Vec<String>) -> Result<Vec<String>, Error> {
-    if versions.is_empty() {
+/// Parses the versions on the wire, of which there must be at least one.
+pub fn decode_versions(raw_versions: Vec<String>) -> Result<Vec<Version>, Error> {
+    if raw_versions.is_empty() {
         return Err(Kind::InvalidVersion
             .context("no versions".to_string())
             .into());
     }
-    for version_str in versions.iter() {
-        validate_version(version_str.clone())?;
-    }
-    Ok(versions)
+    raw_versions.iter().map(|v| decode_version(v)).collect()
 }
 
-pub fn validate_version(raw_version: String) -> Result<String, Error> {
-    let version =
-        Version::from_str(raw_version.as_ref()).map_err(|e| Kind::InvalidVersion.context(e))?;
+/// The versions which the host supports, in decreasing order of preference.
+pub fn get_compatible_versions() -> Vec<Version> {
+    vec![Version::default()]
+}
 
-    if version.identifier.trim().is_empty() {
-        return Err(Kind::InvalidVersion
-            .context("empty version string".to_string())
-            .into());
-    }
-    for feature in version.features {
-        if feature.trim().is_empty() {
-            return Err(Kind::InvalidVersion
-                .context("empty feature string".to_string())
-                .into());
+/// Picks the version of a connection out of the versions which both parties support. Versions
+/// with the same identifier are intersected feature-wise, and those without common features are
+/// discarded. As in ibc-go, the first remaining version in the order of `supported_versions`, i.e.,
+/// the one which the host prefers, is picked.
+pub fn pick_version(
+    supported_versions: Vec<Version>,
+    counterparty_versions: Vec<Version>,
+) -> Result<Version, Error> {
+    for supported_version in supported_versions.into_iter() {
+        for counterparty_version in counterparty_versions.iter() {
+            if supported_version.identifier != counterparty_version.identifier {
+                continue;
+            }
+            // A subset of the features of a version encodes as well as the version itself.
+            let features: Vec<String> = supported_version
+                .features
+                .iter()
+                .filter(|f| counterparty_version.is_supported_feature(f))
+                .cloned()
+                .collect();
+            if !features.is_empty() {
+                return Ok(Version {
+                    identifier: supported_version.identifier,
+                    features,
+                });
+            }
         }
     }
-    Ok(raw_version)
+    Err(Kind::NoCommonVersion.into())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::ics03_connection::version::{
-        default_version_string, get_compatible_versions, pick_version, validate_versions, Version,
+        decode_version, decode_versions, encode_version, get_compatible_versions, pick_version,
+        Version,
     };
-    use std::str::FromStr;
+    use crate::ics04_channel::channel::Order;
 
     fn good_versions() -> Vec<String> {
         vec![
             Version::default(),
-            Version {
-                identifier: "2".to_string(),
-                features: vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()],
-            },
+            Version::new(
+                "2".to_string(),
+                vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()],
+            )
+            .unwrap(),
         ]
         .into_iter()
-        .map(|v| v.to_string())
+        .map(|v| encode_version(&v).unwrap())
         .collect()
     }
 
     fn bad_versions_identifier() -> Vec<String> {
-        vec![Version {
-            identifier: "".to_string(),
-            features: vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()],
-        }]
+        vec![Version::new(
+            "".to_string(),
+            vec!["ORDER_RANDOM".to_string(), "ORDER_UNORDERED".to_string()],
+        )
+        .unwrap()]
         .into_iter()
-        .map(|v| v.to_string())
+        .map(|v| encode_version(&v).unwrap())
         .collect()
     }
 
     fn bad_versions_features() -> Vec<String> {
-        vec![Version {
-            identifier: "2".to_string(),
-            features: vec!["".to_string()],
-        }]
-        .into_iter()
-        .map(|v| v.to_string())
-        .collect()
+        vec![Version::new("2".to_string(), vec!["".to_string()]).unwrap()]
+            .into_iter()
+            .map(|v| encode_version(&v).unwrap())
+            .collect()
     }
 
-    fn overlapping() -> (Vec<String>, Vec<String>, String) {
+    fn overlapping() -> (Vec<Version>, Vec<Version>, Version) {
         (
             vec![
                 Version::default(),
-                Version {
-                    identifier: "3".to_string(),
-                    features: vec![],
-                },
-                Version {
-                    identifier: "4".to_string(),
-                    features: vec![],
-                },
-            ]
-            .into_iter()
-            .map(|v| v.to_string())
-            .collect(),
+                Version::new("3".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+                Version::new("4".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+            ],
             vec![
-                Version {
-                    identifier: "2".to_string(),
-                    features: vec![],
-                },
-                Version {
-                    identifier: "4".to_string(),
-                    features: vec![],
-                },
-                Version {
-                    identifier: "3".to_string(),
-                    features: vec![],
-                },
-            ]
-            .into_iter()
-            .map(|v| v.to_string())
-            .collect(),
-            // Should pick version 3 as it's the lowest of the intersection {3, 4}
-            Version {
-                identifier: "3".to_string(),
-                features: vec![],
-            }
-            .to_string(),
+                Version::new("2".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+                Version::new("4".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+                Version::new("3".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+            ],
+            // Should pick version 3 as it comes first in the supported versions of the intersection
+            // {3, 4}
+            Version::new("3".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
         )
     }
 
-    fn disjoint() -> (Vec<String>, Vec<String>, String) {
+    fn preferred() -> (Vec<Version>, Vec<Version>, Version) {
         (
-            vec![Version {
-                identifier: "1".to_string(),
-                features: vec![],
-            }]
-            .into_iter()
-            .map(|v| v.to_string())
-            .collect(),
-            vec![Version {
-                identifier: "2".to_string(),
-                features: vec![],
-            }]
-            .into_iter()
-            .map(|v| v.to_string())
-            .collect(),
-            "".to_string(),
+            vec![
+                Version::new("4".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+                Version::new("3".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+            ],
+            vec![
+                Version::new("3".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+                Version::new("4".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+            ],
+            // Should pick version 4, which the host prefers over version 3
+            Version::new("4".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap(),
+        )
+    }
+
+    fn disjoint() -> (Vec<Version>, Vec<Version>) {
+        (
+            vec![Version::new("1".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap()],
+            vec![Version::new("2".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap()],
+        )
+    }
+
+    fn feature_subset() -> (Vec<Version>, Vec<Version>, Version) {
+        (
+            get_compatible_versions(),
+            vec![Version::new(
+                "1".to_string(),
+                vec!["ORDER_UNORDERED".to_string(), "ORDER_RANDOM".to_string()],
+            )
+            .unwrap()],
+            // Only the features which both parties support remain.
+            Version::new("1".to_string(), vec!["ORDER_UNORDERED".to_string()]).unwrap(),
+        )
+    }
+
+    fn feature_disjoint() -> (Vec<Version>, Vec<Version>) {
+        (
+            vec![Version::new("1".to_string(), vec!["ORDER_ORDERED".to_string()]).unwrap()],
+            vec![Version::new("1".to_string(), vec!["ORDER_UNORDERED".to_string()]).unwrap()],
         )
     }
 
@@ -237,7 +285,10 @@ mod tests {
         let tests: Vec<Test> = vec![
             Test {
                 name: "Compatible versions".to_string(),
-                versions: get_compatible_versions(),
+                versions: get_compatible_versions()
+                    .into_iter()
+                    .map(|v| encode_version(&v).unwrap())
+                    .collect(),
                 want_pass: true,
             },
             Test {
@@ -263,7 +314,7 @@ mod tests {
         ];
 
         for test in tests {
-            let versions = validate_versions(test.versions);
+            let versions = decode_versions(test.versions);
 
             assert_eq!(
                 test.want_pass,
@@ -278,32 +329,46 @@ mod tests {
     fn pick() {
         struct Test {
             name: String,
-            supported: Vec<String>,
-            counterparty: Vec<String>,
-            picked: String,
-            want_pass: bool,
+            supported: Vec<Version>,
+            counterparty: Vec<Version>,
+            picked: Option<Version>,
         }
         let tests: Vec<Test> = vec![
             Test {
                 name: "Compatible versions".to_string(),
                 supported: get_compatible_versions(),
                 counterparty: get_compatible_versions(),
-                picked: default_version_string(),
-                want_pass: true,
+                picked: Some(Version::default()),
             },
             Test {
                 name: "Overlapping versions".to_string(),
                 supported: overlapping().0,
                 counterparty: overlapping().1,
-                picked: overlapping().2,
-                want_pass: true,
+                picked: Some(overlapping().2),
+            },
+            Test {
+                name: "Preferred version".to_string(),
+                supported: preferred().0,
+                counterparty: preferred().1,
+                picked: Some(preferred().2),
             },
             Test {
                 name: "Disjoint versions".to_string(),
                 supported: disjoint().0,
                 counterparty: disjoint().1,
-                picked: disjoint().2,
-                want_pass: false,
+                picked: None,
+            },
+            Test {
+                name: "Overlapping features".to_string(),
+                supported: feature_subset().0,
+                counterparty: feature_subset().1,
+                picked: Some(feature_subset().2),
+            },
+            Test {
+                name: "Disjoint features".to_string(),
+                supported: feature_disjoint().0,
+                counterparty: feature_disjoint().1,
+                picked: None,
             },
         ];
 
@@ -311,22 +376,46 @@ mod tests {
             let version = pick_version(test.supported, test.counterparty);
 
             assert_eq!(
-                test.want_pass,
+                test.picked.is_some(),
                 version.is_ok(),
                 "Validate versions failed for test {}",
                 test.name,
             );
 
-            if test.want_pass {
-                assert_eq!(version.unwrap(), test.picked);
+            if let Some(picked) = test.picked {
+                assert_eq!(version.unwrap(), picked);
             }
         }
     }
     #[test]
     fn serialize() {
         let def = Version::default();
-        let def_raw = def.to_string();
-        let def_back = Version::from_str(def_raw.as_ref()).unwrap();
+        let def_raw = encode_version(&def).unwrap();
+        let def_back = decode_version(&def_raw).unwrap();
         assert_eq!(def, def_back);
+
+        // The length of a feature of 128 bytes takes two bytes, which are not UTF-8.
+        assert!(Version::new("1".to_string(), vec!["A".repeat(127)]).is_ok());
+        assert!(Version::new("1".to_string(), vec!["A".repeat(128)]).is_err());
+    }
+    #[test]
+    fn display() {
+        let def = Version::default();
+        assert_eq!(def.to_string(), "1: [ORDER_ORDERED, ORDER_UNORDERED]");
+    }
+    #[test]
+    fn supported_orders() {
+        let def = Version::default();
+        assert!(def.supports_order(&Order::Ordered));
+        assert!(def.supports_order(&Order::Unordered));
+        assert!(!def.supports_order(&Order::None));
+
+        let unordered = Version::new("1".to_string(), vec!["ORDER_UNORDERED".to_string()]).unwrap();
+        assert!(!unordered.supports_order(&Order::Ordered));
+        assert!(unordered.supports_order(&Order::Unordered));
+
+        // A version picked out of the compatible versions has a subset of their features.
+        assert!(unordered.is_supported_by(&get_compatible_versions()));
+        assert!(!get_compatible_versions()[0].is_supported_by(&[unordered]));
     }
 }
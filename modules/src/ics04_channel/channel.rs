@@ -95,14 +95,34 @@ impl ChannelEnd {
         self.state = s;
     }
 
+    /// Updates the ChannelEnd to assume a new version 'v'.
+    pub fn set_version(&mut self, v: String) {
+        self.version = v;
+    }
+
+    /// Updates the identifier of the counterparty channel end to be `c`.
+    pub fn set_counterparty_channel_id(&mut self, c: ChannelId) {
+        self.remote.channel_id = Some(c);
+    }
+
     pub fn state(&self) -> &State {
         &self.state
     }
 
+    /// Helper function to compare the state of this end with another state.
+    pub fn state_matches(&self, other: &State) -> bool {
+        self.state.eq(other)
+    }
+
     pub fn ordering(&self) -> &Order {
         &self.ordering
     }
 
+    /// Helper function to compare the order of this end with another order.
+    pub fn order_matches(&self, other: &Order) -> bool {
+        self.ordering.eq(other)
+    }
+
     pub fn counterparty(&self) -> Counterparty {
         self.remote.clone()
     }
@@ -277,6 +297,7 @@ pub fn validate_version(version: String) -> Result<String, Error> {
 
 #[cfg(test)]
 pub mod test_util {
+    use crate::ics24_host::identifier::ConnectionId;
     use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
     use ibc_proto::ibc::core::channel::v1::Counterparty as RawCounterparty;
 
@@ -294,7 +315,7 @@ pub mod test_util {
             state: 0,
             ordering: 0,
             counterparty: Some(get_dummy_raw_counterparty()),
-            connection_hops: vec![],
+            connection_hops: vec![ConnectionId::default().to_string()],
             version: "".to_string(), // The version is not validated.
         }
     }
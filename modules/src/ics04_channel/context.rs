@@ -0,0 +1,273 @@
+//! ICS4 (channel) context. The two traits `ChannelReader` and `ChannelKeeper` define the interface
+//! that any host chain must implement to be able to process any `ChannelMsg` or `PacketMsg`.
+//! See "ADR 003: IBC protocol implementation" for more details.
+
+use std::time::Duration;
+
+use crate::ics02_client::client_def::AnyConsensusState;
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::{ChannelEnd, State};
+use crate::ics04_channel::error::Error;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult, PacketResult};
+use crate::ics04_channel::packet::{Receipt, Sequence};
+use crate::ics05_port::context::PortReader;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// A context supplying all the necessary read-only dependencies for processing any `ChannelMsg` or
+/// `PacketMsg`. Channel handshakes only proceed on behalf of the module which bound the port, hence
+/// the port authentication dependencies. The client states and the time of the host chain come
+/// from the ICS2 context.
+pub trait ChannelReader: PortReader + ClientReader {
+    /// Returns the ChannelEnd for the given identifiers `port_id` and `channel_id`.
+    fn channel_end(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<ChannelEnd>;
+
+    /// Returns the ConnectionEnd for the given identifier `conn_id`.
+    fn connection_end(&self, conn_id: &ConnectionId) -> Option<ConnectionEnd>;
+
+    /// Returns the ConsensusState that the given client stores at a specific height.
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState>;
+
+    /// Returns the current height of the local chain.
+    fn host_current_height(&self) -> Height;
+
+    /// Returns the time of the host chain at which the given client got its consensus state at
+    /// `height`, i.e., the start of the delay period of proofs at this height.
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Timestamp>;
+
+    /// Returns the height of the host chain at which the given client got its consensus state at
+    /// `height`, i.e., the start of the delay period of proofs at this height in blocks.
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height>;
+
+    /// Returns the maximum time the host chain expects between two blocks, which converts the
+    /// delay period of connections into a number of blocks.
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    /// Returns the number of blocks of the host chain which cover the given delay period, rounding
+    /// up. Without an expected time per block, the delay period counts no blocks.
+    fn block_delay(&self, delay_period: Duration) -> u64 {
+        let max_time_per_block = self.max_expected_time_per_block().as_nanos();
+        if max_time_per_block == 0 {
+            return 0;
+        }
+        let delay = delay_period.as_nanos();
+        ((delay + max_time_per_block - 1) / max_time_per_block) as u64
+    }
+
+    /// Returns the sequence number of the next packet to be sent on the given channel end.
+    fn next_sequence_send(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence>;
+
+    /// Returns the sequence number of the next packet to be received on the given channel end.
+    fn next_sequence_recv(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence>;
+
+    /// Returns the sequence number of the next packet to be acknowledged on the given channel end.
+    fn next_sequence_ack(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence>;
+
+    /// Returns the commitment to the packet with sequence `seq` sent on the given channel end, if
+    /// this packet is neither acknowledged nor timed out yet.
+    fn packet_commitment(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Vec<u8>>;
+
+    /// Returns the receipt for the packet with sequence `seq` received on the given channel end.
+    fn packet_receipt(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Receipt>;
+
+    /// Returns the commitment to the acknowledgement written for the packet with sequence `seq`
+    /// received on the given channel end.
+    fn packet_acknowledgement(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Vec<u8>>;
+
+    /// Returns a counter on the number of channel ids which have been created thus far.
+    /// The value of this counter should increase only via method
+    /// `ChannelKeeper::increase_channel_counter`.
+    fn channel_counter(&self) -> u64;
+}
+
+/// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
+/// for processing any `ChannelMsg` or `PacketMsg`.
+pub trait ChannelKeeper {
+    fn store_channel_result(&mut self, result: ChannelResult) -> Result<(), Error> {
+        match result.channel_end.state() {
+            State::Init | State::TryOpen => {
+                self.store_channel(&result.port_id, &result.channel_id, &result.channel_end)?;
+                // If this is the first time the handler processed this channel, associate the
+                // channel end to its connection identifier. The handlers guarantee that a channel
+                // end always has exactly one connection hop.
+                self.store_connection_channels(
+                    &result.channel_end.connection_hops()[0],
+                    &result.port_id,
+                    &result.channel_id,
+                )?;
+                // Packet sequence numbers start from the beginning on a new channel end.
+                self.store_next_sequence_send(
+                    &result.port_id,
+                    &result.channel_id,
+                    Sequence::default(),
+                )?;
+                self.store_next_sequence_recv(
+                    &result.port_id,
+                    &result.channel_id,
+                    Sequence::default(),
+                )?;
+                self.store_next_sequence_ack(
+                    &result.port_id,
+                    &result.channel_id,
+                    Sequence::default(),
+                )?;
+            }
+            _ => {
+                self.store_channel(&result.port_id, &result.channel_id, &result.channel_end)?;
+            }
+        }
+
+        // The handler generated the identifier of the channel out of the counter.
+        if result.channel_id_state == ChannelIdState::Generated {
+            self.increase_channel_counter();
+        }
+        Ok(())
+    }
+
+    fn store_packet_result(&mut self, result: PacketResult) -> Result<(), Error> {
+        match result {
+            PacketResult::Send(res) => {
+                self.store_packet_commitment(
+                    &res.port_id,
+                    &res.channel_id,
+                    res.seq,
+                    res.commitment,
+                )?;
+                self.store_next_sequence_send(&res.port_id, &res.channel_id, res.seq_number)?;
+            }
+            PacketResult::Recv(res) => match res.receipt {
+                // Unordered channels keep track of every received packet individually.
+                Some(receipt) => {
+                    self.store_packet_receipt(&res.port_id, &res.channel_id, res.seq, receipt)?
+                }
+                None => {
+                    self.store_next_sequence_recv(&res.port_id, &res.channel_id, res.seq_number)?
+                }
+            },
+            PacketResult::WriteAck(res) => {
+                self.store_packet_acknowledgement(
+                    &res.port_id,
+                    &res.channel_id,
+                    res.seq,
+                    res.ack_commitment,
+                )?;
+            }
+            PacketResult::Ack(res) => {
+                self.delete_packet_commitment(&res.port_id, &res.channel_id, res.seq)?;
+                if let Some(seq_number) = res.seq_number {
+                    self.store_next_sequence_ack(&res.port_id, &res.channel_id, seq_number)?;
+                }
+            }
+            PacketResult::Timeout(res) => {
+                self.delete_packet_commitment(&res.port_id, &res.channel_id, res.seq)?;
+                // A timeout on an ordered channel closes the channel.
+                if let Some(channel_end) = res.channel {
+                    self.store_channel(&res.port_id, &res.channel_id, &channel_end)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the given channel_end at a path associated with the port_id and channel_id.
+    fn store_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), Error>;
+
+    /// Stores the given (port_id, channel_id) pair at a path associated with the connection_id.
+    fn store_connection_channels(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), Error>;
+
+    /// Stores the sequence number of the next packet to be sent on the given channel end.
+    fn store_next_sequence_send(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), Error>;
+
+    /// Stores the sequence number of the next packet to be received on the given channel end.
+    fn store_next_sequence_recv(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), Error>;
+
+    /// Stores the sequence number of the next packet to be acknowledged on the given channel end.
+    fn store_next_sequence_ack(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), Error>;
+
+    /// Stores the commitment to the packet with sequence `seq` sent on the given channel end.
+    fn store_packet_commitment(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        commitment: Vec<u8>,
+    ) -> Result<(), Error>;
+
+    /// Deletes the commitment to the packet with sequence `seq` sent on the given channel end.
+    fn delete_packet_commitment(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), Error>;
+
+    /// Stores the receipt for the packet with sequence `seq` received on the given channel end.
+    fn store_packet_receipt(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        receipt: Receipt,
+    ) -> Result<(), Error>;
+
+    /// Stores the commitment to the acknowledgement of the packet with sequence `seq` received on
+    /// the given channel end.
+    fn store_packet_acknowledgement(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        ack_commitment: Vec<u8>,
+    ) -> Result<(), Error>;
+
+    /// Called upon channel identifier creation (Init or Try message processing).
+    /// Increases the counter which keeps track of how many channels have been created.
+    /// Should never fail.
+    fn increase_channel_counter(&mut self);
+}
@@ -1,6 +1,12 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics04_channel::channel::State;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
+use crate::Height;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error)]
@@ -46,6 +52,116 @@ pub enum Kind {
 
     #[error("missing channel end")]
     MissingChannel,
+
+    #[error("port {0} is not bound")]
+    UnboundPort(PortId),
+
+    #[error("the capability does not authenticate port {0}")]
+    InvalidPortCapability(PortId),
+
+    #[error("channel end ({0}, {1}) already exists")]
+    ChannelExistsAlready(PortId, ChannelId),
+
+    #[error("channel end ({0}, {1}) does not exist")]
+    ChannelNotFound(PortId, ChannelId),
+
+    #[error("channel end ({0}, {1}) does not match the expected channel end")]
+    ChannelMismatch(PortId, ChannelId),
+
+    #[error("channel end ({0}, {1}) is in an invalid state {2:?}")]
+    InvalidChannelState(PortId, ChannelId, State),
+
+    #[error("the counterparty chosen channel id {0} does not match the local channel id {1}")]
+    ChannelIdMismatch(ChannelId, ChannelId),
+
+    #[error("the counterparty channel identifier is missing")]
+    MissingCounterpartyChannelId,
+
+    #[error("the connection end {0} does not exist")]
+    MissingConnection(ConnectionId),
+
+    #[error("the connection end {0} is not open")]
+    ConnectionNotOpen(ConnectionId),
+
+    #[error("the connection end {0} must have a single negotiated version")]
+    InvalidVersionLengthConnection(ConnectionId),
+
+    #[error("the version of the connection end {0} does not support {1} channels")]
+    ChannelFeatureNotSupportedByConnection(ConnectionId, String),
+
+    #[error("the connection end {0} has no counterparty connection identifier")]
+    MissingCounterpartyConnection(ConnectionId),
+
+    #[error("the client {0} running locally is missing")]
+    MissingClient(ClientId),
+
+    #[error("the client {0} running locally is frozen")]
+    FrozenClient(ClientId),
+
+    #[error("the client {0} has no consensus state at height {1}")]
+    MissingClientConsensusState(ClientId, Height),
+
+    #[error("the client {0} has no processed time for its consensus state at height {1}")]
+    MissingClientUpdateTime(ClientId, Height),
+
+    #[error("the client {0} has no processed height for its consensus state at height {1}")]
+    MissingClientUpdateHeight(ClientId, Height),
+
+    #[error("verification of the channel state failed")]
+    ChannelVerificationFailure,
+
+    #[error("packet destination ({0}, {1}) does not match the counterparty of the channel end")]
+    InvalidPacketCounterparty(PortId, ChannelId),
+
+    #[error("missing next send sequence number for channel end ({0}, {1})")]
+    MissingNextSendSeq(PortId, ChannelId),
+
+    #[error("missing next receive sequence number for channel end ({0}, {1})")]
+    MissingNextRecvSeq(PortId, ChannelId),
+
+    #[error("missing next acknowledgement sequence number for channel end ({0}, {1})")]
+    MissingNextAckSeq(PortId, ChannelId),
+
+    #[error("invalid packet sequence {0}, the expected sequence is {1}")]
+    InvalidPacketSequence(Sequence, Sequence),
+
+    #[error("the packet timeout height {0} is already reached by the counterparty height {1}")]
+    LowPacketHeight(Height, Height),
+
+    #[error(
+        "the packet timeout timestamp {0} is already reached by the counterparty timestamp {1}"
+    )]
+    LowPacketTimestamp(Timestamp, Timestamp),
+
+    #[error("the client {0} does not track the time of the counterparty chain, so packets cannot time out on a timestamp")]
+    MissingCounterpartyTimestamp(ClientId),
+
+    #[error("the packet timeout height {0} has been reached on the receiving chain")]
+    PacketTimeoutHeightReached(Height),
+
+    #[error("the packet timeout timestamp {0} has been reached on the receiving chain")]
+    PacketTimeoutTimestampReached(Timestamp),
+
+    #[error("the packet timeout (height {0}, timestamp {2}) is not reached by the proof (height {1}, timestamp {3})")]
+    PacketTimeoutNotReached(Height, Height, Timestamp, Timestamp),
+
+    #[error("the packet with sequence {0} was already received")]
+    PacketAlreadyReceived(Sequence),
+
+    #[error("the packet with sequence {0} was already acknowledged")]
+    PacketAlreadyAcknowledged(Sequence),
+
+    #[error("the commitment for the packet with sequence {0} is missing")]
+    MissingPacketCommitment(Sequence),
+
+    #[error("the commitment for the packet with sequence {0} does not match the packet")]
+    IncorrectPacketCommitment(Sequence),
+
+    #[error("the acknowledgement for a packet cannot be empty")]
+    InvalidAcknowledgement,
+
+    #[error("verification of the packet data failed")]
+    PacketVerificationFailure,
 }
 
 impl Kind {
@@ -2,6 +2,7 @@
 use crate::attribute;
 use crate::events::{IBCEvent, RawObject};
 use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::timestamp::Timestamp;
 use anomaly::BoxError;
 use serde_derive::{Deserialize, Serialize};
 use std::convert::TryFrom;
@@ -172,7 +173,7 @@ pub struct SendPacket {
     pub packet_dst_channel: ChannelId,
     pub packet_sequence: u64,
     pub packet_timeout_height: u64,
-    pub packet_timeout_stamp: u64,
+    pub packet_timeout_stamp: Timestamp,
 }
 
 impl TryFrom<RawObject> for SendPacket {
@@ -206,7 +207,7 @@ pub struct ReceivePacket {
     pub packet_dst_channel: ChannelId,
     pub packet_sequence: u64,
     pub packet_timeout_height: u64,
-    pub packet_timeout_stamp: u64,
+    pub packet_timeout_stamp: Timestamp,
     pub packet_ack: String,
 }
 
@@ -242,7 +243,7 @@ pub struct AcknowledgePacket {
     pub packet_dst_channel: ChannelId,
     pub packet_sequence: u64,
     pub packet_timeout_height: u64,
-    pub packet_timeout_stamp: u64,
+    pub packet_timeout_stamp: Timestamp,
 }
 
 impl TryFrom<RawObject> for AcknowledgePacket {
@@ -276,7 +277,7 @@ pub struct CleanupPacket {
     pub packet_dst_channel: ChannelId,
     pub packet_sequence: u64,
     pub packet_timeout_height: u64,
-    pub packet_timeout_stamp: u64,
+    pub packet_timeout_stamp: Timestamp,
 }
 
 impl TryFrom<RawObject> for CleanupPacket {
@@ -310,7 +311,7 @@ pub struct TimeoutPacket {
     pub packet_dst_channel: ChannelId,
     pub packet_sequence: u64,
     pub packet_timeout_height: u64,
-    pub packet_timeout_stamp: u64,
+    pub packet_timeout_stamp: Timestamp,
 }
 
 impl TryFrom<RawObject> for TimeoutPacket {
@@ -0,0 +1,222 @@
+//! This module implements the processing logic for ICS4 (channel) messages.
+
+use crate::handler::{Event, EventType, HandlerOutput};
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::Error;
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+pub mod acknowledgement;
+pub mod chan_close_confirm;
+pub mod chan_close_init;
+pub mod chan_open_ack;
+pub mod chan_open_confirm;
+pub mod chan_open_init;
+pub mod chan_open_try;
+pub mod recv_packet;
+pub mod send_packet;
+pub mod timeout;
+pub mod timeout_on_close;
+mod verify;
+pub mod write_acknowledgement;
+
+#[derive(Clone, Debug)]
+pub enum ChannelEvent {
+    ChanOpenInit(ChannelResult),
+    ChanOpenTry(ChannelResult),
+    ChanOpenAck(ChannelResult),
+    ChanOpenConfirm(ChannelResult),
+    ChanCloseInit(ChannelResult),
+    ChanCloseConfirm(ChannelResult),
+}
+
+/// Defines the possible states of a channel identifier in a `ChannelResult`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChannelIdState {
+    /// Specifies that the channel handshake handler allocated a new channel identifier. This
+    /// happens during the processing of either the `MsgChannelOpenInit` or `MsgChannelOpenTry`.
+    Generated,
+
+    /// Specifies that the handler reused a previously-allocated channel identifier.
+    Reused,
+}
+
+#[derive(Clone, Debug)]
+pub struct ChannelResult {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub channel_id_state: ChannelIdState,
+    pub channel_end: ChannelEnd,
+}
+
+impl ChannelResult {
+    /// The attributes of any channel event. Relayers rely on these (e.g., to build a `CloseInit`
+    /// out of a "channel_close_init" event), so the keys match those in `ics04_channel::events`.
+    fn attributes(&self) -> Vec<(String, String)> {
+        vec![
+            ("port_id".to_string(), self.port_id.to_string()),
+            ("channel_id".to_string(), self.channel_id.to_string()),
+        ]
+    }
+}
+
+impl From<ChannelEvent> for Event {
+    fn from(ev: ChannelEvent) -> Event {
+        match ev {
+            ChannelEvent::ChanOpenInit(chan) => Event::new(
+                EventType::Custom("channel_open_init".to_string()),
+                chan.attributes(),
+            ),
+            ChannelEvent::ChanOpenTry(chan) => Event::new(
+                EventType::Custom("channel_open_try".to_string()),
+                chan.attributes(),
+            ),
+            ChannelEvent::ChanOpenAck(chan) => Event::new(
+                EventType::Custom("channel_open_ack".to_string()),
+                chan.attributes(),
+            ),
+            ChannelEvent::ChanOpenConfirm(chan) => Event::new(
+                EventType::Custom("channel_open_confirm".to_string()),
+                chan.attributes(),
+            ),
+            ChannelEvent::ChanCloseInit(chan) => Event::new(
+                EventType::Custom("channel_close_init".to_string()),
+                chan.attributes(),
+            ),
+            ChannelEvent::ChanCloseConfirm(chan) => Event::new(
+                EventType::Custom("channel_close_confirm".to_string()),
+                chan.attributes(),
+            ),
+        }
+    }
+}
+
+/// General entry point for processing any message of the ICS4 channel open & close handshakes.
+/// The `capability` is the one held by the module owning the port of the channel.
+pub fn dispatch<Ctx>(
+    ctx: &Ctx,
+    capability: &Capability,
+    msg: ChannelMsg,
+) -> Result<HandlerOutput<ChannelResult>, Error>
+where
+    Ctx: ChannelReader,
+{
+    Ok(match msg {
+        ChannelMsg::ChannelOpenInit(msg) => chan_open_init::process(ctx, capability, msg)?,
+        ChannelMsg::ChannelOpenTry(msg) => chan_open_try::process(ctx, capability, *msg)?,
+        ChannelMsg::ChannelOpenAck(msg) => chan_open_ack::process(ctx, capability, msg)?,
+        ChannelMsg::ChannelOpenConfirm(msg) => chan_open_confirm::process(ctx, capability, msg)?,
+        ChannelMsg::ChannelCloseInit(msg) => chan_close_init::process(ctx, capability, msg)?,
+        ChannelMsg::ChannelCloseConfirm(msg) => chan_close_confirm::process(ctx, capability, msg)?,
+    })
+}
+
+#[derive(Clone, Debug)]
+pub enum PacketEvent {
+    SendPacket(Packet),
+    ReceivePacket(Packet),
+    WriteAcknowledgement(Packet, Vec<u8>),
+    AcknowledgePacket(Packet),
+    TimeoutPacket(Packet),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketResult {
+    Send(send_packet::Result),
+    Recv(recv_packet::Result),
+    WriteAck(write_acknowledgement::Result),
+    Ack(acknowledgement::Result),
+    Timeout(timeout::Result),
+}
+
+/// The attributes of any packet event, with the same keys as in `ics04_channel::events`.
+fn packet_attributes(packet: &Packet) -> Vec<(String, String)> {
+    vec![
+        (
+            "packet_src_port".to_string(),
+            packet.source_port.to_string(),
+        ),
+        (
+            "packet_src_channel".to_string(),
+            packet.source_channel.to_string(),
+        ),
+        (
+            "packet_dst_port".to_string(),
+            packet.destination_port.to_string(),
+        ),
+        (
+            "packet_dst_channel".to_string(),
+            packet.destination_channel.to_string(),
+        ),
+        ("packet_sequence".to_string(), packet.sequence.to_string()),
+        (
+            "packet_timeout_height".to_string(),
+            format!(
+                "{}-{}",
+                packet.timeout_height.version_number, packet.timeout_height.version_height
+            ),
+        ),
+        (
+            "packet_timeout_timestamp".to_string(),
+            packet.timeout_timestamp.to_string(),
+        ),
+    ]
+}
+
+impl From<PacketEvent> for Event {
+    fn from(ev: PacketEvent) -> Event {
+        match ev {
+            PacketEvent::SendPacket(packet) => Event::new(
+                EventType::Custom("send_packet".to_string()),
+                packet_attributes(&packet),
+            ),
+            PacketEvent::ReceivePacket(packet) => Event::new(
+                EventType::Custom("recv_packet".to_string()),
+                packet_attributes(&packet),
+            ),
+            PacketEvent::WriteAcknowledgement(packet, ack) => {
+                let mut attributes = packet_attributes(&packet);
+                attributes.push((
+                    "packet_ack".to_string(),
+                    String::from_utf8_lossy(&ack).into_owned(),
+                ));
+                Event::new(
+                    EventType::Custom("write_acknowledgement".to_string()),
+                    attributes,
+                )
+            }
+            PacketEvent::AcknowledgePacket(packet) => Event::new(
+                EventType::Custom("acknowledge_packet".to_string()),
+                packet_attributes(&packet),
+            ),
+            PacketEvent::TimeoutPacket(packet) => Event::new(
+                EventType::Custom("timeout_packet".to_string()),
+                packet_attributes(&packet),
+            ),
+        }
+    }
+}
+
+/// General entry point for processing any ICS4 packet message, i.e., the messages which relay
+/// packets or their outcome. Sending a packet, as well as writing the acknowledgement of a received
+/// packet, are not triggered by messages: applications do so via the functions
+/// `send_packet::send_packet` and `write_acknowledgement::write_acknowledgement`.
+/// The `capability` is the one held by the module owning the port on this end of the packet flow.
+pub fn packet_dispatch<Ctx>(
+    ctx: &Ctx,
+    capability: &Capability,
+    msg: PacketMsg,
+) -> Result<HandlerOutput<PacketResult>, Error>
+where
+    Ctx: ChannelReader,
+{
+    Ok(match msg {
+        PacketMsg::RecvPacket(msg) => recv_packet::process(ctx, capability, *msg)?,
+        PacketMsg::Acknowledgement(msg) => acknowledgement::process(ctx, capability, *msg)?,
+        PacketMsg::Timeout(msg) => timeout::process(ctx, capability, *msg)?,
+        PacketMsg::TimeoutOnClose(msg) => timeout_on_close::process(ctx, capability, *msg)?,
+    })
+}
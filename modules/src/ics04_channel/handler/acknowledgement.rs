@@ -0,0 +1,303 @@
+//! Protocol logic specific to ICS4 messages of type `MsgAcknowledgement`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{
+    verify_packet_acknowledgement_proofs, verify_port_capability,
+};
+use crate::ics04_channel::handler::PacketEvent::AcknowledgePacket;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::packet::{ack_commitment, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The result following the successful processing of a `MsgAcknowledgement` message. Preferably
+/// this data type should be used with a qualified name `acknowledgement::Result` to avoid
+/// ambiguity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Result {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The sequence of the packet which was acknowledged.
+    pub seq: Sequence,
+    /// The sequence of the next packet to acknowledge, only for ordered channels.
+    pub seq_number: Option<Sequence>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgAcknowledgement,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    // Only the module which bound the port may act on its packets.
+    verify_port_capability(ctx, &packet.source_port, capability)?;
+
+    let source_channel_end = ctx
+        .channel_end(&packet.source_port, &packet.source_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+
+    if !source_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            source_channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // The packet must have been addressed to the counterparty of the source channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_hops = source_channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(packet.source_channel.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    if !conn.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(connection_hops[0].clone()).into());
+    }
+
+    // The packet must have been sent, and neither acknowledged nor timed out yet.
+    let commitment = ctx
+        .packet_commitment(&packet.source_port, &packet.source_channel, packet.sequence)
+        .ok_or(Kind::MissingPacketCommitment(packet.sequence))?;
+    if commitment != packet.commitment() {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    verify_packet_acknowledgement_proofs(
+        ctx,
+        packet,
+        &ack_commitment(msg.acknowledgement()),
+        &conn,
+        msg.proofs(),
+    )?;
+
+    output.log("success: packet acknowledgement verification passed");
+
+    // Ordered channels process acknowledgements strictly in the order the packets were sent.
+    let seq_number = if source_channel_end.order_matches(&Order::Ordered) {
+        let next_seq_ack = ctx
+            .next_sequence_ack(&packet.source_port, &packet.source_channel)
+            .ok_or_else(|| {
+                Kind::MissingNextAckSeq(packet.source_port.clone(), packet.source_channel.clone())
+            })?;
+        if packet.sequence != next_seq_ack {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_ack).into());
+        }
+        Some(next_seq_ack.increment())
+    } else {
+        None
+    };
+
+    let result = PacketResult::Ack(Result {
+        port_id: packet.source_port.clone(),
+        channel_id: packet.source_channel.clone(),
+        seq: packet.sequence,
+        seq_number,
+    });
+
+    output.emit(AcknowledgePacket(packet.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::acknowledgement::test_util::get_dummy_raw_msg_acknowledgement;
+    use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn ack_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: PacketMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        let mut raw_msg = get_dummy_raw_msg_acknowledgement(proof_height);
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.sequence = 1;
+        }
+        let msg_ack = MsgAcknowledgement::try_from(raw_msg).unwrap();
+        let packet = msg_ack.packet().clone();
+
+        let context = MockContext::default().with_port_capability(packet.source_port.clone());
+        let capability = context.lookup_module_by_port(&packet.source_port).unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let ordered_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Ordered,
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+        );
+
+        // A context where the packet was sent, but its commitment is missing.
+        let no_commitment_ctx = context
+            .clone()
+            .with_client(&client_id, Height::new(1, proof_height))
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_channel(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                ordered_chan_end,
+            )
+            .with_sequences(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                Sequence::from(2),
+                Sequence::from(1),
+                Sequence::from(1),
+            );
+
+        let ready_ctx = no_commitment_ctx.clone().with_packet_commitment(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+            packet.commitment(),
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.source_port),
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment is missing".to_string(),
+                ctx: no_commitment_ctx,
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment does not match".to_string(),
+                ctx: ready_ctx.clone().with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    vec![0],
+                ),
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the ordered channel expects another sequence"
+                    .to_string(),
+                ctx: ready_ctx.clone().with_sequences(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    Sequence::from(3),
+                    Sequence::from(1),
+                    Sequence::from(2),
+                ),
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: ready_ctx,
+                msg: PacketMsg::Acknowledgement(Box::new(msg_ack)),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = packet_dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "ack_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // On an ordered channel, the next sequence to acknowledge must advance.
+                    match proto_output.result {
+                        PacketResult::Ack(res) => {
+                            assert_eq!(res.seq, packet.sequence);
+                            assert_eq!(res.seq_number, Some(Sequence::from(2)));
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::Ack!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("acknowledge_packet".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "ack_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
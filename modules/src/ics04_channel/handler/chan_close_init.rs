@@ -0,0 +1,235 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelCloseInit`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::State;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::verify_port_capability;
+use crate::ics04_channel::handler::ChannelEvent::ChanCloseInit;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::ics05_port::capabilities::Capability;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgChannelCloseInit,
+) -> HandlerResult<ChannelResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the port may act on its channels.
+    verify_port_capability(ctx, msg.port_id(), capability)?;
+
+    // Unwrap the old channel end and validate it against the message.
+    let mut channel_end = ctx
+        .channel_end(msg.port_id(), msg.channel_id())
+        .ok_or_else(|| Kind::ChannelNotFound(msg.port_id().clone(), msg.channel_id().clone()))?;
+
+    // Validate that the channel end is in a state where it can be closed.
+    if channel_end.state_matches(&State::Closed) {
+        return Err(Kind::InvalidChannelState(
+            msg.port_id().clone(),
+            msg.channel_id().clone(),
+            channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // An IBC connection running on the local (host) chain should exist, and it must be open.
+    let connection_hops = channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(msg.channel_id().to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    if !conn.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(connection_hops[0].clone()).into());
+    }
+
+    output.log("success: channel can be closed");
+
+    // Transition the channel end to the final state.
+    channel_end.set_state(State::Closed);
+
+    let result = ChannelResult {
+        port_id: msg.port_id().clone(),
+        channel_id: msg.channel_id().clone(),
+        channel_id_state: ChannelIdState::Reused,
+        channel_end,
+    };
+
+    output.emit(ChanCloseInit(result.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::handler::{dispatch, ChannelResult};
+    use crate::ics04_channel::msgs::chan_close_init::test_util::get_dummy_raw_msg_chan_close_init;
+    use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+    use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn chan_close_init_msg_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: ChannelMsg,
+            want_pass: bool,
+        }
+
+        let msg_chan_close_init =
+            MsgChannelCloseInit::try_from(get_dummy_raw_msg_chan_close_init()).unwrap();
+
+        let context =
+            MockContext::default().with_port_capability(msg_chan_close_init.port_id().clone());
+        let capability = context
+            .lookup_module_by_port(msg_chan_close_init.port_id())
+            .unwrap();
+
+        let init_conn_end = get_dummy_connection_end(
+            ConnectionState::Init,
+            &ClientId::from_str("mock_clientid").unwrap(),
+        );
+
+        let mut open_conn_end = init_conn_end.clone();
+        open_conn_end.set_state(ConnectionState::Open);
+
+        let open_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                PortId::from_str("cpartyport").unwrap(),
+                Some(ChannelId::from_str("cpartychannel").unwrap()),
+            ),
+        );
+
+        let mut closed_chan_end = open_chan_end.clone();
+        closed_chan_end.set_state(State::Closed);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(msg_chan_close_init.port_id()),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), open_conn_end.clone()),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the channel is already closed".to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), open_conn_end.clone())
+                    .with_channel(
+                        msg_chan_close_init.port_id().clone(),
+                        msg_chan_close_init.channel_id().clone(),
+                        closed_chan_end,
+                    ),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the connection is not open".to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), init_conn_end)
+                    .with_channel(
+                        msg_chan_close_init.port_id().clone(),
+                        msg_chan_close_init.channel_id().clone(),
+                        open_chan_end.clone(),
+                    ),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the port is not bound".to_string(),
+                ctx: MockContext::default()
+                    .with_connection(ConnectionId::default(), open_conn_end.clone())
+                    .with_channel(
+                        msg_chan_close_init.port_id().clone(),
+                        msg_chan_close_init.channel_id().clone(),
+                        open_chan_end.clone(),
+                    ),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: context
+                    .with_connection(ConnectionId::default(), open_conn_end)
+                    .with_channel(
+                        msg_chan_close_init.port_id().clone(),
+                        msg_chan_close_init.channel_id().clone(),
+                        open_chan_end,
+                    ),
+                msg: ChannelMsg::ChannelCloseInit(msg_chan_close_init.clone()),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "chan_close_init: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The object in the output is a ChannelEnd, should have Closed state.
+                    let res: ChannelResult = proto_output.result;
+                    assert_eq!(&res.channel_id, msg_chan_close_init.channel_id());
+                    assert_eq!(res.channel_end.state().clone(), State::Closed);
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("channel_close_init".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "chan_close_init: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelOpenAck`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{verify_port_capability, verify_proofs};
+use crate::ics04_channel::handler::ChannelEvent::ChanOpenAck;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics05_port::capabilities::Capability;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgChannelOpenAck,
+) -> HandlerResult<ChannelResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the port may act on its channels.
+    verify_port_capability(ctx, &msg.port_id, capability)?;
+
+    // Unwrap the old channel end and validate it against the message.
+    let mut channel_end = ctx
+        .channel_end(&msg.port_id, &msg.channel_id)
+        .ok_or_else(|| Kind::ChannelNotFound(msg.port_id.clone(), msg.channel_id.clone()))?;
+
+    // Validate that the channel end is in a state where it can be ack'ed.
+    if !channel_end.state_matches(&State::Init) && !channel_end.state_matches(&State::TryOpen) {
+        return Err(Kind::InvalidChannelState(
+            msg.port_id,
+            msg.channel_id,
+            channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // An IBC connection running on the local (host) chain should exist, and it must be open.
+    let connection_hops = channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(msg.channel_id.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    if !conn.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(connection_hops[0].clone()).into());
+    }
+
+    // Proof verification in two steps:
+    // 1. Setup: build the ChannelEnd as we expect to find it on the other party.
+    let counterparty_connection_id = conn
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| Kind::MissingCounterpartyConnection(connection_hops[0].clone()))?;
+
+    let expected_channel_end = ChannelEnd::new(
+        State::TryOpen,
+        *channel_end.ordering(),
+        Counterparty::new(msg.port_id.clone(), Some(msg.channel_id.clone())),
+        vec![counterparty_connection_id],
+        msg.counterparty_version.clone(),
+    );
+
+    // 2. Pass the details to the verification function.
+    verify_proofs(
+        ctx,
+        &channel_end,
+        &conn,
+        &msg.counterparty_channel_id,
+        &expected_channel_end,
+        &msg.proofs,
+    )?;
+
+    output.log("success: channel verification passed");
+
+    // Transition the channel end to the new state & adopt the counterparty version.
+    channel_end.set_state(State::Open);
+    channel_end.set_version(msg.counterparty_version.clone());
+    channel_end.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+    let result = ChannelResult {
+        port_id: msg.port_id,
+        channel_id: msg.channel_id,
+        channel_id_state: ChannelIdState::Reused,
+        channel_end,
+    };
+
+    output.emit(ChanOpenAck(result.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::handler::{dispatch, ChannelResult};
+    use crate::ics04_channel::msgs::chan_open_ack::test_util::get_dummy_raw_msg_chan_open_ack;
+    use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+    use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn chan_open_ack_msg_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: ChannelMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let msg_chan_ack =
+            MsgChannelOpenAck::try_from(get_dummy_raw_msg_chan_open_ack(proof_height)).unwrap();
+
+        let context = MockContext::default().with_port_capability(msg_chan_ack.port_id.clone());
+        let capability = context
+            .lookup_module_by_port(&msg_chan_ack.port_id)
+            .unwrap();
+
+        let init_conn_end = get_dummy_connection_end(ConnectionState::Init, &client_id);
+
+        let mut open_conn_end = init_conn_end.clone();
+        open_conn_end.set_state(ConnectionState::Open);
+
+        let init_chan_end = get_dummy_channel_end(
+            State::Init,
+            Order::default(),
+            Counterparty::new(PortId::from_str("cpartyport").unwrap(), None),
+        );
+
+        let mut open_chan_end = init_chan_end.clone();
+        open_chan_end.set_state(State::Open);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&msg_chan_ack.port_id),
+                msg: ChannelMsg::ChannelOpenAck(msg_chan_ack.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end.clone()),
+                msg: ChannelMsg::ChannelOpenAck(msg_chan_ack.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the channel is in the wrong state".to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end.clone())
+                    .with_channel(
+                        msg_chan_ack.port_id.clone(),
+                        msg_chan_ack.channel_id.clone(),
+                        open_chan_end,
+                    ),
+                msg: ChannelMsg::ChannelOpenAck(msg_chan_ack.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the connection is not open".to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), init_conn_end)
+                    .with_channel(
+                        msg_chan_ack.port_id.clone(),
+                        msg_chan_ack.channel_id.clone(),
+                        init_chan_end.clone(),
+                    ),
+                msg: ChannelMsg::ChannelOpenAck(msg_chan_ack.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: context
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end)
+                    .with_channel(
+                        msg_chan_ack.port_id.clone(),
+                        msg_chan_ack.channel_id.clone(),
+                        init_chan_end,
+                    ),
+                msg: ChannelMsg::ChannelOpenAck(msg_chan_ack.clone()),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "chan_open_ack: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The object in the output is a ChannelEnd, should have Open state and should
+                    // now know about the counterparty channel end.
+                    let res: ChannelResult = proto_output.result;
+                    assert_eq!(res.channel_id, msg_chan_ack.channel_id.clone());
+                    assert_eq!(res.channel_end.state().clone(), State::Open);
+                    assert_eq!(
+                        res.channel_end.counterparty().channel_id(),
+                        Some(&msg_chan_ack.counterparty_channel_id)
+                    );
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("channel_open_ack".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "chan_open_ack: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
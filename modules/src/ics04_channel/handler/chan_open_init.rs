@@ -0,0 +1,214 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelOpenInit`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::channel::{ChannelEnd, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{
+    verify_connection_supports_order, verify_port_capability,
+};
+use crate::ics04_channel::handler::ChannelEvent::ChanOpenInit;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::ChannelId;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgChannelOpenInit,
+) -> HandlerResult<ChannelResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the port may act on its channels.
+    verify_port_capability(ctx, &msg.port_id, capability)?;
+
+    // Construct the identifier for the new channel out of the channel counter of the host chain.
+    // The identifier in the message is not used.
+    let channel_id = ChannelId::new(ctx.channel_counter());
+
+    // Channel identifier (port, channel) must not exist in the store.
+    if ctx.channel_end(&msg.port_id, &channel_id).is_some() {
+        return Err(Kind::ChannelExistsAlready(msg.port_id, channel_id).into());
+    }
+
+    output.log("success: no channel found");
+
+    // A channel must be built on top of exactly one connection.
+    let connection_hops = msg.channel.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(channel_id.to_string())
+            .into());
+    }
+
+    // An IBC connection running on the local (host) chain should exist, and its version must
+    // support the ordering of the channel.
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    verify_connection_supports_order(&connection_hops[0], &conn, msg.channel.ordering())?;
+
+    let new_channel_end = ChannelEnd::new(
+        State::Init,
+        *msg.channel.ordering(),
+        msg.channel.counterparty(),
+        connection_hops,
+        msg.channel.version(),
+    );
+
+    output.log("success: channel end initialized");
+
+    let result = ChannelResult {
+        port_id: msg.port_id,
+        channel_id,
+        channel_id_state: ChannelIdState::Generated,
+        channel_end: new_channel_end,
+    };
+
+    output.emit(ChanOpenInit(result.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::version::Version;
+    use crate::ics04_channel::channel::State;
+    use crate::ics04_channel::handler::{dispatch, ChannelIdState, ChannelResult};
+    use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init;
+    use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+    use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn chan_open_init_msg_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: ChannelMsg,
+            want_pass: bool,
+        }
+
+        let msg_chan_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+
+        let context = MockContext::default().with_port_capability(msg_chan_init.port_id.clone());
+        let capability = context
+            .lookup_module_by_port(&msg_chan_init.port_id)
+            .unwrap();
+
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let init_conn_end = get_dummy_connection_end(ConnectionState::Init, &client_id);
+
+        // A connection whose version supports no channel ordering.
+        let mut unsupported_conn_end = init_conn_end.clone();
+        unsupported_conn_end
+            .set_version(Version::new("1".to_string(), vec!["ORDER_RANDOM".to_string()]).unwrap());
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&msg_chan_init.port_id),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no connection exists in the context".to_string(),
+                ctx: context.clone(),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the channel exists in the context".to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), init_conn_end.clone())
+                    .with_channel(
+                        msg_chan_init.port_id.clone(),
+                        ChannelId::new(0),
+                        msg_chan_init.channel.clone(),
+                    ),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the port is not bound".to_string(),
+                ctx: MockContext::default()
+                    .with_connection(ConnectionId::default(), init_conn_end.clone()),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name:
+                    "Processing fails because the connection does not support the channel ordering"
+                        .to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), unsupported_conn_end),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: context.with_connection(ConnectionId::default(), init_conn_end),
+                msg: ChannelMsg::ChannelOpenInit(msg_chan_init.clone()),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "chan_open_init: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The object in the output is a ChannelEnd, should have init state. Its
+                    // identifier is the first one which the host generates.
+                    let res: ChannelResult = proto_output.result;
+                    assert_eq!(res.port_id, msg_chan_init.port_id.clone());
+                    assert_eq!(res.channel_id, ChannelId::new(0));
+                    assert_eq!(res.channel_id_state, ChannelIdState::Generated);
+                    assert_eq!(res.channel_end.state().clone(), State::Init);
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("channel_open_init".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "chan_open_init: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
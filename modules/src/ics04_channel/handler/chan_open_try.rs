@@ -0,0 +1,305 @@
+//! Protocol logic specific to ICS4 messages of type `MsgChannelOpenTry`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{ChannelEnd, Counterparty, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{
+    verify_connection_supports_order, verify_port_capability, verify_proofs,
+};
+use crate::ics04_channel::handler::ChannelEvent::ChanOpenTry;
+use crate::ics04_channel::handler::{ChannelIdState, ChannelResult};
+use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::ChannelId;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgChannelOpenTry,
+) -> HandlerResult<ChannelResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the port may act on its channels.
+    verify_port_capability(ctx, &msg.port_id, capability)?;
+
+    if let Some(chosen_id) = msg.counterparty_chosen_channel_id.clone() {
+        if chosen_id != msg.channel_id {
+            return Err(Kind::ChannelIdMismatch(chosen_id, msg.channel_id).into());
+        }
+    }
+
+    // A channel must be built on top of exactly one connection.
+    let connection_hops = msg.channel.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(msg.channel_id.to_string())
+            .into());
+    }
+
+    // Unwrap the old channel end (if any) and validate it against the message. An old end exists
+    // when both chains initialized the channel; the message then names this end. Otherwise, the
+    // new channel end gets an identifier out of the channel counter of the host chain.
+    let (mut new_channel_end, channel_id, channel_id_state) = match ctx
+        .channel_end(&msg.port_id, &msg.channel_id)
+    {
+        Some(old_channel_end) => {
+            // Validate that existing channel end matches with the one we're trying to establish.
+            if old_channel_end.state_matches(&State::Init)
+                && old_channel_end.ordering() == msg.channel.ordering()
+                && old_channel_end.counterparty().port_id() == msg.channel.counterparty().port_id()
+                && old_channel_end.connection_hops() == connection_hops
+                && old_channel_end.version() == msg.channel.version()
+            {
+                // A ChannelEnd already exists and all validation passed.
+                Ok((
+                    old_channel_end,
+                    msg.channel_id.clone(),
+                    ChannelIdState::Reused,
+                ))
+            } else {
+                // A ChannelEnd already exists and validation failed.
+                Err(Into::<Error>::into(Kind::ChannelMismatch(
+                    msg.port_id.clone(),
+                    msg.channel_id.clone(),
+                )))
+            }
+        }
+        // No ChannelEnd exists for this (port, channel) pair. Create & return a new one.
+        None => {
+            let channel_id = ChannelId::new(ctx.channel_counter());
+            if ctx.channel_end(&msg.port_id, &channel_id).is_some() {
+                return Err(Kind::ChannelExistsAlready(msg.port_id, channel_id).into());
+            }
+
+            Ok((msg.channel.clone(), channel_id, ChannelIdState::Generated))
+        }
+    }?;
+
+    // An IBC connection running on the local (host) chain should exist, and it must be open.
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    if !conn.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(connection_hops[0].clone()).into());
+    }
+    verify_connection_supports_order(&connection_hops[0], &conn, msg.channel.ordering())?;
+
+    // The channel end on the counterparty, which the proof attests to.
+    let counterparty_channel_id = msg
+        .channel
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or(Kind::MissingCounterpartyChannelId)?;
+
+    // Proof verification in two steps:
+    // 1. Setup: build the ChannelEnd as we expect to find it on the other party.
+    let counterparty_connection_id = conn
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| Kind::MissingCounterpartyConnection(connection_hops[0].clone()))?;
+
+    let expected_channel_end = ChannelEnd::new(
+        State::Init,
+        *msg.channel.ordering(),
+        Counterparty::new(
+            msg.port_id.clone(),
+            msg.counterparty_chosen_channel_id.clone(),
+        ),
+        vec![counterparty_connection_id],
+        msg.counterparty_version.clone(),
+    );
+
+    // 2. Pass the details to the verification function.
+    verify_proofs(
+        ctx,
+        &new_channel_end,
+        &conn,
+        &counterparty_channel_id,
+        &expected_channel_end,
+        &msg.proofs,
+    )?;
+
+    output.log("success: channel verification passed");
+
+    // Transition the channel end to the new state.
+    new_channel_end.set_state(State::TryOpen);
+    new_channel_end.set_counterparty_channel_id(counterparty_channel_id);
+
+    let result = ChannelResult {
+        port_id: msg.port_id,
+        channel_id,
+        channel_id_state,
+        channel_end: new_channel_end,
+    };
+
+    output.emit(ChanOpenTry(result.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::State;
+    use crate::ics04_channel::handler::{dispatch, ChannelIdState, ChannelResult};
+    use crate::ics04_channel::msgs::chan_open_try::test_util::get_dummy_raw_msg_chan_open_try;
+    use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+    use crate::ics04_channel::msgs::ChannelMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn chan_open_try_msg_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: ChannelMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let msg_chan_try =
+            MsgChannelOpenTry::try_from(get_dummy_raw_msg_chan_open_try(proof_height)).unwrap();
+
+        let context = MockContext::default().with_port_capability(msg_chan_try.port_id.clone());
+        let capability = context
+            .lookup_module_by_port(&msg_chan_try.port_id)
+            .unwrap();
+
+        let init_conn_end = get_dummy_connection_end(ConnectionState::Init, &client_id);
+
+        let mut open_conn_end = init_conn_end.clone();
+        open_conn_end.set_state(ConnectionState::Open);
+
+        // A channel end that already exists, but cannot be used for crossing hellos.
+        let mut open_chan_end = msg_chan_try.channel.clone();
+        open_chan_end.set_state(State::Open);
+
+        // The relayer chose a channel identifier which does not match the one in the message.
+        let mut msg_chan_try_mismatch = msg_chan_try.clone();
+        msg_chan_try_mismatch.counterparty_chosen_channel_id =
+            Some(ChannelId::from_str("otherchannel").unwrap());
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&msg_chan_try.port_id),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no connection exists in the context".to_string(),
+                ctx: context.clone(),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the connection is not open".to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), init_conn_end),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the client is missing".to_string(),
+                ctx: context
+                    .clone()
+                    .with_connection(ConnectionId::default(), open_conn_end.clone()),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the existing channel end is not in Init state"
+                    .to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end.clone())
+                    .with_channel(
+                        msg_chan_try.port_id.clone(),
+                        msg_chan_try.channel_id.clone(),
+                        open_chan_end,
+                    ),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because of mismatching chosen channel identifier"
+                    .to_string(),
+                ctx: context
+                    .clone()
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end.clone()),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try_mismatch)),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: context
+                    .with_client(&client_id, Height::new(1, proof_height))
+                    .with_connection(ConnectionId::default(), open_conn_end),
+                msg: ChannelMsg::ChannelOpenTry(Box::new(msg_chan_try.clone())),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "chan_open_try: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The object in the output is a ChannelEnd, should have TryOpen state. No
+                    // channel end existed, so the end got the first identifier of the host.
+                    let res: ChannelResult = proto_output.result;
+                    assert_eq!(res.channel_id, ChannelId::new(0));
+                    assert_eq!(res.channel_id_state, ChannelIdState::Generated);
+                    assert_eq!(res.channel_end.state().clone(), State::TryOpen);
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("channel_open_try".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "chan_open_try: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,438 @@
+//! Protocol logic specific to ICS4 messages of type `MsgRecvPacket`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics03_connection::connection::State as ConnectionState;
+use crate::ics04_channel::channel::{Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{verify_packet_recv_proofs, verify_port_capability};
+use crate::ics04_channel::handler::PacketEvent::ReceivePacket;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::packet::{Receipt, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The result following the successful processing of a `MsgRecvPacket` message. Preferably this
+/// data type should be used with a qualified name `recv_packet::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Result {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The sequence of the packet which was received.
+    pub seq: Sequence,
+    /// The sequence of the next packet to receive on this channel end.
+    pub seq_number: Sequence,
+    /// The receipt for this packet. Only unordered channels store receipts; ordered channels
+    /// advance `seq_number` instead.
+    pub receipt: Option<Receipt>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgRecvPacket,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    // Only the module which bound the port may act on its packets.
+    verify_port_capability(ctx, &packet.destination_port, capability)?;
+
+    let dest_channel_end = ctx
+        .channel_end(&packet.destination_port, &packet.destination_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            )
+        })?;
+
+    if !dest_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            dest_channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // The packet must come from the counterparty of the destination channel end.
+    let counterparty = dest_channel_end.counterparty();
+    if counterparty.port_id() != &packet.source_port
+        || counterparty.channel_id() != Some(&packet.source_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_hops = dest_channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(packet.destination_channel.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+    if !conn.state_matches(&ConnectionState::Open) {
+        return Err(Kind::ConnectionNotOpen(connection_hops[0].clone()).into());
+    }
+
+    // The packet cannot be received once the local chain reached the timeout height or timestamp.
+    let current_height = ctx.host_current_height();
+    if !packet.timeout_height.is_zero() && current_height >= packet.timeout_height {
+        return Err(Kind::PacketTimeoutHeightReached(packet.timeout_height).into());
+    }
+
+    if packet
+        .timeout_timestamp
+        .has_expired_at(&ctx.host_timestamp())
+    {
+        return Err(Kind::PacketTimeoutTimestampReached(packet.timeout_timestamp).into());
+    }
+
+    verify_packet_recv_proofs(ctx, packet, &conn, msg.proofs())?;
+
+    output.log("success: packet receipt verification passed");
+
+    let next_seq_recv = ctx
+        .next_sequence_recv(&packet.destination_port, &packet.destination_channel)
+        .ok_or_else(|| {
+            Kind::MissingNextRecvSeq(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            )
+        })?;
+
+    let result = if dest_channel_end.order_matches(&Order::Ordered) {
+        // Ordered channels receive packets strictly in the order they were sent.
+        if packet.sequence != next_seq_recv {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_recv).into());
+        }
+
+        PacketResult::Recv(Result {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            seq: packet.sequence,
+            seq_number: next_seq_recv.increment(),
+            receipt: None,
+        })
+    } else {
+        // Unordered channels receive any packet, but at most once.
+        if ctx
+            .packet_receipt(
+                &packet.destination_port,
+                &packet.destination_channel,
+                packet.sequence,
+            )
+            .is_some()
+        {
+            return Err(Kind::PacketAlreadyReceived(packet.sequence).into());
+        }
+
+        PacketResult::Recv(Result {
+            port_id: packet.destination_port.clone(),
+            channel_id: packet.destination_channel.clone(),
+            seq: packet.sequence,
+            seq_number: next_seq_recv,
+            receipt: Some(Receipt::Ok),
+        })
+    };
+
+    output.emit(ReceivePacket(packet.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tendermint_proto::Protobuf;
+
+    use crate::handler::EventType;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::{ConnectionEnd, State as ConnectionState};
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
+    use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+        get_dummy_delayed_connection_end,
+    };
+    use crate::ics04_channel::packet::{Receipt, Sequence};
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn recv_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: PacketMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        // The dummy packet has sequence 0 and times out at `proof_height`, which is beyond the
+        // current height of the default mock context.
+        let mut raw_msg = get_dummy_raw_msg_recv_packet(proof_height);
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.sequence = 1;
+        }
+        let msg_recv_packet = MsgRecvPacket::try_from(raw_msg.clone()).unwrap();
+        let packet = msg_recv_packet.packet().clone();
+
+        // A packet whose timeout timestamp passed on the (local) receiving chain, whose time is
+        // that of its latest block, i.e., `HostBlock::tm_block_time` of its latest height.
+        let mut raw_msg_expired = raw_msg.clone();
+        if let Some(raw_packet) = raw_msg_expired.packet.as_mut() {
+            raw_packet.timeout_timestamp = 1;
+        }
+        let msg_timestamp_expired = MsgRecvPacket::try_from(raw_msg_expired).unwrap();
+
+        // A packet which timed out on the (local) receiving chain.
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.timeout_height.as_mut().unwrap().version_height = 1;
+        }
+        let msg_timed_out = MsgRecvPacket::try_from(raw_msg).unwrap();
+
+        let context = MockContext::default().with_port_capability(packet.destination_port.clone());
+        let capability = context
+            .lookup_module_by_port(&packet.destination_port)
+            .unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let counterparty = Counterparty::new(
+            packet.source_port.clone(),
+            Some(packet.source_channel.clone()),
+        );
+        let unordered_chan_end =
+            get_dummy_channel_end(State::Open, Order::Unordered, counterparty.clone());
+        let ordered_chan_end = get_dummy_channel_end(State::Open, Order::Ordered, counterparty);
+
+        // A context which is ready to receive the packet, except for the channel end.
+        let base_ctx = context
+            .clone()
+            .with_client(&client_id, Height::new(1, proof_height))
+            .with_connection(ConnectionId::default(), open_conn_end.clone())
+            .with_sequences(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            );
+
+        let unordered_ctx = base_ctx.clone().with_channel(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            unordered_chan_end.clone(),
+        );
+
+        // The same context over a loopback client, which looks up the commitment to the packet in
+        // the host store instead of verifying a proof of it.
+        let loopback_ctx = context
+            .clone()
+            .with_client_parametrized(
+                &client_id,
+                Height::new(1, proof_height),
+                Some(ClientType::Localhost),
+                None,
+            )
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_sequences(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            )
+            .with_channel(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                unordered_chan_end,
+            );
+
+        // The same context, but the connection delays the use of proofs by an hour after each
+        // client update. The client got its consensus state upon building this context. The
+        // connection end goes through its encoding on the wire, which carries the delay.
+        let delayed_conn_end = get_dummy_delayed_connection_end(
+            ConnectionState::Open,
+            &client_id,
+            Duration::from_secs(3600),
+        );
+        let delayed_conn_end =
+            ConnectionEnd::decode(delayed_conn_end.encode_vec().unwrap().as_slice()).unwrap();
+        let delayed_ctx = unordered_ctx
+            .clone()
+            .with_connection(ConnectionId::default(), delayed_conn_end);
+
+        let ordered_ctx = base_ctx.with_channel(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            ordered_chan_end,
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.destination_port),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet timed out".to_string(),
+                ctx: unordered_ctx.clone(),
+                msg: PacketMsg::RecvPacket(Box::new(msg_timed_out)),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet timeout timestamp passed".to_string(),
+                ctx: unordered_ctx.clone(),
+                msg: PacketMsg::RecvPacket(Box::new(msg_timestamp_expired)),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet was already received".to_string(),
+                ctx: unordered_ctx.clone().with_packet_receipt(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                    packet.sequence,
+                    Receipt::Ok,
+                ),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the ordered channel expects another sequence"
+                    .to_string(),
+                ctx: ordered_ctx.clone().with_sequences(
+                    packet.destination_port.clone(),
+                    packet.destination_channel.clone(),
+                    Sequence::from(1),
+                    Sequence::from(2),
+                    Sequence::from(1),
+                ),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the connection delay period did not pass"
+                    .to_string(),
+                ctx: delayed_ctx,
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, unordered channel".to_string(),
+                ctx: unordered_ctx,
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, ordered channel".to_string(),
+                ctx: ordered_ctx,
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: true,
+            },
+            Test {
+                name: "Processing fails because the host store has no commitment to the packet"
+                    .to_string(),
+                ctx: loopback_ctx.clone(),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the host store commits to another packet"
+                    .to_string(),
+                ctx: loopback_ctx.clone().with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    vec![0; 32],
+                ),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, loopback client".to_string(),
+                ctx: loopback_ctx.with_packet_commitment(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    packet.sequence,
+                    packet.commitment(),
+                ),
+                msg: PacketMsg::RecvPacket(Box::new(msg_recv_packet)),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = packet_dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "recv_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // Either the receipt is stored, or the next sequence to receive is advanced.
+                    match proto_output.result {
+                        PacketResult::Recv(res) => {
+                            assert_eq!(res.seq, packet.sequence);
+                            if res.receipt.is_none() {
+                                assert_eq!(res.seq_number, Sequence::from(2));
+                            }
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::Recv!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("recv_packet".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "recv_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Protocol logic for sending an ICS4 packet. Unlike the other handlers of ICS4, this one is not
+//! triggered by a message: the application which owns the source port calls it directly.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::state::{ClientState, ConsensusState};
+use crate::ics04_channel::channel::State;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::verify_port_capability;
+use crate::ics04_channel::handler::PacketEvent::SendPacket;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The result following the successful processing of `send_packet`. Preferably this data type
+/// should be used with a qualified name `send_packet::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Result {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The sequence of the packet which was sent.
+    pub seq: Sequence,
+    /// The sequence of the next packet to send on this channel end.
+    pub seq_number: Sequence,
+    pub commitment: Vec<u8>,
+}
+
+pub fn send_packet(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    packet: Packet,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the source port may send packets from it.
+    verify_port_capability(ctx, &packet.source_port, capability)?;
+
+    let source_channel_end = ctx
+        .channel_end(&packet.source_port, &packet.source_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+
+    if source_channel_end.state_matches(&State::Closed) {
+        return Err(Kind::InvalidChannelState(
+            packet.source_port,
+            packet.source_channel,
+            source_channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // The packet must be addressed to the counterparty of the source channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port,
+            packet.destination_channel,
+        )
+        .into());
+    }
+
+    let connection_hops = source_channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(packet.source_channel.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+
+    // The packet must not have timed out already, judging by the latest height and timestamp of
+    // the counterparty chain that the local client knows of.
+    let client_id = conn.client_id();
+    let client_state = ctx
+        .client_state(client_id)
+        .ok_or_else(|| Kind::MissingClient(client_id.clone()))?;
+    let latest_height = client_state.latest_height();
+    if !packet.timeout_height.is_zero() && latest_height >= packet.timeout_height {
+        return Err(Kind::LowPacketHeight(packet.timeout_height, latest_height).into());
+    }
+
+    if !packet.timeout_timestamp.is_none() {
+        let latest_timestamp = ctx
+            .client_consensus_state(client_id, latest_height)
+            .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), latest_height))?
+            .timestamp();
+        // A timeout timestamp which the counterparty can never prove reached would lock the packet.
+        if latest_timestamp.is_none() {
+            return Err(Kind::MissingCounterpartyTimestamp(client_id.clone()).into());
+        }
+        if packet.timeout_timestamp.has_expired_at(&latest_timestamp) {
+            return Err(
+                Kind::LowPacketTimestamp(packet.timeout_timestamp, latest_timestamp).into(),
+            );
+        }
+    }
+
+    let next_seq_send = ctx
+        .next_sequence_send(&packet.source_port, &packet.source_channel)
+        .ok_or_else(|| {
+            Kind::MissingNextSendSeq(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+    if packet.sequence != next_seq_send {
+        return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_send).into());
+    }
+
+    output.log("success: packet send");
+
+    let result = PacketResult::Send(Result {
+        port_id: packet.source_port.clone(),
+        channel_id: packet.source_channel.clone(),
+        seq: packet.sequence,
+        seq_number: next_seq_send.increment(),
+        commitment: packet.commitment(),
+    });
+
+    output.emit(SendPacket(packet));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::send_packet::send_packet;
+    use crate::ics04_channel::handler::PacketResult;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+        get_dummy_raw_packet,
+    };
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::mock::host::HostBlock;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn send_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            packet: Packet,
+            want_pass: bool,
+        }
+
+        let client_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        let mut packet = Packet::try_from(get_dummy_raw_packet(client_height + 10)).unwrap();
+        packet.sequence = Sequence::from(1);
+
+        let mut packet_timed_out = packet.clone();
+        packet_timed_out.timeout_height = Height::new(1, client_height);
+
+        let mut packet_wrong_seq = packet.clone();
+        packet_wrong_seq.sequence = Sequence::from(2);
+
+        // The consensus state of the Tendermint client is timestamped with its block time.
+        let client_time = HostBlock::tm_block_time(client_height);
+        let mut packet_timestamp_expired = packet.clone();
+        packet_timestamp_expired.timeout_timestamp = Timestamp::from_nanoseconds(1);
+
+        let mut packet_timestamp_pending = packet.clone();
+        packet_timestamp_pending.timeout_timestamp =
+            Timestamp::from_nanoseconds((client_time + 3_600) * 1_000_000_000);
+
+        let context = MockContext::default().with_port_capability(packet.source_port.clone());
+        let capability = context.lookup_module_by_port(&packet.source_port).unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let open_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+        );
+
+        let ready_ctx = context
+            .clone()
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_channel(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                open_chan_end,
+            )
+            .with_sequences(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            );
+
+        // Mock clients do not track time, unlike Tendermint clients.
+        let tm_ctx = ready_ctx.clone().with_client_parametrized(
+            &client_id,
+            Height::new(1, client_height),
+            Some(ClientType::Tendermint),
+            None,
+        );
+        let ready_ctx = ready_ctx.with_client(&client_id, Height::new(1, client_height));
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.source_port),
+                packet: packet.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                packet: packet.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the counterparty already passed the timeout height"
+                    .to_string(),
+                ctx: ready_ctx.clone(),
+                packet: packet_timed_out,
+                want_pass: false,
+            },
+            Test {
+                name:
+                    "Processing fails because the counterparty already passed the timeout timestamp"
+                        .to_string(),
+                ctx: tm_ctx.clone(),
+                packet: packet_timestamp_expired,
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the client does not track the counterparty time"
+                    .to_string(),
+                ctx: ready_ctx.clone(),
+                packet: packet_timestamp_pending.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, timeout timestamp ahead of the counterparty".to_string(),
+                ctx: tm_ctx,
+                packet: packet_timestamp_pending,
+                want_pass: true,
+            },
+            Test {
+                name: "Processing fails because the packet sequence is not the next one to send"
+                    .to_string(),
+                ctx: ready_ctx.clone(),
+                packet: packet_wrong_seq,
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: ready_ctx,
+                packet: packet.clone(),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = send_packet(&test.ctx, &capability, test.packet.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "send_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.packet.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The output should commit to the packet and move on to the next sequence.
+                    match proto_output.result {
+                        PacketResult::Send(res) => {
+                            assert_eq!(res.seq, test.packet.sequence);
+                            assert_eq!(res.seq_number, Sequence::from(2));
+                            assert_eq!(res.commitment, test.packet.commitment());
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::Send!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("send_packet".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "send_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.packet,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Protocol logic specific to ICS4 messages of type `MsgTimeout`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics02_client::state::ConsensusState;
+use crate::ics04_channel::channel::{ChannelEnd, Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{
+    verify_next_sequence_recv, verify_packet_receipt_absence, verify_port_capability,
+};
+use crate::ics04_channel::handler::PacketEvent::TimeoutPacket;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The result following the successful processing of a `MsgTimeout` message. Preferably this data
+/// type should be used with a qualified name `timeout::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Result {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    /// The sequence of the packet which timed out.
+    pub seq: Sequence,
+    /// The closed channel end, only for ordered channels.
+    pub channel: Option<ChannelEnd>,
+}
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgTimeout,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    // Only the module which bound the port may act on its packets.
+    verify_port_capability(ctx, &packet.source_port, capability)?;
+
+    let mut source_channel_end = ctx
+        .channel_end(&packet.source_port, &packet.source_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+
+    if !source_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            source_channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // The packet must have been addressed to the counterparty of the source channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_hops = source_channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(packet.source_channel.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+
+    // The packet must have been sent, and neither acknowledged nor timed out yet.
+    let commitment = ctx
+        .packet_commitment(&packet.source_port, &packet.source_channel, packet.sequence)
+        .ok_or(Kind::MissingPacketCommitment(packet.sequence))?;
+    if commitment != packet.commitment() {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    // The proof must have been created once the counterparty chain reached the timeout height or
+    // timestamp. The timestamp of the counterparty at the proof height is that of the consensus
+    // state which the local client stores at this height.
+    let proof_height = msg.proofs().height();
+    let height_reached = !packet.timeout_height.is_zero() && proof_height >= packet.timeout_height;
+    if !height_reached {
+        let client_id = conn.client_id();
+        let proof_timestamp = ctx
+            .client_consensus_state(client_id, proof_height)
+            .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proof_height))?
+            .timestamp();
+        if !packet.timeout_timestamp.has_expired_at(&proof_timestamp) {
+            return Err(Kind::PacketTimeoutNotReached(
+                packet.timeout_height,
+                proof_height,
+                packet.timeout_timestamp,
+                proof_timestamp,
+            )
+            .into());
+        }
+    }
+
+    let channel = if source_channel_end.order_matches(&Order::Ordered) {
+        // The counterparty did not receive the packet if it still expects a lower sequence.
+        let next_seq_recv = *msg.next_sequence_recv();
+        if next_seq_recv > packet.sequence {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_recv).into());
+        }
+        verify_next_sequence_recv(ctx, packet, next_seq_recv, &conn, msg.proofs())?;
+
+        // A timeout breaks the ordering guarantee, hence the channel closes.
+        source_channel_end.set_state(State::Closed);
+        Some(source_channel_end)
+    } else {
+        verify_packet_receipt_absence(ctx, packet, &conn, msg.proofs())?;
+        None
+    };
+
+    output.log("success: packet timeout verification passed");
+
+    let result = PacketResult::Timeout(Result {
+        port_id: packet.source_port.clone(),
+        channel_id: packet.source_channel.clone(),
+        seq: packet.sequence,
+        channel,
+    });
+
+    output.emit(TimeoutPacket(packet.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::timeout::test_util::get_dummy_raw_msg_timeout;
+    use crate::ics04_channel::msgs::timeout::MsgTimeout;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn timeout_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: PacketMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        // The dummy message proves a timeout at exactly the timeout height of its packet.
+        let mut raw_msg = get_dummy_raw_msg_timeout(proof_height);
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.sequence = 1;
+        }
+        raw_msg.next_sequence_recv = 1;
+        let msg_timeout = MsgTimeout::try_from(raw_msg.clone()).unwrap();
+        let packet = msg_timeout.packet().clone();
+
+        // A packet which does not time out before the proof height.
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.timeout_height.as_mut().unwrap().version_height = proof_height + 1;
+        }
+        let msg_early = MsgTimeout::try_from(raw_msg.clone()).unwrap();
+        let early_packet = msg_early.packet().clone();
+
+        // Neither does it time out before the timestamp of the counterparty at the proof height,
+        // which for Tendermint clients is the block time `HostBlock::tm_block_time(proof_height)`.
+        let mut raw_msg_pending = raw_msg.clone();
+        if let Some(raw_packet) = raw_msg_pending.packet.as_mut() {
+            raw_packet.timeout_timestamp = u64::MAX;
+        }
+        let msg_pending = MsgTimeout::try_from(raw_msg_pending).unwrap();
+        let pending_packet = msg_pending.packet().clone();
+
+        // The counterparty claims to have received the packet already.
+        raw_msg.packet = Some(packet.clone().into());
+        raw_msg.next_sequence_recv = 2;
+        let msg_received = MsgTimeout::try_from(raw_msg).unwrap();
+
+        let context = MockContext::default().with_port_capability(packet.source_port.clone());
+        let capability = context.lookup_module_by_port(&packet.source_port).unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let unordered_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+        );
+
+        let ordered_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Ordered,
+            unordered_chan_end.counterparty(),
+        );
+
+        // A context where the packet was sent, except for the channel end.
+        let base_ctx = context
+            .clone()
+            .with_client(&client_id, Height::new(1, proof_height))
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_packet_commitment(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                packet.sequence,
+                packet.commitment(),
+            );
+
+        let unordered_ctx = base_ctx.clone().with_channel(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            unordered_chan_end,
+        );
+
+        let ordered_ctx = base_ctx.with_channel(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            ordered_chan_end,
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.source_port),
+                msg: PacketMsg::Timeout(Box::new(msg_timeout.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: PacketMsg::Timeout(Box::new(msg_timeout.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the timeout height is not reached".to_string(),
+                ctx: unordered_ctx.clone().with_packet_commitment(
+                    early_packet.source_port.clone(),
+                    early_packet.source_channel.clone(),
+                    early_packet.sequence,
+                    early_packet.commitment(),
+                ),
+                msg: PacketMsg::Timeout(Box::new(msg_early)),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the timeout timestamp is not reached".to_string(),
+                ctx: unordered_ctx
+                    .clone()
+                    .with_client_parametrized(
+                        &client_id,
+                        Height::new(1, proof_height),
+                        Some(ClientType::Tendermint),
+                        None,
+                    )
+                    .with_packet_commitment(
+                        pending_packet.source_port.clone(),
+                        pending_packet.source_channel.clone(),
+                        pending_packet.sequence,
+                        pending_packet.commitment(),
+                    ),
+                msg: PacketMsg::Timeout(Box::new(msg_pending)),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the counterparty received the packet".to_string(),
+                ctx: ordered_ctx.clone(),
+                msg: PacketMsg::Timeout(Box::new(msg_received)),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, unordered channel".to_string(),
+                ctx: unordered_ctx,
+                msg: PacketMsg::Timeout(Box::new(msg_timeout.clone())),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, ordered channel".to_string(),
+                ctx: ordered_ctx,
+                msg: PacketMsg::Timeout(Box::new(msg_timeout)),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = packet_dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "timeout_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // A timeout on an ordered channel closes that channel.
+                    match proto_output.result {
+                        PacketResult::Timeout(res) => {
+                            assert_eq!(res.seq, packet.sequence);
+                            if let Some(channel_end) = res.channel {
+                                assert_eq!(channel_end.state().clone(), State::Closed);
+                            }
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::Timeout!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("timeout_packet".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "timeout_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
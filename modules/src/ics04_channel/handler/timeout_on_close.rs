@@ -0,0 +1,307 @@
+//! Protocol logic specific to ICS4 messages of type `MsgTimeoutOnClose`.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::{
+    verify_next_sequence_recv, verify_packet_receipt_absence, verify_port_capability, verify_proofs,
+};
+use crate::ics04_channel::handler::PacketEvent::TimeoutPacket;
+use crate::ics04_channel::handler::{timeout, PacketResult};
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use crate::ics05_port::capabilities::Capability;
+use crate::proofs::Proofs;
+
+pub(crate) fn process(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    msg: MsgTimeoutOnClose,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let packet = msg.packet();
+
+    // Only the module which bound the port may act on its packets.
+    verify_port_capability(ctx, &packet.source_port, capability)?;
+
+    // Unlike a regular timeout, the source channel end need not be open: closing the counterparty
+    // end is exactly what prevents the packet from ever being received.
+    let mut source_channel_end = ctx
+        .channel_end(&packet.source_port, &packet.source_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(packet.source_port.clone(), packet.source_channel.clone())
+        })?;
+
+    // The packet must have been addressed to the counterparty of the source channel end.
+    let counterparty = source_channel_end.counterparty();
+    if counterparty.port_id() != &packet.destination_port
+        || counterparty.channel_id() != Some(&packet.destination_channel)
+    {
+        return Err(Kind::InvalidPacketCounterparty(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+        )
+        .into());
+    }
+
+    let connection_hops = source_channel_end.connection_hops();
+    if connection_hops.len() != 1 {
+        return Err(Kind::InvalidConnectionHopsLength
+            .context(packet.source_channel.to_string())
+            .into());
+    }
+    let conn = ctx
+        .connection_end(&connection_hops[0])
+        .ok_or_else(|| Kind::MissingConnection(connection_hops[0].clone()))?;
+
+    // The packet must have been sent, and neither acknowledged nor timed out yet.
+    let commitment = ctx
+        .packet_commitment(&packet.source_port, &packet.source_channel, packet.sequence)
+        .ok_or(Kind::MissingPacketCommitment(packet.sequence))?;
+    if commitment != packet.commitment() {
+        return Err(Kind::IncorrectPacketCommitment(packet.sequence).into());
+    }
+
+    // Proof verification of the counterparty channel closure, in two steps:
+    // 1. Setup: build the ChannelEnd as we expect to find it on the other party.
+    let counterparty_connection_id = conn
+        .counterparty()
+        .connection_id()
+        .cloned()
+        .ok_or_else(|| Kind::MissingCounterpartyConnection(connection_hops[0].clone()))?;
+
+    let expected_channel_end = ChannelEnd::new(
+        State::Closed,
+        *source_channel_end.ordering(),
+        Counterparty::new(
+            packet.source_port.clone(),
+            Some(packet.source_channel.clone()),
+        ),
+        vec![counterparty_connection_id],
+        source_channel_end.version(),
+    );
+
+    // 2. Pass the details to the verification function. The closure proof is taken at the same
+    // height as the proof of non-receipt.
+    let proofs_close = Proofs::new(msg.proof_close().clone(), None, None, msg.proofs().height())
+        .map_err(|e| Kind::InvalidProof.context(e))?;
+    verify_proofs(
+        ctx,
+        &source_channel_end,
+        &conn,
+        &packet.destination_channel,
+        &expected_channel_end,
+        &proofs_close,
+    )?;
+
+    let channel = if source_channel_end.order_matches(&Order::Ordered) {
+        // The counterparty did not receive the packet if it still expects a lower sequence.
+        let next_seq_recv = *msg.next_sequence_recv();
+        if next_seq_recv > packet.sequence {
+            return Err(Kind::InvalidPacketSequence(packet.sequence, next_seq_recv).into());
+        }
+        verify_next_sequence_recv(ctx, packet, next_seq_recv, &conn, msg.proofs())?;
+
+        source_channel_end.set_state(State::Closed);
+        Some(source_channel_end)
+    } else {
+        verify_packet_receipt_absence(ctx, packet, &conn, msg.proofs())?;
+        None
+    };
+
+    output.log("success: packet timeout on close verification passed");
+
+    let result = PacketResult::Timeout(timeout::Result {
+        port_id: packet.source_port.clone(),
+        channel_id: packet.source_channel.clone(),
+        seq: packet.sequence,
+        channel,
+    });
+
+    output.emit(TimeoutPacket(packet.clone()));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::{packet_dispatch, PacketResult};
+    use crate::ics04_channel::msgs::timeout_on_close::test_util::get_dummy_raw_msg_timeout_on_close;
+    use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+    use crate::ics04_channel::msgs::PacketMsg;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics05_port::context::PortReader;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn timeout_on_close_packet_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: PacketMsg,
+            want_pass: bool,
+        }
+
+        let proof_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        let mut raw_msg = get_dummy_raw_msg_timeout_on_close(proof_height);
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.sequence = 1;
+        }
+        raw_msg.next_sequence_recv = 1;
+        let msg_toc = MsgTimeoutOnClose::try_from(raw_msg.clone()).unwrap();
+        let packet = msg_toc.packet().clone();
+
+        // The counterparty claims to have received the packet already.
+        raw_msg.next_sequence_recv = 2;
+        let msg_received = MsgTimeoutOnClose::try_from(raw_msg).unwrap();
+
+        let context = MockContext::default().with_port_capability(packet.source_port.clone());
+        let capability = context.lookup_module_by_port(&packet.source_port).unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let unordered_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(
+                packet.destination_port.clone(),
+                Some(packet.destination_channel.clone()),
+            ),
+        );
+
+        let ordered_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Ordered,
+            unordered_chan_end.counterparty(),
+        );
+
+        // A context where the packet was sent, except for the channel end.
+        let base_ctx = context
+            .clone()
+            .with_client(&client_id, Height::new(1, proof_height))
+            .with_connection(ConnectionId::default(), open_conn_end);
+
+        let unordered_ctx = base_ctx
+            .clone()
+            .with_channel(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                unordered_chan_end.clone(),
+            )
+            .with_packet_commitment(
+                packet.source_port.clone(),
+                packet.source_channel.clone(),
+                packet.sequence,
+                packet.commitment(),
+            );
+
+        let ordered_ctx = unordered_ctx.clone().with_channel(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            ordered_chan_end,
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.source_port),
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_toc.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_toc.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the packet commitment is missing".to_string(),
+                ctx: base_ctx.with_channel(
+                    packet.source_port.clone(),
+                    packet.source_channel.clone(),
+                    unordered_chan_end,
+                ),
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_toc.clone())),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the counterparty received the packet".to_string(),
+                ctx: ordered_ctx.clone(),
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_received)),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, unordered channel".to_string(),
+                ctx: unordered_ctx,
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_toc.clone())),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, ordered channel".to_string(),
+                ctx: ordered_ctx,
+                msg: PacketMsg::TimeoutOnClose(Box::new(msg_toc)),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = packet_dispatch(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "timeout_on_close: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // A timeout on an ordered channel closes that channel.
+                    match proto_output.result {
+                        PacketResult::Timeout(res) => {
+                            assert_eq!(res.seq, packet.sequence);
+                            if let Some(channel_end) = res.channel {
+                                assert_eq!(channel_end.state().clone(), State::Closed);
+                            }
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::Timeout!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(e.tpe, EventType::Custom("timeout_packet".to_string()));
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "timeout_on_close: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,338 @@
+//! ICS4 verification functions, common across the handlers of ICS4.
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics02_client::state::{ClientState, ConsensusState};
+use crate::ics02_client::{client_def::AnyClient, client_def::ClientDef};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::handler::verify::verify_delay_passed;
+use crate::ics04_channel::channel::{ChannelEnd, Order};
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
+use crate::ics09_localhost::verify as localhost;
+use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use crate::proofs::Proofs;
+use crate::Height;
+
+/// Authenticates the module acting on the given port: the port must be bound, and `capability`,
+/// which the calling module holds, must be the one issued for it.
+pub fn verify_port_capability(
+    ctx: &dyn ChannelReader,
+    port_id: &PortId,
+    capability: &Capability,
+) -> Result<(), Error> {
+    if ctx.lookup_module_by_port(port_id).is_none() {
+        return Err(Kind::UnboundPort(port_id.clone()).into());
+    }
+
+    if !ctx.authenticate(capability, port_id) {
+        return Err(Kind::InvalidPortCapability(port_id.clone()).into());
+    }
+
+    Ok(())
+}
+
+/// Checks that a channel of the given ordering may be opened over the connection `connection_id`,
+/// whose single negotiated version must support this ordering as a feature.
+pub fn verify_connection_supports_order(
+    connection_id: &ConnectionId,
+    connection_end: &ConnectionEnd,
+    order: &Order,
+) -> Result<(), Error> {
+    let versions = connection_end.versions();
+    if versions.len() != 1 {
+        return Err(Kind::InvalidVersionLengthConnection(connection_id.clone()).into());
+    }
+
+    if !versions[0].supports_order(order) {
+        return Err(Kind::ChannelFeatureNotSupportedByConnection(
+            connection_id.clone(),
+            order.as_string().to_string(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Entry point for verifying all proofs bundled in any ICS4 channel handshake message.
+/// The commitment claims to prove that a channel end with identifier `channel_id` exists on the
+/// counterparty chain (i.e., the chain which created this proof) under the counterparty port of
+/// `channel_end`, and that it matches the state of `expected_chan`.
+pub fn verify_proofs(
+    ctx: &dyn ChannelReader,
+    channel_end: &ChannelEnd,
+    connection_end: &ConnectionEnd,
+    channel_id: &ChannelId,
+    expected_chan: &ChannelEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    // A loopback client reads the counterparty channel end from the host store instead.
+    if let Some(client_state) = loopback_client(ctx, connection_end) {
+        return Ok(localhost::verify_channel_state(
+            ctx,
+            &client_state,
+            proofs.height(),
+            channel_end.counterparty().port_id(),
+            channel_id,
+            expected_chan,
+        )
+        .map_err(|e| Kind::ChannelVerificationFailure.context(e.to_string()))?);
+    }
+
+    let (client_def, client_state, consensus_state) =
+        client_for_proof(ctx, connection_end, proofs.height())?;
+
+    // Verify the proof for the channel state against the expected channel end.
+    Ok(client_def
+        .verify_channel_state(
+            &client_state,
+            proofs.height(),
+            consensus_state.root(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            channel_end.counterparty().port_id(),
+            channel_id,
+            expected_chan,
+        )
+        .map_err(|e| Kind::ChannelVerificationFailure.context(e.to_string()))?)
+}
+
+/// Verifies the proof that the counterparty chain, which sent the `packet`, stores a commitment
+/// to this packet.
+pub fn verify_packet_recv_proofs(
+    ctx: &dyn ChannelReader,
+    packet: &Packet,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    if let Some(client_state) = loopback_client(ctx, connection_end) {
+        return Ok(localhost::verify_packet_data(
+            ctx,
+            &client_state,
+            proofs.height(),
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+            &packet.commitment(),
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?);
+    }
+
+    let (client_def, client_state, consensus_state) =
+        client_for_proof(ctx, connection_end, proofs.height())?;
+
+    // The proof may only be used once the delay period of the connection passed.
+    verify_connection_delay_passed(ctx, connection_end, proofs.height())?;
+
+    Ok(client_def
+        .verify_packet_data(
+            &client_state,
+            proofs.height(),
+            consensus_state.root(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+            &packet.commitment(),
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?)
+}
+
+/// Verifies the proof that the counterparty chain, which received the `packet`, stores the
+/// commitment `ack_commitment` to its acknowledgement.
+pub fn verify_packet_acknowledgement_proofs(
+    ctx: &dyn ChannelReader,
+    packet: &Packet,
+    ack_commitment: &[u8],
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    if let Some(client_state) = loopback_client(ctx, connection_end) {
+        return Ok(localhost::verify_packet_acknowledgement(
+            ctx,
+            &client_state,
+            proofs.height(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+            ack_commitment,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?);
+    }
+
+    let (client_def, client_state, consensus_state) =
+        client_for_proof(ctx, connection_end, proofs.height())?;
+
+    // The proof may only be used once the delay period of the connection passed.
+    verify_connection_delay_passed(ctx, connection_end, proofs.height())?;
+
+    Ok(client_def
+        .verify_packet_acknowledgement(
+            &client_state,
+            proofs.height(),
+            consensus_state.root(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+            ack_commitment,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?)
+}
+
+/// Verifies the proof that the next sequence which the counterparty chain expects to receive on the
+/// (ordered) destination channel of `packet` is `next_sequence_recv`.
+pub fn verify_next_sequence_recv(
+    ctx: &dyn ChannelReader,
+    packet: &Packet,
+    next_sequence_recv: Sequence,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    if let Some(client_state) = loopback_client(ctx, connection_end) {
+        return Ok(localhost::verify_next_sequence_recv(
+            ctx,
+            &client_state,
+            proofs.height(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            next_sequence_recv,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?);
+    }
+
+    let (client_def, client_state, consensus_state) =
+        client_for_proof(ctx, connection_end, proofs.height())?;
+
+    // The proof may only be used once the delay period of the connection passed.
+    verify_connection_delay_passed(ctx, connection_end, proofs.height())?;
+
+    Ok(client_def
+        .verify_next_sequence_recv(
+            &client_state,
+            proofs.height(),
+            consensus_state.root(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            next_sequence_recv,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?)
+}
+
+/// Verifies the proof that the counterparty chain did not receive the `packet` on the (unordered)
+/// destination channel.
+pub fn verify_packet_receipt_absence(
+    ctx: &dyn ChannelReader,
+    packet: &Packet,
+    connection_end: &ConnectionEnd,
+    proofs: &Proofs,
+) -> Result<(), Error> {
+    if let Some(client_state) = loopback_client(ctx, connection_end) {
+        return Ok(localhost::verify_packet_receipt_absence(
+            ctx,
+            &client_state,
+            proofs.height(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?);
+    }
+
+    let (client_def, client_state, consensus_state) =
+        client_for_proof(ctx, connection_end, proofs.height())?;
+
+    // The proof may only be used once the delay period of the connection passed.
+    verify_connection_delay_passed(ctx, connection_end, proofs.height())?;
+
+    Ok(client_def
+        .verify_packet_receipt_absence(
+            &client_state,
+            proofs.height(),
+            consensus_state.root(),
+            connection_end.counterparty().prefix(),
+            proofs.object_proof(),
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+        )
+        .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?)
+}
+
+/// Returns the state of the client underlying `connection_end` if this is a loopback client, which
+/// reads the counterparty states from the host store instead of verifying proofs of them.
+fn loopback_client(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+) -> Option<LocalhostClientState> {
+    match ctx.client_state(connection_end.client_id()) {
+        Some(AnyClientState::Localhost(client_state)) => Some(client_state),
+        _ => None,
+    }
+}
+
+/// Fetches the client underlying `connection_end` (IBC client on the local/host chain) and checks
+/// that it can verify a proof created at `proof_height` on the counterparty chain. Also returns the
+/// consensus state at that height, whose root the proof commits to.
+fn client_for_proof(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+    proof_height: Height,
+) -> Result<(AnyClient, AnyClientState, AnyConsensusState), Error> {
+    let client_id = connection_end.client_id();
+    let client_state = ctx
+        .client_state(client_id)
+        .ok_or_else(|| Kind::MissingClient(client_id.clone()))?;
+
+    // The client must not be frozen.
+    if client_state.is_frozen() {
+        return Err(Kind::FrozenClient(client_id.clone()).into());
+    }
+
+    // The client must have the consensus state for the height where this proof was created.
+    let consensus_state = ctx
+        .client_consensus_state(client_id, proof_height)
+        .ok_or_else(|| Kind::MissingClientConsensusState(client_id.clone(), proof_height))?;
+
+    let client_def = AnyClient::from_client_state(&client_state);
+
+    Ok((client_def, client_state, consensus_state))
+}
+
+/// Checks that the delay period of `connection_end` passed since its client got the consensus
+/// state at `proof_height`, both in time and in blocks of the host chain.
+fn verify_connection_delay_passed(
+    ctx: &dyn ChannelReader,
+    connection_end: &ConnectionEnd,
+    proof_height: Height,
+) -> Result<(), Error> {
+    let delay_period_time = connection_end.delay_period();
+    if delay_period_time.as_nanos() == 0 {
+        return Ok(());
+    }
+
+    let client_id = connection_end.client_id();
+    let processed_time = ctx
+        .client_update_time(client_id, proof_height)
+        .ok_or_else(|| Kind::MissingClientUpdateTime(client_id.clone(), proof_height))?;
+    let processed_height = ctx
+        .client_update_height(client_id, proof_height)
+        .ok_or_else(|| Kind::MissingClientUpdateHeight(client_id.clone(), proof_height))?;
+
+    Ok(verify_delay_passed(
+        ctx.host_timestamp(),
+        ctx.host_current_height(),
+        processed_time,
+        processed_height,
+        delay_period_time,
+        ctx.block_delay(delay_period_time),
+    )
+    .map_err(|e| Kind::PacketVerificationFailure.context(e.to_string()))?)
+}
@@ -0,0 +1,207 @@
+//! Protocol logic for writing the acknowledgement of a received ICS4 packet. Like `send_packet`,
+//! this is not triggered by a message: the application which received the packet calls it directly
+//! once it has processed that packet.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::channel::State;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::handler::verify::verify_port_capability;
+use crate::ics04_channel::handler::PacketEvent::WriteAcknowledgement;
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::packet::{ack_commitment, Packet, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The result following the successful processing of `write_acknowledgement`. Preferably this data
+/// type should be used with a qualified name `write_acknowledgement::Result` to avoid ambiguity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Result {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub seq: Sequence,
+    pub ack_commitment: Vec<u8>,
+}
+
+pub fn write_acknowledgement(
+    ctx: &dyn ChannelReader,
+    capability: &Capability,
+    packet: Packet,
+    ack: Vec<u8>,
+) -> HandlerResult<PacketResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    // Only the module which bound the destination port may acknowledge packets sent to it.
+    verify_port_capability(ctx, &packet.destination_port, capability)?;
+
+    let dest_channel_end = ctx
+        .channel_end(&packet.destination_port, &packet.destination_channel)
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+            )
+        })?;
+
+    if !dest_channel_end.state_matches(&State::Open) {
+        return Err(Kind::InvalidChannelState(
+            packet.destination_port,
+            packet.destination_channel,
+            dest_channel_end.state().clone(),
+        )
+        .into());
+    }
+
+    // An acknowledgement is written at most once, and it cannot be empty.
+    if ctx
+        .packet_acknowledgement(
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+        )
+        .is_some()
+    {
+        return Err(Kind::PacketAlreadyAcknowledged(packet.sequence).into());
+    }
+    if ack.is_empty() {
+        return Err(Kind::InvalidAcknowledgement.into());
+    }
+
+    output.log("success: packet write acknowledgement");
+
+    let result = PacketResult::WriteAck(Result {
+        port_id: packet.destination_port.clone(),
+        channel_id: packet.destination_channel.clone(),
+        seq: packet.sequence,
+        ack_commitment: ack_commitment(&ack),
+    });
+
+    output.emit(WriteAcknowledgement(packet, ack));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::handler::EventType;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::write_acknowledgement::write_acknowledgement;
+    use crate::ics04_channel::handler::PacketResult;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_raw_packet,
+    };
+    use crate::ics04_channel::packet::{ack_commitment, Packet};
+    use crate::ics05_port::context::PortReader;
+    use crate::mock::context::MockContext;
+
+    #[test]
+    fn write_ack_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            ack: Vec<u8>,
+            want_pass: bool,
+        }
+
+        let packet = Packet::try_from(get_dummy_raw_packet(10)).unwrap();
+        let ack = vec![1];
+
+        let context = MockContext::default().with_port_capability(packet.destination_port.clone());
+        let capability = context
+            .lookup_module_by_port(&packet.destination_port)
+            .unwrap();
+
+        let open_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::default(),
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+        );
+
+        let ready_ctx = context.clone().with_channel(
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            open_chan_end,
+        );
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(&packet.destination_port),
+                ack: ack.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                ack: ack.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the acknowledgement is empty".to_string(),
+                ctx: ready_ctx.clone(),
+                ack: vec![],
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters".to_string(),
+                ctx: ready_ctx,
+                ack: ack.clone(),
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res =
+                write_acknowledgement(&test.ctx, &capability, packet.clone(), test.ack.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "write_ack: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.ack.clone(),
+                        test.ctx.clone()
+                    );
+                    assert_ne!(proto_output.events.is_empty(), true); // Some events must exist.
+
+                    // The output commits to the acknowledgement.
+                    match proto_output.result {
+                        PacketResult::WriteAck(res) => {
+                            assert_eq!(res.seq, packet.sequence);
+                            assert_eq!(res.ack_commitment, ack_commitment(&ack));
+                        }
+                        _ => panic!("unexpected result type: expected PacketResult::WriteAck!"),
+                    }
+
+                    for e in proto_output.events.iter() {
+                        assert_eq!(
+                            e.tpe,
+                            EventType::Custom("write_acknowledgement".to_string())
+                        );
+                    }
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "write_ack: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.ack,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
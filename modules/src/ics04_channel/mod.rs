@@ -1,7 +1,11 @@
 //! ICS 04: IBC Channel implementation
 
 pub mod channel;
+/// Context definitions (dependencies for the protocol).
+pub mod context;
 pub mod error;
 pub mod events;
+/// Message processing logic (protocol) for ICS 04.
+pub mod handler;
 pub mod msgs;
 pub mod packet;
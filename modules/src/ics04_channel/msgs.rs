@@ -1,6 +1,18 @@
 //! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
 //! as packets.
 
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use crate::ics24_host::identifier::PortId;
+
 // Opening handshake messages.
 pub mod chan_open_ack;
 pub mod chan_open_confirm;
@@ -15,3 +27,53 @@ pub mod chan_close_init;
 pub mod acknowledgement;
 pub mod recv_packet;
 pub mod timeout;
+pub mod timeout_on_close;
+
+/// Enumeration of all messages of the channel open & close handshakes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChannelMsg {
+    ChannelOpenInit(MsgChannelOpenInit),
+    ChannelOpenTry(Box<MsgChannelOpenTry>),
+    ChannelOpenAck(MsgChannelOpenAck),
+    ChannelOpenConfirm(MsgChannelOpenConfirm),
+
+    ChannelCloseInit(MsgChannelCloseInit),
+    ChannelCloseConfirm(MsgChannelCloseConfirm),
+}
+
+impl ChannelMsg {
+    /// The port of the channel end which the message acts on.
+    pub fn port_id(&self) -> &PortId {
+        match self {
+            ChannelMsg::ChannelOpenInit(msg) => &msg.port_id,
+            ChannelMsg::ChannelOpenTry(msg) => &msg.port_id,
+            ChannelMsg::ChannelOpenAck(msg) => &msg.port_id,
+            ChannelMsg::ChannelOpenConfirm(msg) => &msg.port_id,
+            ChannelMsg::ChannelCloseInit(msg) => msg.port_id(),
+            ChannelMsg::ChannelCloseConfirm(msg) => msg.port_id(),
+        }
+    }
+}
+
+/// Enumeration of all messages which relay packets or their outcome (acknowledgement or timeout)
+/// over an open channel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PacketMsg {
+    RecvPacket(Box<MsgRecvPacket>),
+    Acknowledgement(Box<MsgAcknowledgement>),
+    Timeout(Box<MsgTimeout>),
+    TimeoutOnClose(Box<MsgTimeoutOnClose>),
+}
+
+impl PacketMsg {
+    /// The port on the host chain end of the packet flow: the destination port of a received
+    /// packet, and the source port of an acknowledged or timed out packet.
+    pub fn port_id(&self) -> &PortId {
+        match self {
+            PacketMsg::RecvPacket(msg) => &msg.packet().destination_port,
+            PacketMsg::Acknowledgement(msg) => &msg.packet().source_port,
+            PacketMsg::Timeout(msg) => &msg.packet().source_port,
+            PacketMsg::TimeoutOnClose(msg) => &msg.packet().source_port,
+        }
+    }
+}
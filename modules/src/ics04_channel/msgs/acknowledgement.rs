@@ -47,6 +47,21 @@ impl MsgAcknowledgement {
             signer,
         })
     }
+
+    /// Getter for accessing the packet of this message.
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    /// Getter for accessing the acknowledgement of this message.
+    pub fn acknowledgement(&self) -> &[u8] {
+        &self.acknowledgement
+    }
+
+    /// Getter for accessing the proofs in this message.
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgAcknowledgement {
@@ -118,7 +133,7 @@ impl From<MsgAcknowledgement> for RawMsgAcknowledgement {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -133,7 +148,7 @@ mod test_util {
             acknowledgement: get_dummy_proof(),
             proof: get_dummy_proof(),
             proof_height: Some(RawHeight {
-                version_number: 0,
+                version_number: 1,
                 version_height: height,
             }),
             signer: get_dummy_bech32_account(),
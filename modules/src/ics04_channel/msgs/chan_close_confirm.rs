@@ -47,6 +47,21 @@ impl MsgChannelCloseConfirm {
             signer,
         })
     }
+
+    /// Getter for accessing the port identifier of this message.
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    /// Getter for accessing the channel identifier of this message.
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+
+    /// Getter for accessing the proofs in this message.
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgChannelCloseConfirm {
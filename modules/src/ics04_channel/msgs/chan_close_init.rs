@@ -40,6 +40,16 @@ impl MsgChannelCloseInit {
             signer,
         })
     }
+
+    /// Getter for accessing the port identifier of this message.
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    /// Getter for accessing the channel identifier of this message.
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
 }
 
 impl Msg for MsgChannelCloseInit {
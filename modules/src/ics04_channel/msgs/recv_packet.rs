@@ -46,6 +46,16 @@ impl MsgRecvPacket {
     pub fn get_data_bytes() -> Vec<u8> {
         todo!()
     }
+
+    /// Getter for accessing the packet of this message.
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    /// Getter for accessing the proofs in this message.
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgRecvPacket {
@@ -115,7 +125,7 @@ impl From<MsgRecvPacket> for RawMsgRecvPacket {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -129,7 +139,7 @@ mod test_util {
             packet: Some(get_dummy_raw_packet(height)),
             proof: get_dummy_proof(),
             proof_height: Some(RawHeight {
-                version_number: 0,
+                version_number: 1,
                 version_height: height,
             }),
             signer: get_dummy_bech32_account(),
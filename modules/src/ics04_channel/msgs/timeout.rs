@@ -43,6 +43,21 @@ impl MsgTimeout {
             signer,
         })
     }
+
+    /// Getter for accessing the packet of this message.
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    /// Getter for accessing the next sequence to be received by the counterparty of this message.
+    pub fn next_sequence_recv(&self) -> &Sequence {
+        &self.next_sequence_recv
+    }
+
+    /// Getter for accessing the proofs in this message.
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
 }
 
 impl Msg for MsgTimeout {
@@ -116,7 +131,7 @@ impl From<MsgTimeout> for RawMsgTimeout {
 }
 
 #[cfg(test)]
-mod test_util {
+pub mod test_util {
     use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
@@ -130,7 +145,7 @@ mod test_util {
             packet: Some(get_dummy_raw_packet(height)),
             proof: get_dummy_proof(),
             proof_height: Some(RawHeight {
-                version_number: 0,
+                version_number: 1,
                 version_height: height,
             }),
             next_sequence_recv: 0,
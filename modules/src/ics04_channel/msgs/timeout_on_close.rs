@@ -0,0 +1,248 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint::account::Id as AccountId;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+
+use crate::address::{account_to_string, string_to_account};
+use crate::ics04_channel::error::{Error, Kind};
+use crate::ics04_channel::packet::{Packet, Sequence};
+use crate::ics23_commitment::commitment::CommitmentProof;
+use crate::{proofs::Proofs, tx_msg::Msg};
+
+/// Message type for the `MsgTimeoutOnClose` message.
+const TYPE_MSG_TIMEOUT_ON_CLOSE: &str = "ics04/timeout_on_close";
+
+///
+/// Message definition for packet timeout domain type, in case the counterparty channel end closed
+/// before receiving the packet.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgTimeoutOnClose {
+    packet: Packet,
+    next_sequence_recv: Sequence,
+    /// The proof that the packet was not received, alongside the proof height.
+    proofs: Proofs,
+    /// The proof that the counterparty channel end is closed, at the same height.
+    proof_close: CommitmentProof,
+    signer: AccountId,
+}
+
+impl MsgTimeoutOnClose {
+    /// Getter for accessing the packet of this message.
+    pub fn packet(&self) -> &Packet {
+        &self.packet
+    }
+
+    /// Getter for accessing the next sequence to be received by the counterparty of this message.
+    pub fn next_sequence_recv(&self) -> &Sequence {
+        &self.next_sequence_recv
+    }
+
+    /// Getter for accessing the proofs of packet non-receipt in this message.
+    pub fn proofs(&self) -> &Proofs {
+        &self.proofs
+    }
+
+    /// Getter for accessing the proof of the counterparty channel closure in this message.
+    pub fn proof_close(&self) -> &CommitmentProof {
+        &self.proof_close
+    }
+}
+
+impl Msg for MsgTimeoutOnClose {
+    type ValidationError = Error;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn get_type(&self) -> String {
+        TYPE_MSG_TIMEOUT_ON_CLOSE.to_string()
+    }
+
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
+        // Nothing to validate
+        // All the validation is performed on creation
+        Ok(())
+    }
+
+    fn get_signers(&self) -> Vec<AccountId> {
+        vec![self.signer]
+    }
+}
+
+impl Protobuf<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {}
+
+impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
+    type Error = anomaly::Error<Kind>;
+
+    fn try_from(raw_msg: RawMsgTimeoutOnClose) -> Result<Self, Self::Error> {
+        let signer =
+            string_to_account(raw_msg.signer).map_err(|e| Kind::InvalidSigner.context(e))?;
+
+        let proofs = Proofs::new(
+            raw_msg.proof.into(),
+            None,
+            None,
+            raw_msg
+                .proof_height
+                .ok_or(Kind::MissingHeight)?
+                .try_into()
+                .map_err(|e| Kind::InvalidProof.context(e))?,
+        )
+        .map_err(|e| Kind::InvalidProof.context(e))?;
+
+        let proof_close: CommitmentProof = raw_msg.proof_close.into();
+        if proof_close.is_empty() {
+            return Err(Kind::InvalidProof
+                .context("the proof of channel closure cannot be empty")
+                .into());
+        }
+
+        Ok(MsgTimeoutOnClose {
+            packet: raw_msg
+                .packet
+                .ok_or(Kind::MissingPacket)?
+                .try_into()
+                .map_err(|e| Kind::InvalidPacket.context(e))?,
+            next_sequence_recv: Sequence::from(raw_msg.next_sequence_recv),
+            proofs,
+            proof_close,
+            signer,
+        })
+    }
+}
+
+impl From<MsgTimeoutOnClose> for RawMsgTimeoutOnClose {
+    fn from(domain_msg: MsgTimeoutOnClose) -> Self {
+        RawMsgTimeoutOnClose {
+            packet: Some(domain_msg.packet.into()),
+            proof: domain_msg.proofs.object_proof().clone().into(),
+            proof_close: domain_msg.proof_close.into(),
+            proof_height: Some(domain_msg.proofs.height().into()),
+            next_sequence_recv: domain_msg.next_sequence_recv.into(),
+            signer: account_to_string(domain_msg.signer).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::ics04_channel::packet::test_utils::get_dummy_raw_packet;
+    use crate::test_utils::{get_dummy_bech32_account, get_dummy_proof};
+
+    /// Returns a dummy `RawMsgTimeoutOnClose`, for testing only!
+    /// The `height` parametrizes both the proof height as well as the timeout height.
+    pub fn get_dummy_raw_msg_timeout_on_close(height: u64) -> RawMsgTimeoutOnClose {
+        RawMsgTimeoutOnClose {
+            packet: Some(get_dummy_raw_packet(height)),
+            proof: get_dummy_proof(),
+            proof_close: get_dummy_proof(),
+            proof_height: Some(RawHeight {
+                version_number: 1,
+                version_height: height,
+            }),
+            next_sequence_recv: 1,
+            signer: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::{TryFrom, TryInto};
+
+    use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
+
+    use crate::ics04_channel::error::Error;
+    use crate::ics04_channel::msgs::timeout_on_close::test_util::get_dummy_raw_msg_timeout_on_close;
+    use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+
+    #[test]
+    fn msg_timeout_on_close_try_from_raw() {
+        struct Test {
+            name: String,
+            raw: RawMsgTimeoutOnClose,
+            want_pass: bool,
+        }
+
+        let height = 50;
+        let default_raw_msg = get_dummy_raw_msg_timeout_on_close(height);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Missing packet".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    packet: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof: vec![],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof of channel closure".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof_close: vec![],
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing proof height".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    proof_height: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing signer".to_string(),
+                raw: RawMsgTimeoutOnClose {
+                    signer: "".to_string(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg: Result<MsgTimeoutOnClose, Error> = test.raw.clone().try_into();
+
+            assert_eq!(
+                res_msg.is_ok(),
+                test.want_pass,
+                "MsgTimeoutOnClose::try_from failed for test {} \nraw message: {:?} with error: {:?}",
+                test.name,
+                test.raw,
+                res_msg.err()
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_timeout_on_close(15);
+        let msg = MsgTimeoutOnClose::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgTimeoutOnClose::from(msg.clone());
+        let msg_back = MsgTimeoutOnClose::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -1,15 +1,38 @@
 use std::convert::{TryFrom, TryInto};
 
+use sha2::{Digest, Sha256};
+
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
 use crate::ics04_channel::error::Kind;
 use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// The sequence number of a packet enforces ordering among packets from the same source.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Sequence(u64);
 
+impl Sequence {
+    /// Returns the sequence number that follows this one.
+    pub fn increment(&self) -> Sequence {
+        Sequence(self.0 + 1)
+    }
+}
+
+impl Default for Sequence {
+    /// Sequence numbers start at 1 on every channel end.
+    fn default() -> Self {
+        Sequence(1)
+    }
+}
+
+impl std::fmt::Display for Sequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl From<u64> for Sequence {
     fn from(seq: u64) -> Self {
         Sequence(seq)
@@ -24,14 +47,41 @@ impl From<Sequence> for u64 {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Packet {
-    sequence: Sequence,
-    source_port: PortId,
-    source_channel: ChannelId,
-    destination_port: PortId,
-    destination_channel: ChannelId,
-    data: Vec<u8>,
-    timeout_height: Height,
-    timeout_timestamp: u64,
+    pub sequence: Sequence,
+    pub source_port: PortId,
+    pub source_channel: ChannelId,
+    pub destination_port: PortId,
+    pub destination_channel: ChannelId,
+    pub data: Vec<u8>,
+    pub timeout_height: Height,
+    pub timeout_timestamp: Timestamp,
+}
+
+impl Packet {
+    /// The commitment to this packet which the sending chain stores, and which the receiving chain
+    /// verifies. It is computed as in the Cosmos SDK, i.e., the SHA-256 hash of the timeout
+    /// timestamp, the timeout height and the hash of the packet data.
+    pub fn commitment(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&self.timeout_timestamp.nanoseconds().to_be_bytes());
+        buf.extend_from_slice(&self.timeout_height.version_number.to_be_bytes());
+        buf.extend_from_slice(&self.timeout_height.version_height.to_be_bytes());
+        buf.extend_from_slice(Sha256::digest(&self.data).as_slice());
+        Sha256::digest(&buf).to_vec()
+    }
+}
+
+/// The commitment to a packet acknowledgement which the receiving chain stores, and which the
+/// sending chain verifies: the SHA-256 hash of the acknowledgement bytes.
+pub fn ack_commitment(ack: &[u8]) -> Vec<u8> {
+    Sha256::digest(ack).to_vec()
+}
+
+/// The receipt which the receiving end of an unordered channel stores for every packet it
+/// processes, so that each packet is received at most once.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Receipt {
+    Ok,
 }
 
 impl TryFrom<RawPacket> for Packet {
@@ -62,7 +112,7 @@ impl TryFrom<RawPacket> for Packet {
                 .ok_or(Kind::MissingHeight)?
                 .try_into()
                 .map_err(|e| Kind::InvalidTimeoutHeight.context(e))?,
-            timeout_timestamp: raw_pkt.timeout_timestamp,
+            timeout_timestamp: Timestamp::from_nanoseconds(raw_pkt.timeout_timestamp),
         })
     }
 }
@@ -77,16 +127,28 @@ impl From<Packet> for RawPacket {
             destination_channel: packet.destination_channel.to_string(),
             data: packet.data,
             timeout_height: Some(packet.timeout_height.into()),
-            timeout_timestamp: packet.timeout_timestamp,
+            timeout_timestamp: packet.timeout_timestamp.nanoseconds(),
         }
     }
 }
 
 #[cfg(test)]
 pub mod test_utils {
+    use std::str::FromStr;
+    use std::time::Duration;
+
     use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
     use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
+    use crate::ics03_connection::connection::{
+        ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+    };
+    use crate::ics03_connection::version::get_compatible_versions;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics23_commitment::commitment::CommitmentPrefix;
+    use crate::ics24_host::identifier::{ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+
     /// Returns a dummy `RawPacket`, for testing only!
     pub fn get_dummy_raw_packet(timeout_height: u64) -> RawPacket {
         RawPacket {
@@ -103,6 +165,56 @@ pub mod test_utils {
             timeout_timestamp: 0,
         }
     }
+
+    /// Returns a connection end in the given state over the client `client_id`, with a dummy
+    /// counterparty and no delay period, for testing only!
+    pub fn get_dummy_connection_end(state: ConnectionState, client_id: &ClientId) -> ConnectionEnd {
+        get_dummy_delayed_connection_end(state, client_id, Duration::from_secs(0))
+    }
+
+    /// Same as `get_dummy_connection_end`, but packet proofs over the connection are delayed by
+    /// `delay_period` after each client update, for testing only!
+    pub fn get_dummy_delayed_connection_end(
+        state: ConnectionState,
+        client_id: &ClientId,
+        delay_period: Duration,
+    ) -> ConnectionEnd {
+        ConnectionEnd::new(
+            state,
+            client_id.clone(),
+            ConnectionCounterparty::new(
+                ClientId::from_str("cpartyclientid").unwrap(),
+                Some(ConnectionId::from_str("cpartyconnectionid").unwrap()),
+                CommitmentPrefix::from(vec![]),
+            ),
+            get_compatible_versions(),
+            delay_period,
+        )
+        .unwrap()
+    }
+
+    /// Returns a channel end of version "v1" over the default connection, for testing only!
+    pub fn get_dummy_channel_end(
+        state: State,
+        order: Order,
+        counterparty: Counterparty,
+    ) -> ChannelEnd {
+        ChannelEnd::new(
+            state,
+            order,
+            counterparty,
+            vec![ConnectionId::default()],
+            "v1".to_string(),
+        )
+    }
+
+    /// Returns a context whose capability for `port_id` is not the one which a default context
+    /// issues for this port, for testing only!
+    pub fn get_context_with_other_capability(port_id: &PortId) -> MockContext {
+        MockContext::default()
+            .with_port_capability(PortId::from_str("otherport").unwrap())
+            .with_port_capability(port_id.clone())
+    }
 }
 
 #[cfg(test)]
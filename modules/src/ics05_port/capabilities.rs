@@ -0,0 +1,19 @@
+//! Object capabilities, which the host chain issues to the module which binds a port. The channel
+//! handlers only act on a port on behalf of the holder of its capability.
+
+/// An opaque, unforgeable reference to a port. Only the host chain creates capabilities: they are
+/// identified by an index which is unique across the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capability {
+    index: u64,
+}
+
+impl Capability {
+    pub fn new(index: u64) -> Self {
+        Self { index }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+}
@@ -0,0 +1,23 @@
+//! ICS5 (port) context. The two traits `PortReader` and `PortKeeper` define the interface that any
+//! host chain must implement to bind ports and authenticate the modules acting on them.
+
+use crate::ics05_port::capabilities::Capability;
+use crate::ics05_port::error::Error;
+use crate::ics24_host::identifier::PortId;
+
+/// A context supplying all the necessary read-only dependencies for authenticating the module which
+/// owns a port.
+pub trait PortReader {
+    /// Returns the capability issued when the given port was bound, if it is bound.
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability>;
+
+    /// Whether the given capability is the one issued for the given port.
+    fn authenticate(&self, capability: &Capability, port_id: &PortId) -> bool;
+}
+
+/// A context supplying all the necessary write-only dependencies for binding ports.
+pub trait PortKeeper {
+    /// Binds the given port, and returns the capability for it. Fails if the port is already
+    /// bound.
+    fn bind_port(&mut self, port_id: &PortId) -> Result<Capability, Error>;
+}
@@ -0,0 +1,21 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use crate::ics24_host::identifier::PortId;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum Kind {
+    #[error("port {0} is unknown")]
+    UnknownPort(PortId),
+
+    #[error("port {0} is already bound")]
+    PortAlreadyBound(PortId),
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
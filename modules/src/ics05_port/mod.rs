@@ -0,0 +1,6 @@
+//! ICS 05: Port allocation and authentication
+
+pub mod capabilities;
+/// Context definitions (dependencies for the protocol).
+pub mod context;
+pub mod error;
@@ -0,0 +1,704 @@
+use std::convert::TryFrom;
+
+use prost::Message;
+use prost_types::Any;
+use tendermint::signature::Signature;
+use tendermint::PublicKey;
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{
+    data::Sum as SignatureSum, Data as RawSignatureData,
+};
+use ibc_proto::ibc::core::channel::v1::Channel as RawChannel;
+use ibc_proto::ibc::core::connection::v1::ConnectionEnd as RawConnectionEnd;
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    ChannelStateData, ClientStateData, ConnectionStateData, ConsensusStateData, DataType,
+    HeaderData, NextSequenceRecvData, PacketAcknowledgementData, PacketCommitmentData,
+    PacketReceiptAbsenceData, SignBytes, TimestampedSignatureData,
+};
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics06_solomachine::client_state::ClientState;
+use crate::ics06_solomachine::consensus_state::{encode_public_key, ConsensusState};
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics06_solomachine::header::Header;
+use crate::ics06_solomachine::misbehaviour::Misbehaviour;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
+use crate::ics23_commitment::merkle::apply_prefix;
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+/// The client of a solo machine, e.g., an off-chain signer. Instead of proving its states against
+/// a commitment root, the machine signs them with the key which the client trusts.
+///
+/// The machine signs states at the current sequence of the client. Unlike in the ICS 6
+/// specification, where each verified signature advances the sequence, the verification functions
+/// cannot update the client state here, so the sequence only increases with headers. Until the next
+/// header, a signature may thus be replayed, e.g., to prove the same packet commitment twice; the
+/// handlers which verify proofs are what guard against acting twice on the same state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SoloMachineClient;
+
+impl ClientDef for SoloMachineClient {
+    type Header = Header;
+    type Misbehaviour = Misbehaviour;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+
+    fn check_header_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        let consensus_state = &client_state.consensus_state;
+
+        if header.sequence != client_state.sequence {
+            return Err(format!(
+                "header sequence {} does not match the client sequence {}",
+                header.sequence, client_state.sequence
+            )
+            .into());
+        }
+        if header.timestamp < consensus_state.timestamp {
+            return Err(format!(
+                "header timestamp {} is lower than the consensus state timestamp {}",
+                header.timestamp, consensus_state.timestamp
+            )
+            .into());
+        }
+
+        // The machine signs the key and diversifier it rotates to with its current key.
+        let data = HeaderData {
+            new_pub_key: Some(encode_public_key(header.new_public_key)),
+            new_diversifier: header.new_diversifier.clone(),
+        };
+        let sign_bytes = sign_bytes(
+            header.sequence,
+            header.timestamp,
+            &consensus_state.diversifier,
+            DataType::Header,
+            encode(data),
+        );
+        verify_signature(&consensus_state.public_key, &sign_bytes, &header.signature)?;
+
+        let new_consensus_state = ConsensusState::new(
+            header.new_public_key,
+            header.new_diversifier,
+            header.timestamp,
+        );
+        let new_client_state = ClientState {
+            sequence: header.sequence + 1,
+            consensus_state: new_consensus_state.clone(),
+            ..client_state
+        };
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        let (one, two) = (&misbehaviour.signature_one, &misbehaviour.signature_two);
+        if one.signature == two.signature {
+            return Err("the misbehaviour signatures are equal".into());
+        }
+        if one.data_type == two.data_type && one.data == two.data {
+            return Err("the misbehaviour signatures are of the same data".into());
+        }
+
+        // Both signatures must be valid for the key which the client trusts.
+        let consensus_state = &client_state.consensus_state;
+        for signature_and_data in [one, two].iter() {
+            let sign_bytes = sign_bytes(
+                misbehaviour.sequence,
+                signature_and_data.timestamp,
+                &consensus_state.diversifier,
+                signature_and_data.data_type,
+                signature_and_data.data.clone(),
+            );
+            verify_signature(
+                &consensus_state.public_key,
+                &sign_bytes,
+                &signature_and_data.signature,
+            )?;
+        }
+
+        Ok(client_state.with_frozen_sequence(misbehaviour.sequence))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _upgraded_client_state: Self::ClientState,
+        _upgrade_height: Height,
+        _proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err("solo machine clients cannot be upgraded".into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_consensus_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.version_number,
+            height: consensus_height.version_height,
+        };
+        let data = ConsensusStateData {
+            path: signed_path(prefix, path)?,
+            consensus_state: Some(Any::from(expected_consensus_state.clone())),
+        };
+        verify_signed_data(client_state, height, proof, DataType::ConsensusState, data)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_connection_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Connections(connection_id.clone());
+        let data = ConnectionStateData {
+            path: signed_path(prefix, path)?,
+            connection: Some(RawConnectionEnd::from(expected_connection_end.clone())),
+        };
+        verify_signed_data(client_state, height, proof, DataType::ConnectionState, data)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let data = ChannelStateData {
+            path: signed_path(prefix, path)?,
+            channel: Some(RawChannel::from(expected_channel_end.clone())),
+        };
+        verify_signed_data(client_state, height, proof, DataType::ChannelState, data)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        let data = PacketCommitmentData {
+            path: signed_path(prefix, path)?,
+            commitment: commitment.to_vec(),
+        };
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketCommitment,
+            data,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        let data = PacketAcknowledgementData {
+            path: signed_path(prefix, path)?,
+            acknowledgement: ack_commitment.to_vec(),
+        };
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketAcknowledgement,
+            data,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        let data = NextSequenceRecvData {
+            path: signed_path(prefix, path)?,
+            next_seq_recv: next_sequence_recv.into(),
+        };
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::NextSequenceRecv,
+            data,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        let data = PacketReceiptAbsenceData {
+            path: signed_path(prefix, path)?,
+        };
+        verify_signed_data(
+            client_state,
+            height,
+            proof,
+            DataType::PacketReceiptAbsence,
+            data,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_full_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        _root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProof,
+        expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ClientState(client_id.clone());
+        let data = ClientStateData {
+            path: signed_path(prefix, path)?,
+            client_state: Some(Any::from(expected_client_state.clone())),
+        };
+        verify_signed_data(client_state, height, proof, DataType::ClientState, data)
+    }
+}
+
+/// Verifies that `proof` holds a signature of `data`, of the given type, by the solo machine at
+/// `height`, which must be the current sequence of the client. The sequence is not advanced, so the
+/// same proof verifies again until a header updates the client.
+fn verify_signed_data(
+    client_state: &ClientState,
+    height: Height,
+    proof: &CommitmentProof,
+    data_type: DataType,
+    data: impl Message,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if client_state.is_frozen() {
+        return Err(format!(
+            "client is frozen at sequence {}",
+            client_state.frozen_sequence
+        )
+        .into());
+    }
+    if height != client_state.latest_height() {
+        return Err(format!(
+            "the proof is for height {}, but the client is at sequence {}",
+            height, client_state.sequence
+        )
+        .into());
+    }
+
+    let proof = TimestampedSignatureData::decode(Vec::<u8>::from(proof.clone()).as_slice())
+        .map_err(|e| Kind::InvalidProof.context(e))?;
+    let consensus_state = &client_state.consensus_state;
+    if proof.timestamp < consensus_state.timestamp {
+        return Err(format!(
+            "proof timestamp {} is lower than the consensus state timestamp {}",
+            proof.timestamp, consensus_state.timestamp
+        )
+        .into());
+    }
+
+    let sign_bytes = sign_bytes(
+        client_state.sequence,
+        proof.timestamp,
+        &consensus_state.diversifier,
+        data_type,
+        encode(data),
+    );
+    verify_signature(
+        &consensus_state.public_key,
+        &sign_bytes,
+        &proof.signature_data,
+    )?;
+    Ok(())
+}
+
+/// The path of an object in the store of the solo machine, in the form which the machine signs.
+fn signed_path(
+    prefix: &CommitmentPrefix,
+    path: Path,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Ok(apply_prefix(prefix, path.to_string())?
+        .to_string()
+        .into_bytes())
+}
+
+/// The bytes which the solo machine signs: the data, along with the sequence and timestamp at which
+/// the machine signs it, and the diversifier which binds the signature to a single client.
+pub fn sign_bytes(
+    sequence: u64,
+    timestamp: u64,
+    diversifier: &str,
+    data_type: DataType,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    encode(SignBytes {
+        sequence,
+        timestamp,
+        diversifier: diversifier.to_string(),
+        data_type: data_type as i32,
+        data,
+    })
+}
+
+fn encode(message: impl Message) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    message.encode(&mut bytes).unwrap();
+    bytes
+}
+
+/// Verifies that `signature_data`, i.e., the encoding of the Cosmos SDK signature descriptor data
+/// of a single signer, holds a signature of `sign_bytes` by `public_key`.
+fn verify_signature(
+    public_key: &PublicKey,
+    sign_bytes: &[u8],
+    signature_data: &[u8],
+) -> Result<(), Error> {
+    let signature_data =
+        RawSignatureData::decode(signature_data).map_err(|e| Kind::InvalidSignature.context(e))?;
+    let signature = match signature_data.sum {
+        Some(SignatureSum::Single(single)) => single.signature,
+        _ => {
+            return Err(Kind::InvalidSignature
+                .context("not the signature of a single signer")
+                .into())
+        }
+    };
+
+    let signature = Signature::try_from(signature)
+        .map_err(|e| Kind::InvalidSignature.context(e.to_string()))?;
+    public_key
+        .verify(sign_bytes, &signature)
+        .map_err(|e| Kind::InvalidSignature.context(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{
+        data::{Single, Sum as SignatureSum},
+        Data as RawSignatureData,
+    };
+    use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+    use ibc_proto::ibc::lightclients::solomachine::v1::{
+        DataType, HeaderData, PacketCommitmentData, TimestampedSignatureData,
+    };
+
+    use crate::ics02_client::client_def::ClientDef;
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics06_solomachine::client_def::{
+        encode, sign_bytes, signed_path, SoloMachineClient,
+    };
+    use crate::ics06_solomachine::client_state::test_util::{
+        get_dummy_solomachine_client_state, DUMMY_DIVERSIFIER, DUMMY_TIMESTAMP,
+    };
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::encode_public_key;
+    use crate::ics06_solomachine::consensus_state::test_util::SoloMachineKey;
+    use crate::ics06_solomachine::header::Header;
+    use crate::ics06_solomachine::misbehaviour::{Misbehaviour, SignatureAndData};
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
+    use crate::ics24_host::identifier::{ChannelId, ClientId, PortId};
+    use crate::ics24_host::Path;
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    const SEQUENCE: u64 = 5;
+
+    /// Signs `sign_bytes` with `key`, in the form of the signature data of a single signer.
+    fn signature_data(key: &SoloMachineKey, sign_bytes: &[u8]) -> Vec<u8> {
+        encode(RawSignatureData {
+            sum: Some(SignatureSum::Single(Single {
+                mode: SignMode::Unspecified as i32,
+                signature: key.sign(sign_bytes),
+            })),
+        })
+    }
+
+    /// Builds the header which rotates the key of the machine to `new_key`, signed by `signer`.
+    fn header(
+        signer: &SoloMachineKey,
+        new_key: &SoloMachineKey,
+        sequence: u64,
+        timestamp: u64,
+    ) -> Header {
+        let new_diversifier = "new diversifier".to_string();
+        let data = HeaderData {
+            new_pub_key: Some(encode_public_key(new_key.public_key())),
+            new_diversifier: new_diversifier.clone(),
+        };
+        let sign_bytes = sign_bytes(
+            sequence,
+            timestamp,
+            DUMMY_DIVERSIFIER,
+            DataType::Header,
+            encode(data),
+        );
+
+        Header {
+            sequence,
+            timestamp,
+            signature: signature_data(signer, &sign_bytes),
+            new_public_key: new_key.public_key(),
+            new_diversifier,
+        }
+    }
+
+    #[test]
+    fn update_solomachine_client() {
+        struct Test {
+            name: String,
+            header: Header,
+            want_pass: bool,
+        }
+
+        let key = SoloMachineKey::new("current key");
+        let new_key = SoloMachineKey::new("new key");
+        let client_state = get_dummy_solomachine_client_state(&key, SEQUENCE);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                header: header(&key, &new_key, SEQUENCE, DUMMY_TIMESTAMP + 1),
+                want_pass: true,
+            },
+            Test {
+                name: "Header signed by the new key".to_string(),
+                header: header(&new_key, &new_key, SEQUENCE, DUMMY_TIMESTAMP + 1),
+                want_pass: false,
+            },
+            Test {
+                name: "Header at another sequence".to_string(),
+                header: header(&key, &new_key, SEQUENCE + 1, DUMMY_TIMESTAMP + 1),
+                want_pass: false,
+            },
+            Test {
+                name: "Header older than the consensus state".to_string(),
+                header: header(&key, &new_key, SEQUENCE, DUMMY_TIMESTAMP - 1),
+                want_pass: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = SoloMachineClient.check_header_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state.clone(),
+                test.header.clone(),
+            );
+
+            assert_eq!(
+                test.want_pass,
+                res.is_ok(),
+                "update_solomachine_client: test {} failed",
+                test.name
+            );
+
+            if let Ok((new_client_state, new_consensus_state)) = res {
+                assert_eq!(new_client_state.sequence, SEQUENCE + 1);
+                assert_eq!(new_consensus_state.public_key, new_key.public_key());
+                assert_eq!(new_client_state.consensus_state, new_consensus_state);
+            }
+        }
+    }
+
+    #[test]
+    fn verify_solomachine_signed_packet() {
+        let key = SoloMachineKey::new("key");
+        let client_state = get_dummy_solomachine_client_state(&key, SEQUENCE);
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let (port_id, channel_id) = (PortId::default(), ChannelId::default());
+        let sequence = Sequence::from(1);
+        let commitment = b"commitment".to_vec();
+
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        let data = PacketCommitmentData {
+            path: signed_path(&prefix, path).unwrap(),
+            commitment: commitment.clone(),
+        };
+        let sign_bytes = sign_bytes(
+            SEQUENCE,
+            DUMMY_TIMESTAMP,
+            DUMMY_DIVERSIFIER,
+            DataType::PacketCommitment,
+            encode(data),
+        );
+        let proof: CommitmentProof = encode(TimestampedSignatureData {
+            signature_data: signature_data(&key, &sign_bytes),
+            timestamp: DUMMY_TIMESTAMP,
+        })
+        .into();
+
+        let verify = |client_state: &ClientState, height: Height, commitment: &[u8]| {
+            SoloMachineClient.verify_packet_data(
+                client_state,
+                height,
+                &CommitmentRoot::from(vec![]),
+                &prefix,
+                &proof,
+                &port_id,
+                &channel_id,
+                sequence,
+                commitment,
+            )
+        };
+
+        let height = Height::new(0, SEQUENCE);
+        assert!(verify(&client_state, height, &commitment).is_ok());
+        assert!(verify(&client_state, height, b"other commitment").is_err());
+        assert!(verify(&client_state, height.increment(), &commitment).is_err());
+        assert!(verify(&client_state.with_frozen_sequence(1), height, &commitment).is_err());
+
+        // The proof can be replayed while the client stays at the same sequence, but not once a
+        // header has moved the client to the next one.
+        assert!(verify(&client_state, height, &commitment).is_ok());
+        let (updated_state, _) = SoloMachineClient
+            .check_header_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state.clone(),
+                header(&key, &key, SEQUENCE, DUMMY_TIMESTAMP + 1),
+            )
+            .unwrap();
+        assert!(verify(&updated_state, height, &commitment).is_err());
+        assert!(verify(&updated_state, height.increment(), &commitment).is_err());
+    }
+
+    #[test]
+    fn solomachine_misbehaviour() {
+        let key = SoloMachineKey::new("key");
+        let client_state = get_dummy_solomachine_client_state(&key, SEQUENCE);
+
+        let signature_and_data = |signer: &SoloMachineKey, data: &[u8]| {
+            let sign_bytes = sign_bytes(
+                SEQUENCE,
+                DUMMY_TIMESTAMP,
+                DUMMY_DIVERSIFIER,
+                DataType::PacketCommitment,
+                data.to_vec(),
+            );
+            SignatureAndData {
+                signature: signature_data(signer, &sign_bytes),
+                data_type: DataType::PacketCommitment,
+                data: data.to_vec(),
+                timestamp: DUMMY_TIMESTAMP,
+            }
+        };
+        let misbehaviour = |signature_two| Misbehaviour {
+            client_id: ClientId::default(),
+            sequence: SEQUENCE,
+            signature_one: signature_and_data(&key, b"data"),
+            signature_two,
+        };
+
+        let check = |misbehaviour| {
+            SoloMachineClient.check_misbehaviour_and_update_state(
+                &MockContext::default(),
+                ClientId::default(),
+                client_state.clone(),
+                misbehaviour,
+            )
+        };
+
+        // Two signatures of different data by the key of the machine.
+        let frozen_state = check(misbehaviour(signature_and_data(&key, b"other data"))).unwrap();
+        assert_eq!(frozen_state.frozen_sequence, SEQUENCE);
+
+        // The same signature twice, or a signature by another key.
+        assert!(check(misbehaviour(signature_and_data(&key, b"data"))).is_err());
+        let other_key = SoloMachineKey::new("other key");
+        assert!(check(misbehaviour(signature_and_data(&other_key, b"other data"))).is_err());
+    }
+}
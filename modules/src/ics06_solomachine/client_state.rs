@@ -0,0 +1,154 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::consensus_state::ConsensusState;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::Height;
+
+/// The state of a solo machine client. The sequence of the machine serves as the height of the
+/// client: it increases with each header, and the machine signs every state at the current one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub frozen_sequence: u64,
+    pub consensus_state: ConsensusState,
+    pub allow_update_after_proposal: bool,
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl ClientState {
+    pub fn new(
+        sequence: u64,
+        consensus_state: ConsensusState,
+        allow_update_after_proposal: bool,
+    ) -> Self {
+        Self {
+            sequence,
+            frozen_sequence: 0,
+            consensus_state,
+            allow_update_after_proposal,
+        }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        // If 'frozen_sequence' is set to a non-zero value, then the client state is frozen.
+        self.frozen_sequence != 0
+    }
+
+    /// Freezes the client at `sequence`, e.g., upon evidence of misbehaviour.
+    pub fn with_frozen_sequence(self, sequence: u64) -> Self {
+        ClientState {
+            frozen_sequence: sequence,
+            ..self
+        }
+    }
+}
+
+impl crate::ics02_client::state::ClientState for ClientState {
+    fn chain_id(&self) -> String {
+        // A solo machine is not a chain.
+        String::new()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.is_frozen()
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::SoloMachine(self)
+    }
+}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: raw.sequence,
+            frozen_sequence: raw.frozen_sequence,
+            consensus_state: raw
+                .consensus_state
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing consensus state"))?
+                .try_into()?,
+            allow_update_after_proposal: raw.allow_update_after_proposal,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            frozen_sequence: value.frozen_sequence,
+            consensus_state: Some(value.consensus_state.into()),
+            allow_update_after_proposal: value.allow_update_after_proposal,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::test_util::SoloMachineKey;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+
+    pub const DUMMY_DIVERSIFIER: &str = "diversifier";
+    pub const DUMMY_TIMESTAMP: u64 = 10;
+
+    /// Returns the state of a client of the solo machine which signs with `key`, at `sequence`.
+    pub fn get_dummy_solomachine_client_state(key: &SoloMachineKey, sequence: u64) -> ClientState {
+        let consensus_state = ConsensusState::new(
+            key.public_key(),
+            DUMMY_DIVERSIFIER.to_string(),
+            DUMMY_TIMESTAMP,
+        );
+        ClientState::new(sequence, consensus_state, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::lightclients::solomachine::v1::ClientState as RawClientState;
+
+    use crate::ics02_client::client_def::AnyClientState;
+    use crate::ics06_solomachine::client_state::test_util::get_dummy_solomachine_client_state;
+    use crate::ics06_solomachine::client_state::ClientState;
+    use crate::ics06_solomachine::consensus_state::test_util::SoloMachineKey;
+    use crate::Height;
+
+    #[test]
+    fn client_state_serialization() {
+        let client_state = get_dummy_solomachine_client_state(&SoloMachineKey::new("a"), 3);
+        assert_eq!(client_state.latest_height(), Height::new(0, 3));
+        assert!(!client_state.is_frozen());
+
+        let raw = RawClientState::from(client_state.clone());
+        assert_eq!(ClientState::try_from(raw).unwrap(), client_state);
+
+        let any: prost_types::Any = AnyClientState::SoloMachine(client_state.clone()).into();
+        assert_eq!(
+            AnyClientState::try_from(any).unwrap(),
+            AnyClientState::SoloMachine(client_state)
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::convert::TryFrom;
+
+use prost::Message;
+use prost_types::Any;
+use tendermint::PublicKey;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::ConsensusState as RawConsensusState;
+
+use crate::ics02_client::{client_def::AnyConsensusState, client_type::ClientType};
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const ED25519_PUBLIC_KEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// Solo machines sign their states instead of committing to them, hence they have no root.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot(Vec::new());
+
+/// The state of a solo machine that its client trusts: the key which signs the states of the
+/// machine, along with the diversifier which tells apart the clients of machines sharing a key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusState {
+    pub public_key: PublicKey,
+    pub diversifier: String,
+    pub timestamp: u64,
+}
+
+impl ConsensusState {
+    pub fn new(public_key: PublicKey, diversifier: String, timestamp: u64) -> Self {
+        Self {
+            public_key,
+            diversifier,
+            timestamp,
+        }
+    }
+}
+
+impl crate::ics02_client::state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &EMPTY_ROOT
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp)
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.diversifier.trim().is_empty() {
+            return Err("empty diversifier".into());
+        }
+        if self.timestamp == 0 {
+            return Err("zero timestamp".into());
+        }
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(|| Kind::InvalidRawConsensusState.context("missing public key"))?;
+
+        Ok(Self {
+            public_key: decode_public_key(public_key)?,
+            diversifier: raw.diversifier,
+            timestamp: raw.timestamp,
+        })
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(encode_public_key(value.public_key)),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+/// The Cosmos SDK encoding of Ed25519 public keys, the only ones that solo machines may use here.
+#[derive(::prost::Message)]
+struct RawEd25519PublicKey {
+    #[prost(bytes, tag = "1")]
+    key: Vec<u8>,
+}
+
+pub fn decode_public_key(raw: Any) -> Result<PublicKey, Error> {
+    if raw.type_url != ED25519_PUBLIC_KEY_TYPE_URL {
+        return Err(Kind::InvalidPublicKey
+            .context(format!("unsupported public key type {}", raw.type_url))
+            .into());
+    }
+
+    let raw_key = RawEd25519PublicKey::decode(raw.value.as_slice())
+        .map_err(|e| Kind::InvalidPublicKey.context(e))?;
+    PublicKey::from_raw_ed25519(&raw_key.key)
+        .ok_or_else(|| Kind::InvalidPublicKey.context("invalid Ed25519 key").into())
+}
+
+pub fn encode_public_key(public_key: PublicKey) -> Any {
+    let mut value = Vec::new();
+    RawEd25519PublicKey {
+        key: public_key.to_bytes(),
+    }
+    .encode(&mut value)
+    .unwrap();
+
+    Any {
+        type_url: ED25519_PUBLIC_KEY_TYPE_URL.to_string(),
+        value,
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use tendermint::signature::Signer;
+    use tendermint::PublicKey;
+    use tendermint_testgen::Validator;
+
+    /// The key pair of a solo machine, from which tests build the signatures of the machine.
+    pub struct SoloMachineKey(Validator);
+
+    impl SoloMachineKey {
+        pub fn new(id: &str) -> Self {
+            Self(Validator::new(id))
+        }
+
+        pub fn public_key(&self) -> PublicKey {
+            self.0.generate().unwrap().pub_key
+        }
+
+        /// Returns the raw Ed25519 signature of `sign_bytes`.
+        pub fn sign(&self, sign_bytes: &[u8]) -> Vec<u8> {
+            let key_pair = self.0.get_private_key().unwrap();
+            key_pair.sign(sign_bytes).as_ref().to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::lightclients::solomachine::v1::ConsensusState as RawConsensusState;
+
+    use crate::ics06_solomachine::consensus_state::test_util::SoloMachineKey;
+    use crate::ics06_solomachine::consensus_state::ConsensusState;
+
+    #[test]
+    fn consensus_state_serialization() {
+        let consensus_state =
+            ConsensusState::new(SoloMachineKey::new("a").public_key(), "div".to_string(), 1);
+
+        let raw = RawConsensusState::from(consensus_state.clone());
+        assert_eq!(
+            ConsensusState::try_from(raw.clone()).unwrap(),
+            consensus_state
+        );
+
+        let mut other_key = raw;
+        other_key.public_key.as_mut().unwrap().type_url =
+            "/cosmos.crypto.secp256k1.PubKey".to_string();
+        assert!(ConsensusState::try_from(other_key).is_err());
+    }
+}
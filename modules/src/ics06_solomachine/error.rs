@@ -0,0 +1,34 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw client consensus state")]
+    InvalidRawConsensusState,
+
+    #[error("invalid raw header")]
+    InvalidRawHeader,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
+
+    #[error("invalid public key")]
+    InvalidPublicKey,
+
+    #[error("invalid signature")]
+    InvalidSignature,
+
+    #[error("invalid proof")]
+    InvalidProof,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
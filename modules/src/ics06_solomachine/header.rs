@@ -0,0 +1,68 @@
+use std::convert::TryFrom;
+
+use tendermint::PublicKey;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::Header as RawHeader;
+
+use crate::ics02_client::{client_def::AnyHeader, client_type::ClientType};
+use crate::ics06_solomachine::consensus_state::{decode_public_key, encode_public_key};
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::Height;
+
+/// Solo machine header, which rotates the key and diversifier of the machine. The machine signs it
+/// with its current key, at the current sequence of the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl crate::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        let new_public_key = raw
+            .new_public_key
+            .ok_or_else(|| Kind::InvalidRawHeader.context("missing new public key"))?;
+
+        Ok(Self {
+            sequence: raw.sequence,
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: decode_public_key(new_public_key)?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            sequence: value.sequence,
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(encode_public_key(value.new_public_key)),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
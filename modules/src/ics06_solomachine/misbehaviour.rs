@@ -0,0 +1,101 @@
+use std::convert::TryFrom;
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::solomachine::v1::{
+    DataType, Misbehaviour as RawMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics06_solomachine::error::{Error, Kind};
+use crate::ics24_host::identifier::ClientId;
+use crate::Height;
+
+/// A signature of the solo machine, together with the data it signs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureAndData {
+    pub signature: Vec<u8>,
+    pub data_type: DataType,
+    pub data: Vec<u8>,
+    pub timestamp: u64,
+}
+
+/// Solo machine misbehaviour: two signatures of different data at the same sequence.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl crate::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_type(&self) -> ClientType {
+        ClientType::SoloMachine
+    }
+
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        AnyMisbehaviour::SoloMachine(self)
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| Kind::InvalidRawMisbehaviour.context(e))?,
+            sequence: raw.sequence,
+            signature_one: signature_and_data(
+                raw.signature_one
+                    .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing signature one"))?,
+            )?,
+            signature_two: signature_and_data(
+                raw.signature_two
+                    .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("missing signature two"))?,
+            )?,
+        })
+    }
+}
+
+fn signature_and_data(raw: RawSignatureAndData) -> Result<SignatureAndData, Error> {
+    Ok(SignatureAndData {
+        signature: raw.signature,
+        data_type: DataType::from_i32(raw.data_type)
+            .ok_or_else(|| Kind::InvalidRawMisbehaviour.context("unknown data type"))?,
+        data: raw.data,
+        timestamp: raw.timestamp,
+    })
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        let to_raw = |value: SignatureAndData| RawSignatureAndData {
+            signature: value.signature,
+            data_type: value.data_type as i32,
+            data: value.data,
+            timestamp: value.timestamp,
+        };
+
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            sequence: value.sequence,
+            signature_one: Some(to_raw(value.signature_one)),
+            signature_two: Some(to_raw(value.signature_two)),
+        }
+    }
+}
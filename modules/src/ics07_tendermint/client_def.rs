@@ -1,12 +1,30 @@
+use std::convert::{TryFrom, TryInto};
+
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use tendermint::time::Time;
+use tendermint_light_client::operations::{
+    ProdCommitValidator, ProdHasher, ProdVotingPowerCalculator,
+};
+use tendermint_light_client::predicates::{ProdPredicates, VerificationPredicates};
+use tendermint_proto::Protobuf;
+
+use crate::downcast;
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
 use crate::ics02_client::header::Header as ICS2Header;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
 use crate::ics07_tendermint::client_state::ClientState;
 use crate::ics07_tendermint::consensus_state::ConsensusState;
 use crate::ics07_tendermint::header::Header;
+use crate::ics07_tendermint::misbehaviour::Misbehaviour;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
+use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerklePath, MerkleProof};
 use crate::ics24_host::identifier::ClientId;
 use crate::ics24_host::identifier::ConnectionId;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics24_host::Path;
 use crate::Height;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -14,11 +32,14 @@ pub struct TendermintClient;
 
 impl ClientDef for TendermintClient {
     type Header = Header;
+    type Misbehaviour = Misbehaviour;
     type ClientState = ClientState;
     type ConsensusState = ConsensusState;
 
     fn check_header_and_update_state(
         &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -28,7 +49,15 @@ impl ClientDef for TendermintClient {
             );
         }
 
-        // TODO: Additional verifications should be implemented here.
+        let trusted_consensus_state =
+            trusted_consensus_state(ctx, &client_id, &client_state, &header)?;
+
+        verify_header(
+            &client_state,
+            &trusted_consensus_state,
+            &header,
+            host_time(ctx)?,
+        )?;
 
         Ok((
             client_state.with_header(header.clone()),
@@ -36,41 +65,538 @@ impl ClientDef for TendermintClient {
         ))
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        if misbehaviour.client_id != client_id {
+            return Err(format!(
+                "misbehaviour of client {} cannot freeze client {}",
+                misbehaviour.client_id, client_id
+            )
+            .into());
+        }
+
+        let header1 = &misbehaviour.header1;
+        let header2 = &misbehaviour.header2;
+
+        // By convention, the first header is the one at the greater height.
+        if header1.height() < header2.height() {
+            return Err(format!(
+                "header 1 height {} must be greater than or equal to header 2 height {}",
+                header1.height(),
+                header2.height()
+            )
+            .into());
+        }
+
+        // Two headers conflict if they commit different blocks at the same height, or if they
+        // break the monotonicity of BFT time.
+        if header1.height() == header2.height() {
+            if header1.signed_header.header.hash() == header2.signed_header.header.hash() {
+                return Err("headers at the same height are identical".into());
+            }
+        } else if header1.signed_header.header.time > header2.signed_header.header.time {
+            return Err("headers at different heights do not violate BFT time monotonicity".into());
+        }
+
+        // Both headers must have been signed by validators trusted by the client.
+        for header in &[header1, header2] {
+            let trusted_consensus_state =
+                trusted_consensus_state(ctx, &client_id, &client_state, header)?;
+            verify_header_commit(
+                &client_state,
+                &trusted_consensus_state,
+                header,
+                host_time(ctx)?,
+            )?;
+        }
+
+        Ok(client_state.with_frozen_height(header1.height()))
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        upgrade_height: Height,
+        proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.upgrade_path.is_empty() {
+            return Err("client has no upgrade path".into());
+        }
+
+        if client_state.latest_height() >= upgraded_client_state.latest_height() {
+            return Err(format!(
+                "upgraded client height {} must be greater than the client latest height {}",
+                upgraded_client_state.latest_height(),
+                client_state.latest_height()
+            )
+            .into());
+        }
+
+        // The proof is verified against the last consensus state of the chain before the upgrade.
+        let consensus_state = ctx
+            .consensus_state(&client_id, upgrade_height)
+            .and_then(|cs| downcast!(cs => AnyConsensusState::Tendermint))
+            .ok_or_else(|| {
+                format!(
+                    "no consensus state for client {} at the upgrade height {}",
+                    client_id, upgrade_height
+                )
+            })?;
+
+        let merkle_path = upgraded_client_state_path(&client_state.upgrade_path, upgrade_height)?;
+        let value = AnyClientState::Tendermint(upgraded_client_state.clone().zero_custom_fields())
+            .encode_vec()?;
+        verify_merkle_proof(&consensus_state.root, proof_upgrade, merkle_path, value)?;
+
+        // The custom fields of this client carry over to the upgraded client.
+        let new_client_state = ClientState {
+            chain_id: upgraded_client_state.chain_id,
+            unbonding_period: upgraded_client_state.unbonding_period,
+            latest_height: upgraded_client_state.latest_height,
+            consensus_params: upgraded_client_state.consensus_params,
+            upgrade_path: upgraded_client_state.upgrade_path,
+            ..client_state
+        };
+
+        // `MsgUpgradeClient` carries neither the upgraded consensus state, committed at
+        // `upgradedIBCState/{upgrade_height}/upgradedConsState`, nor a proof of it: the consensus
+        // state is not verified, but derived from the last one before the upgrade. The root of the
+        // upgraded chain is not known in advance, so the new consensus state only serves to verify
+        // the first headers of the upgraded chain against the last validators of the chain before
+        // the upgrade.
+        let new_consensus_state = ConsensusState::new(
+            CommitmentRoot::from(vec![]),
+            consensus_state.timestamp,
+            consensus_state.next_validators_hash,
+        );
+
+        Ok((new_client_state, new_consensus_state))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn verify_client_consensus_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProof,
-        _client_id: &ClientId,
-        _consensus_height: Height,
-        _expected_consensus_state: &AnyConsensusState,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        client_id: &ClientId,
+        consensus_height: Height,
+        expected_consensus_state: &AnyConsensusState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        let path = Path::ClientConsensusState {
+            client_id: client_id.clone(),
+            epoch: consensus_height.version_number,
+            height: consensus_height.version_height,
+        };
+        let value = expected_consensus_state.encode_vec()?;
+        verify_membership(client_state, height, root, prefix, proof, path, value)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _prefix: &CommitmentPrefix,
-        _proof: &CommitmentProof,
-        _connection_id: &ConnectionId,
-        _expected_connection_end: &ConnectionEnd,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        connection_id: &ConnectionId,
+        expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Connections(connection_id.clone());
+        let value = expected_connection_end.encode_vec()?;
+        verify_membership(client_state, height, root, prefix, proof, path, value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+        let value = expected_channel_end.encode_vec()?;
+        verify_membership(client_state, height, root, prefix, proof, path, value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Commitments {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        verify_membership(
+            client_state,
+            height,
+            root,
+            prefix,
+            proof,
+            path,
+            commitment.to_vec(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        ack_commitment: &[u8],
     ) -> Result<(), Box<dyn std::error::Error>> {
-        todo!()
+        let path = Path::Acks {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        verify_membership(
+            client_state,
+            height,
+            root,
+            prefix,
+            proof,
+            path,
+            ack_commitment.to_vec(),
+        )
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+        // Sequence numbers are stored in big endian.
+        let value = u64::from(next_sequence_recv).to_be_bytes().to_vec();
+        verify_membership(client_state, height, root, prefix, proof, path, value)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        proof: &CommitmentProof,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Path::Receipts {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+            sequence: sequence.into(),
+        };
+        verify_non_membership(client_state, height, root, prefix, proof, path)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
-        _client_state: &Self::ClientState,
-        _height: Height,
-        _root: &CommitmentRoot,
-        _prefix: &CommitmentPrefix,
-        _client_id: &ClientId,
-        _proof: &CommitmentProof,
-        _expected_client_state: &AnyClientState,
+        client_state: &Self::ClientState,
+        height: Height,
+        root: &CommitmentRoot,
+        prefix: &CommitmentPrefix,
+        client_id: &ClientId,
+        proof: &CommitmentProof,
+        expected_client_state: &AnyClientState,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        unimplemented!()
+        let path = Path::ClientState(client_id.clone());
+        let value = expected_client_state.encode_vec()?;
+        verify_membership(client_state, height, root, prefix, proof, path, value)
+    }
+}
+
+/// The current time of the host chain, which Tendermint clients need to tell whether headers are
+/// expired or from the future.
+fn host_time(ctx: &dyn ClientReader) -> Result<Time, Box<dyn std::error::Error>> {
+    ctx.host_timestamp()
+        .into_tm_time()
+        .ok_or_else(|| "the host chain has no timestamp".into())
+}
+
+/// Fetches the consensus state at the trusted height of `header`, after checking that `header`
+/// is for the chain tracked by the client and above its trusted height.
+fn trusted_consensus_state(
+    ctx: &dyn ClientReader,
+    client_id: &ClientId,
+    client_state: &ClientState,
+    header: &Header,
+) -> Result<ConsensusState, Box<dyn std::error::Error>> {
+    if header.signed_header.header.chain_id.as_str() != client_state.chain_id {
+        return Err(format!(
+            "header chain id {} does not match the client chain id {}",
+            header.signed_header.header.chain_id, client_state.chain_id
+        )
+        .into());
+    }
+
+    if header.trusted_height.version_number != header.height().version_number {
+        return Err(format!(
+            "header version {} does not match the trusted version {}",
+            header.height().version_number,
+            header.trusted_height.version_number
+        )
+        .into());
+    }
+
+    if header.trusted_height >= header.height() {
+        return Err(format!(
+            "header height {} must be greater than the trusted height {}",
+            header.height(),
+            header.trusted_height
+        )
+        .into());
+    }
+
+    let trusted_consensus_state = ctx
+        .consensus_state(client_id, header.trusted_height)
+        .and_then(|cs| downcast!(cs => AnyConsensusState::Tendermint))
+        .ok_or_else(|| {
+            format!(
+                "no trusted consensus state for client {} at height {}",
+                client_id, header.trusted_height
+            )
+        })?;
+
+    Ok(trusted_consensus_state)
+}
+
+/// Runs the Tendermint light client verification of `header` against the trusted consensus state,
+/// i.e., a skipping verification from the trusted height to the height of the header.
+fn verify_header(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    header: &Header,
+    now: Time,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let predicates = ProdPredicates::default();
+    let untrusted_header = &header.signed_header.header;
+
+    // The header must not be too far in the future, nor older than the trusted state.
+    predicates.is_header_from_past(untrusted_header, client_state.max_clock_drift, now)?;
+    if untrusted_header.time <= trusted_consensus_state.timestamp {
+        return Err("header time is not greater than the trusted consensus state time".into());
+    }
+
+    verify_header_commit(client_state, trusted_consensus_state, header, now)
+}
+
+/// Verifies that the commit of `header` is valid, and signed by enough of the validators trusted
+/// by the (unexpired) trusted consensus state.
+fn verify_header_commit(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    header: &Header,
+    now: Time,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let predicates = ProdPredicates::default();
+    let hasher = ProdHasher::default();
+    let commit_validator = ProdCommitValidator::default();
+    let voting_power_calculator = ProdVotingPowerCalculator::default();
+
+    // The trusted consensus state must not have expired.
+    if trusted_consensus_state.timestamp + client_state.trusting_period <= now {
+        return Err(format!(
+            "trusted consensus state at height {} has expired",
+            header.trusted_height
+        )
+        .into());
+    }
+
+    // The validator sets in the header must match the hashes they are committed to.
+    if header.validator_set.hash() != header.signed_header.header.validators_hash {
+        return Err("header validator set does not match the validators hash".into());
+    }
+    if header.trusted_validator_set.hash() != trusted_consensus_state.next_validators_hash {
+        return Err("trusted validator set does not match the trusted next validators hash".into());
+    }
+
+    // The commit must be for this header and properly signed by its validator set.
+    predicates.header_matches_commit(&header.signed_header, &hasher)?;
+    predicates.valid_commit(
+        &header.signed_header,
+        &header.validator_set,
+        &commit_validator,
+    )?;
+
+    // An adjacent header must be signed by the validators which the trusted consensus state
+    // designated as the next ones. Skipping verification instead requires enough of the trusted
+    // validators to have signed the commit.
+    if header.height().version_height == header.trusted_height.version_height + 1 {
+        if header.signed_header.header.validators_hash
+            != trusted_consensus_state.next_validators_hash
+        {
+            return Err(
+                "adjacent header validators hash does not match the trusted next validators hash"
+                    .into(),
+            );
+        }
+    } else {
+        predicates.has_sufficient_validators_overlap(
+            &header.signed_header,
+            &header.trusted_validator_set,
+            &client_state.trust_level,
+            &voting_power_calculator,
+        )?;
+    }
+
+    // More than 2/3 of the voting power of the header validator set must have signed the commit.
+    predicates.has_sufficient_signers_overlap(
+        &header.signed_header,
+        &header.validator_set,
+        &voting_power_calculator,
+    )?;
+
+    Ok(())
+}
+
+/// The path at which the counterparty chain commits the upgraded client state, without its custom
+/// fields: the ICS 24 path `upgradedIBCState/{upgrade_height}/upgradedClient`, in the store named by
+/// the first segment of the `upgrade_path` of the client.
+fn upgraded_client_state_path(
+    upgrade_path: &str,
+    upgrade_height: Height,
+) -> Result<MerklePath, Box<dyn std::error::Error>> {
+    let store = match upgrade_path.split('/').next() {
+        Some(store) if !store.is_empty() => store,
+        _ => {
+            return Err(format!("invalid upgrade path {}: expected a store", upgrade_path).into());
+        }
+    };
+
+    apply_prefix(
+        &CommitmentPrefix(store.as_bytes().to_vec()),
+        Path::UpgradedClientState(upgrade_height.version_height).to_string(),
+    )
+}
+
+/// Verifies that the counterparty chain stores `value` at the ICS24 `path`, under the IBC store
+/// designated by `prefix`, given the commitment `root` of its state at `height`.
+fn verify_membership(
+    client_state: &ClientState,
+    height: Height,
+    root: &CommitmentRoot,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProof,
+    path: Path,
+    value: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    check_proof_height(client_state, height)?;
+
+    let merkle_path = apply_prefix(prefix, path.to_string())?;
+    verify_merkle_proof(root, proof, merkle_path, value)
+}
+
+/// Verifies that the counterparty chain stores nothing at the ICS24 `path`, under the IBC store
+/// designated by `prefix`, given the commitment `root` of its state at `height`.
+fn verify_non_membership(
+    client_state: &ClientState,
+    height: Height,
+    root: &CommitmentRoot,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProof,
+    path: Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    check_proof_height(client_state, height)?;
+
+    let merkle_path = apply_prefix(prefix, path.to_string())?;
+    let merkle_proof: MerkleProof = RawMerkleProof::try_from(proof.clone())?.try_into()?;
+
+    merkle_proof.verify_non_membership(&cosmos_specs(), root, merkle_path)?;
+    Ok(())
+}
+
+fn check_proof_height(
+    client_state: &ClientState,
+    height: Height,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if client_state.is_frozen() {
+        return Err(format!("client is frozen at height {}", client_state.frozen_height).into());
+    }
+
+    if client_state.latest_height() < height {
+        return Err(format!(
+            "client latest height {} is lower than the proof height {}",
+            client_state.latest_height(),
+            height
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn verify_merkle_proof(
+    root: &CommitmentRoot,
+    proof: &CommitmentProof,
+    merkle_path: MerklePath,
+    value: Vec<u8>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let merkle_proof: MerkleProof = RawMerkleProof::try_from(proof.clone())?.try_into()?;
+
+    merkle_proof.verify_membership(&cosmos_specs(), root, merkle_path, value)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics07_tendermint::client_def::upgraded_client_state_path;
+    use crate::Height;
+
+    #[test]
+    fn upgraded_client_state_paths() {
+        let path =
+            upgraded_client_state_path("upgrade/upgradedIBCState", Height::new(1, 42)).unwrap();
+        assert_eq!(
+            path.key_path,
+            vec![
+                "upgrade".to_string(),
+                "upgradedIBCState/42/upgradedClient".to_string()
+            ]
+        );
+
+        assert!(upgraded_client_state_path("", Height::new(1, 42)).is_err());
+        assert!(upgraded_client_state_path("/upgradedIBCState", Height::new(1, 42)).is_err());
     }
 }
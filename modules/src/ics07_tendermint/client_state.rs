@@ -2,6 +2,7 @@ use std::convert::{TryFrom, TryInto};
 use std::time::Duration;
 
 use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawClientState, Fraction};
+use ibc_proto::ics23::{InnerSpec as RawInnerSpec, LeafOp as RawLeafOp, ProofSpec as RawProofSpec};
 use tendermint::consensus::Params;
 use tendermint_light_client::types::TrustThreshold;
 use tendermint_proto::Protobuf;
@@ -95,6 +96,37 @@ impl ClientState {
         self.latest_height
     }
 
+    pub fn is_frozen(&self) -> bool {
+        // If 'frozen_height' is set to a non-zero value, then the client state is frozen.
+        !self.frozen_height.is_zero()
+    }
+
+    /// Freezes the client at height `h`, e.g., upon evidence of misbehaviour.
+    pub fn with_frozen_height(self, h: Height) -> Self {
+        ClientState {
+            frozen_height: h,
+            ..self
+        }
+    }
+
+    /// Returns this client state with the client-specific fields (i.e., those chosen by the
+    /// relayer which created the client) zeroed out. This is the form in which a chain commits the
+    /// client state it upgrades to, since these fields remain those of the upgrading client.
+    pub fn zero_custom_fields(self) -> Self {
+        ClientState {
+            trust_level: TrustThreshold {
+                numerator: 0,
+                denominator: 0,
+            },
+            trusting_period: Duration::default(),
+            max_clock_drift: Duration::default(),
+            frozen_height: Height::zero(),
+            allow_update_after_expiry: false,
+            allow_update_after_misbehaviour: false,
+            ..self
+        }
+    }
+
     pub fn with_header(self, h: Header) -> Self {
         // TODO: Clarify which fields should update.
         ClientState {
@@ -120,8 +152,7 @@ impl crate::ics02_client::state::ClientState for ClientState {
     }
 
     fn is_frozen(&self) -> bool {
-        // If 'frozen_height' is set to a non-zero value, then the client state is frozen.
-        !self.frozen_height.is_zero()
+        self.is_frozen()
     }
 
     fn wrap_any(self) -> AnyClientState {
@@ -194,7 +225,7 @@ impl From<ClientState> for RawClientState {
             frozen_height: Some(value.frozen_height.into()),
             latest_height: Some(value.latest_height.into()),
             consensus_params: Some(value.consensus_params.into()),
-            proof_specs: cosmos_specs(),
+            proof_specs: cosmos_specs().into_iter().map(raw_proof_spec).collect(),
             allow_update_after_expiry: false,
             allow_update_after_misbehaviour: false,
             upgrade_path: value.upgrade_path,
@@ -202,6 +233,29 @@ impl From<ClientState> for RawClientState {
     }
 }
 
+/// Converts a proof specification of the `ics23` crate to its counterpart in the raw client state.
+fn raw_proof_spec(spec: ics23::ProofSpec) -> RawProofSpec {
+    RawProofSpec {
+        leaf_spec: spec.leaf_spec.map(|leaf| RawLeafOp {
+            hash: leaf.hash,
+            prehash_key: leaf.prehash_key,
+            prehash_value: leaf.prehash_value,
+            length: leaf.length,
+            prefix: leaf.prefix,
+        }),
+        inner_spec: spec.inner_spec.map(|inner| RawInnerSpec {
+            child_order: inner.child_order,
+            child_size: inner.child_size,
+            min_prefix_length: inner.min_prefix_length,
+            max_prefix_length: inner.max_prefix_length,
+            empty_child: inner.empty_child,
+            hash: inner.hash,
+        }),
+        max_depth: spec.max_depth,
+        min_depth: spec.min_depth,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -31,6 +31,9 @@ pub enum Kind {
 
     #[error("invalid raw header")]
     InvalidRawHeader,
+
+    #[error("invalid raw misbehaviour")]
+    InvalidRawMisbehaviour,
 }
 
 impl Kind {
@@ -5,3 +5,4 @@ pub mod client_state;
 pub mod consensus_state;
 pub mod error;
 pub mod header;
+pub mod misbehaviour;
@@ -0,0 +1,236 @@
+use crate::ics02_client::client_def::{
+    AnyClientState, AnyConsensusState, AnyMisbehaviour, ClientDef,
+};
+use crate::ics02_client::context::ClientReader;
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics09_localhost::client_state::ClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState;
+use crate::ics09_localhost::error::Kind;
+use crate::ics09_localhost::header::Header;
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::Height;
+
+/// The loopback client, which tracks the host chain itself. Since the host can read its own store,
+/// the connection and channel handlers verify the states behind loopback clients against that
+/// store directly (see `ics09_localhost::verify`), and the proof verification functions of this
+/// client def always fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalhostClient;
+
+impl ClientDef for LocalhostClient {
+    type Header = Header;
+    type Misbehaviour = AnyMisbehaviour;
+    type ClientState = ClientState;
+    type ConsensusState = ConsensusState;
+
+    fn check_header_and_update_state(
+        &self,
+        ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        _header: Self::Header,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        // The header carries nothing: the client simply catches up with the host chain.
+        Ok((client_state.with_height(ctx.host_height()), ConsensusState))
+    }
+
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedOperation
+            .context("the host chain cannot misbehave towards itself")
+            .into())
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _upgraded_client_state: Self::ClientState,
+        _upgrade_height: Height,
+        _proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedOperation
+            .context("loopback clients cannot be upgraded")
+            .into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_consensus_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _client_id: &ClientId,
+        _consensus_height: Height,
+        _expected_consensus_state: &AnyConsensusState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_connection_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _connection_id: &ConnectionId,
+        _expected_connection_end: &ConnectionEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_client_full_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _client_id: &ClientId,
+        _proof: &CommitmentProof,
+        _expected_client_state: &AnyClientState,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err(Kind::UnsupportedProof.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::ics02_client::client_def::{AnyClientState, AnyHeader};
+    use crate::ics02_client::client_type::ClientType;
+    use crate::ics02_client::handler::{dispatch, ClientResult};
+    use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+    use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics09_localhost::client_state::ClientState;
+    use crate::ics09_localhost::header::Header;
+    use crate::ics24_host::identifier::ClientId;
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_account_id;
+    use crate::Height;
+
+    #[test]
+    fn update_localhost_client() {
+        let client_id = ClientId::from_str("localhostclient").unwrap();
+
+        // The client lags behind the host chain, which is at height 5.
+        let ctx = MockContext::default().with_client_parametrized(
+            &client_id,
+            Height::new(1, 2),
+            Some(ClientType::Localhost),
+            None,
+        );
+
+        let msg = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: AnyHeader::Localhost(Header),
+            signer: get_dummy_account_id(),
+        };
+
+        let output = dispatch(&ctx, ClientMsg::UpdateClient(msg)).unwrap();
+        match output.result {
+            ClientResult::Update(res) => {
+                assert_eq!(res.client_id, client_id);
+                assert_eq!(
+                    res.client_state,
+                    AnyClientState::Localhost(ClientState::new(
+                        "mockgaia-1".to_string(),
+                        Height::new(1, 5)
+                    ))
+                );
+            }
+            _ => panic!("unexpected result type: expected ClientResult::Update"),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::lightclients::localhost::v1::ClientState as RawClientState;
+
+use crate::ics02_client::client_def::AnyClientState;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::Height;
+
+/// The state of a loopback client, which tracks the host chain itself. The client never holds a
+/// root to verify proofs against: the handlers read the host store directly instead.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientState {
+    pub chain_id: String,
+    pub height: Height,
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl ClientState {
+    pub fn new(chain_id: String, height: Height) -> Self {
+        Self { chain_id, height }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    /// Moves the client to `height`, the current height of the host chain.
+    pub fn with_height(self, height: Height) -> Self {
+        ClientState { height, ..self }
+    }
+}
+
+impl crate::ics02_client::state::ClientState for ClientState {
+    fn chain_id(&self) -> String {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.height
+    }
+
+    fn is_frozen(&self) -> bool {
+        // The host chain cannot misbehave towards itself.
+        false
+    }
+
+    fn wrap_any(self) -> AnyClientState {
+        AnyClientState::Localhost(self)
+    }
+}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        if raw.chain_id.trim().is_empty() {
+            return Err(Kind::InvalidRawClientState.context("empty chain id").into());
+        }
+
+        Ok(Self {
+            chain_id: raw.chain_id,
+            height: raw
+                .height
+                .ok_or_else(|| Kind::InvalidRawClientState.context("missing height"))?
+                .try_into()
+                .map_err(|_| Kind::InvalidRawHeight)?,
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            chain_id: value.chain_id,
+            height: Some(value.height.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ibc_proto::ibc::lightclients::localhost::v1::ClientState as RawClientState;
+
+    use crate::ics02_client::client_def::AnyClientState;
+    use crate::ics09_localhost::client_state::ClientState;
+    use crate::Height;
+
+    #[test]
+    fn client_state_serialization() {
+        let client_state = ClientState::new("chainA-1".to_string(), Height::new(1, 5));
+
+        let raw = RawClientState::from(client_state.clone());
+        assert_eq!(ClientState::try_from(raw).unwrap(), client_state);
+
+        let any: prost_types::Any = AnyClientState::Localhost(client_state.clone()).into();
+        assert_eq!(
+            AnyClientState::try_from(any).unwrap(),
+            AnyClientState::Localhost(client_state)
+        );
+
+        let raw = RawClientState {
+            chain_id: "".to_string(),
+            height: Some(Height::new(1, 5).into()),
+        };
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
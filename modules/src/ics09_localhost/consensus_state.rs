@@ -0,0 +1,33 @@
+use crate::ics02_client::{client_def::AnyConsensusState, client_type::ClientType};
+use crate::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+/// The loopback client verifies against the host store, hence its consensus states have no root.
+static EMPTY_ROOT: CommitmentRoot = CommitmentRoot(Vec::new());
+
+/// The consensus state of a loopback client, which carries no data: the host chain trusts itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConsensusState;
+
+impl crate::ics02_client::state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &EMPTY_ROOT
+    }
+
+    /// Packets between modules of the same chain never time out based on time.
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::none()
+    }
+
+    fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn wrap_any(self) -> AnyConsensusState {
+        AnyConsensusState::Localhost(self)
+    }
+}
@@ -0,0 +1,40 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("invalid raw client state")]
+    InvalidRawClientState,
+
+    #[error("invalid raw height")]
+    InvalidRawHeight,
+
+    #[error("chain id mismatch: expected {0}, got {1}")]
+    ChainIdMismatch(String, String),
+
+    #[error("client is at height {0}, lower than the proof height {1}")]
+    InsufficientHeight(crate::Height, crate::Height),
+
+    #[error("the loopback client only verifies against the host store, not against a proof")]
+    UnsupportedProof,
+
+    #[error("value in the host store differs from the expected one")]
+    ValueMismatch,
+
+    #[error("value is missing from the host store")]
+    MissingValue,
+
+    #[error("value is unexpectedly present in the host store")]
+    UnexpectedValue,
+
+    #[error("unsupported operation")]
+    UnsupportedOperation,
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
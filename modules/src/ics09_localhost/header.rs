@@ -0,0 +1,21 @@
+use crate::ics02_client::{client_def::AnyHeader, client_type::ClientType};
+use crate::Height;
+
+/// The header of a loopback client, which carries no data: upon an update, the client moves to the
+/// current height of the host chain, which the host reports itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Header;
+
+impl crate::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn height(&self) -> Height {
+        Height::zero()
+    }
+
+    fn wrap_any(self) -> AnyHeader {
+        AnyHeader::Localhost(self)
+    }
+}
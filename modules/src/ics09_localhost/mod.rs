@@ -0,0 +1,8 @@
+//! ICS 09: Loopback Client
+
+pub mod client_def;
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod verify;
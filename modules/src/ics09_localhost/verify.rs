@@ -0,0 +1,190 @@
+//! Verification of the states behind a loopback client. Instead of checking a proof against a
+//! commitment root, these functions read the counterparty states, which live on the host chain
+//! itself, directly from the host store.
+
+use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics03_connection::context::ConnectionReader;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::ChannelReader;
+use crate::ics04_channel::packet::Sequence;
+use crate::ics09_localhost::client_state::ClientState;
+use crate::ics09_localhost::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use crate::ics24_host::Path;
+use crate::Height;
+
+pub fn verify_client_consensus_state(
+    ctx: &dyn ConnectionReader,
+    client_state: &ClientState,
+    height: Height,
+    client_id: &ClientId,
+    consensus_height: Height,
+    expected_consensus_state: &AnyConsensusState,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::ClientConsensusState {
+        client_id: client_id.clone(),
+        epoch: consensus_height.version_number,
+        height: consensus_height.version_height,
+    };
+    check_value(
+        path,
+        ctx.client_consensus_state(client_id, consensus_height),
+        expected_consensus_state,
+    )
+}
+
+pub fn verify_connection_state(
+    ctx: &dyn ConnectionReader,
+    client_state: &ClientState,
+    height: Height,
+    connection_id: &ConnectionId,
+    expected_connection_end: &ConnectionEnd,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::Connections(connection_id.clone());
+    check_value(
+        path,
+        ctx.connection_end(connection_id),
+        expected_connection_end,
+    )
+}
+
+pub fn verify_client_full_state(
+    ctx: &dyn ConnectionReader,
+    client_state: &ClientState,
+    height: Height,
+    client_id: &ClientId,
+    expected_client_state: &AnyClientState,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::ClientState(client_id.clone());
+    check_value(path, ctx.client_state(client_id), expected_client_state)
+}
+
+pub fn verify_channel_state(
+    ctx: &dyn ChannelReader,
+    client_state: &ClientState,
+    height: Height,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    expected_channel_end: &ChannelEnd,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::ChannelEnds(port_id.clone(), channel_id.clone());
+    check_value(
+        path,
+        ctx.channel_end(port_id, channel_id),
+        expected_channel_end,
+    )
+}
+
+pub fn verify_packet_data(
+    ctx: &dyn ChannelReader,
+    client_state: &ClientState,
+    height: Height,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+    commitment: &[u8],
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::Commitments {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: sequence.into(),
+    };
+    check_value(
+        path,
+        ctx.packet_commitment(port_id, channel_id, sequence),
+        &commitment.to_vec(),
+    )
+}
+
+pub fn verify_packet_acknowledgement(
+    ctx: &dyn ChannelReader,
+    client_state: &ClientState,
+    height: Height,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+    ack_commitment: &[u8],
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::Acks {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        sequence: sequence.into(),
+    };
+    check_value(
+        path,
+        ctx.packet_acknowledgement(port_id, channel_id, sequence),
+        &ack_commitment.to_vec(),
+    )
+}
+
+pub fn verify_next_sequence_recv(
+    ctx: &dyn ChannelReader,
+    client_state: &ClientState,
+    height: Height,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    next_sequence_recv: Sequence,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    let path = Path::SeqRecvs(port_id.clone(), channel_id.clone());
+    check_value(
+        path,
+        ctx.next_sequence_recv(port_id, channel_id),
+        &next_sequence_recv,
+    )
+}
+
+pub fn verify_packet_receipt_absence(
+    ctx: &dyn ChannelReader,
+    client_state: &ClientState,
+    height: Height,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<(), Error> {
+    check_height(client_state, height)?;
+
+    match ctx.packet_receipt(port_id, channel_id, sequence) {
+        Some(_) => {
+            let path = Path::Receipts {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                sequence: sequence.into(),
+            };
+            Err(Kind::UnexpectedValue.context(path.to_string()).into())
+        }
+        None => Ok(()),
+    }
+}
+
+/// Checks that the client has caught up with the host chain at `height`, the height at which the
+/// counterparty claims to have stored the state under verification.
+fn check_height(client_state: &ClientState, height: Height) -> Result<(), Error> {
+    if client_state.latest_height() < height {
+        return Err(Kind::InsufficientHeight(client_state.latest_height(), height).into());
+    }
+    Ok(())
+}
+
+/// Checks that the host store holds the `expected` value under `path`.
+fn check_value<T: PartialEq>(path: Path, stored: Option<T>, expected: &T) -> Result<(), Error> {
+    match stored {
+        Some(value) if value == *expected => Ok(()),
+        Some(_) => Err(Kind::ValueMismatch.context(path.to_string()).into()),
+        None => Err(Kind::MissingValue.context(path.to_string()).into()),
+    }
+}
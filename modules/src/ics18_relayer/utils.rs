@@ -61,6 +61,7 @@ mod tests {
     use crate::mock::host::HostType;
     use crate::Height;
 
+    use crate::ics02_client::client_def::AnyHeader;
     use crate::ics02_client::client_type::ClientType;
     use crate::ics02_client::header::Header;
     use std::str::FromStr;
@@ -70,7 +71,8 @@ mod tests {
     /// Implements a "ping pong" of client update messages, so that two chains repeatedly
     /// process a client update message and update their height in succession.
     fn client_update_ping_pong() {
-        let chain_a_start_height = Height::new(1, 11);
+        // Chain A must not lag behind the headers of B, which its Tendermint client verifies.
+        let chain_a_start_height = Height::new(1, 21);
         let chain_b_start_height = Height::new(1, 20);
         let client_on_b_for_a_height = Height::new(1, 10); // Should be smaller than `chain_a_start_height`
         let client_on_a_for_b_height = Height::new(1, 20); // Should be smaller than `chain_b_start_height`
@@ -79,6 +81,8 @@ mod tests {
         let client_on_a_for_b = ClientId::from_str("ibconeclient").unwrap();
         let client_on_b_for_a = ClientId::from_str("ibczeroclient").unwrap();
 
+        let chain_id_b = ChainId::new("mockgaiaB".to_string(), 1);
+
         // Create two mock contexts, one for each chain.
         let mut ctx_a = MockContext::new(
             ChainId::new("mockgaiaA".to_string(), 1),
@@ -86,14 +90,15 @@ mod tests {
             5,
             chain_a_start_height,
         )
-        .with_client_parametrized(
+        .with_client_parametrized_with_chain_id(
             &client_on_a_for_b,
             client_on_a_for_b_height,
             Some(ClientType::Tendermint), // The target host chain (B) is synthetic TM.
             Some(client_on_a_for_b_height),
+            chain_id_b.clone(),
         );
         let mut ctx_b = MockContext::new(
-            ChainId::new("mockgaiaB".to_string(), 1),
+            chain_id_b,
             HostType::SyntheticTendermint,
             5,
             chain_b_start_height,
@@ -152,7 +157,7 @@ mod tests {
 
             // Update client on chain B to latest height of B.
             // - create the client update message with the latest header from B
-            let b_latest_header = ctx_b.query_latest_header().unwrap();
+            let mut b_latest_header = ctx_b.query_latest_header().unwrap();
             assert_eq!(
                 b_latest_header.client_type(),
                 ClientType::Tendermint,
@@ -161,6 +166,14 @@ mod tests {
                 ClientType::Tendermint
             );
 
+            // - the header is verified against the latest consensus state of the client on A
+            if let AnyHeader::Tendermint(header) = &mut b_latest_header {
+                header.trusted_height = ctx_a
+                    .query_client_full_state(&client_on_a_for_b)
+                    .unwrap()
+                    .latest_height();
+            }
+
             let client_msg_a_res =
                 create_client_update_datagram(&ctx_a, &client_on_a_for_b, b_latest_header);
             assert_eq!(
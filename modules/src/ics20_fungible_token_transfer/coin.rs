@@ -0,0 +1,54 @@
+use std::convert::TryFrom;
+
+use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+
+/// A fungible token: an amount of some denomination. Denominations of tokens which were received
+/// over IBC carry the trace of the channels they went through, e.g., `transfer/channelid/atom`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: u64,
+}
+
+impl Coin {
+    pub fn new(denom: String, amount: u64) -> Self {
+        Self { denom, amount }
+    }
+}
+
+impl std::fmt::Display for Coin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<RawCoin> for Coin {
+    type Error = Error;
+
+    fn try_from(raw: RawCoin) -> Result<Self, Self::Error> {
+        if raw.denom.is_empty() {
+            return Err(Kind::InvalidToken.context("empty denomination").into());
+        }
+
+        let amount = raw
+            .amount
+            .parse::<u64>()
+            .map_err(|e| Kind::InvalidToken.context(e))?;
+
+        Ok(Coin {
+            denom: raw.denom,
+            amount,
+        })
+    }
+}
+
+impl From<Coin> for RawCoin {
+    fn from(coin: Coin) -> Self {
+        RawCoin {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
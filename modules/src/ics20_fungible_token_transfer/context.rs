@@ -0,0 +1,264 @@
+//! ICS20 (fungible token transfer) context. The handlers need read access to the channels, through
+//! the `ChannelReader` of ICS4, and to the denomination traces of vouchers; the `ICS20Keeper` trait
+//! defines how any host chain applies their results, i.e., moves tokens. Tokens are held by the
+//! bank of the host chain, behind the `BankReader` and `BankKeeper` traits.
+
+use std::convert::TryFrom;
+
+use sha2::{Digest, Sha256};
+use tendermint::account::Id as AccountId;
+
+use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics20_fungible_token_transfer::handler::{TokenOperation, TransferResult};
+use crate::ics20_fungible_token_transfer::VERSION;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The address of the account which holds the tokens escrowed on the given channel end. As in the
+/// Cosmos SDK, this is the truncated SHA-256 hash of `ics20-1\0{port_id}/{channel_id}`.
+pub fn escrow_address(port_id: &PortId, channel_id: &ChannelId) -> AccountId {
+    let mut pre_image = VERSION.as_bytes().to_vec();
+    pre_image.push(0);
+    pre_image.extend(format!("{}/{}", port_id, channel_id).as_bytes());
+
+    let hash = Sha256::digest(&pre_image);
+    AccountId::try_from(hash[..20].to_vec()).unwrap()
+}
+
+/// Read access to the balances of the accounts of the host chain.
+pub trait BankReader {
+    /// Returns the amount of tokens of the given denomination held by the account.
+    fn balance(&self, account: &AccountId, denom: &str) -> u64;
+}
+
+/// Write access to the balances of the accounts of the host chain.
+pub trait BankKeeper: BankReader {
+    /// Moves the given tokens from the `from` account to the `to` account.
+    fn send_coins(&mut self, from: &AccountId, to: &AccountId, coin: &Coin) -> Result<(), Error>;
+
+    /// Creates the given tokens in the account.
+    fn mint_coins(&mut self, account: &AccountId, coin: &Coin) -> Result<(), Error>;
+
+    /// Destroys the given tokens from the account.
+    fn burn_coins(&mut self, account: &AccountId, coin: &Coin) -> Result<(), Error>;
+}
+
+/// A context supplying all the necessary read-only dependencies for processing any ICS20 message or
+/// packet.
+pub trait ICS20Reader: ChannelReader {
+    /// Returns the trace of the vouchers with denomination `ibc/{hash}`, if any.
+    fn denom_trace(&self, hash: &[u8]) -> Option<DenomTrace>;
+}
+
+/// A context supplying all the necessary write-only dependencies (i.e., storage writing facility)
+/// for processing any ICS20 message or packet.
+pub trait ICS20Keeper: ChannelKeeper + BankKeeper {
+    fn store_transfer_result(&mut self, result: TransferResult) -> Result<(), Error> {
+        // Move the tokens first: the transfer fails altogether if, e.g., the sender lacks funds.
+        match result.token_op {
+            Some(TokenOperation::Escrow {
+                port_id,
+                channel_id,
+                sender,
+                coin,
+            }) => self.escrow_tokens(&port_id, &channel_id, &sender, &coin)?,
+            Some(TokenOperation::Unescrow {
+                port_id,
+                channel_id,
+                receiver,
+                coin,
+            }) => self.unescrow_tokens(&port_id, &channel_id, &receiver, &coin)?,
+            Some(TokenOperation::Mint { receiver, coin }) => self.mint_tokens(&receiver, &coin)?,
+            Some(TokenOperation::Burn { sender, coin }) => self.burn_tokens(&sender, &coin)?,
+            None => {}
+        }
+
+        if let Some(denom_trace) = result.denom_trace {
+            self.store_denom_trace(denom_trace)?;
+        }
+
+        if let Some(packet_result) = result.packet_result {
+            self.store_packet_result(packet_result)
+                .map_err(|e| Kind::PacketError.context(e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Records the trace of vouchers, under its hash.
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), Error>;
+
+    /// Moves the given tokens from the `sender` account to the escrow account of the channel end.
+    fn escrow_tokens(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sender: &AccountId,
+        coin: &Coin,
+    ) -> Result<(), Error> {
+        let escrow = escrow_address(port_id, channel_id);
+        self.send_coins(sender, &escrow, coin)
+    }
+
+    /// Moves the given tokens from the escrow account of the channel end to the `receiver` account.
+    fn unescrow_tokens(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        receiver: &AccountId,
+        coin: &Coin,
+    ) -> Result<(), Error> {
+        let escrow = escrow_address(port_id, channel_id);
+        self.send_coins(&escrow, receiver, coin)
+    }
+
+    /// Creates the given tokens (i.e., vouchers) in the `receiver` account.
+    fn mint_tokens(&mut self, receiver: &AccountId, coin: &Coin) -> Result<(), Error> {
+        self.mint_coins(receiver, coin)
+    }
+
+    /// Destroys the given tokens (i.e., vouchers) from the `sender` account.
+    fn burn_tokens(&mut self, sender: &AccountId, coin: &Coin) -> Result<(), Error> {
+        self.burn_coins(sender, coin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::address::string_to_account;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::packet::test_utils::{
+        get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics05_port::context::PortReader;
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::context::{
+        escrow_address, BankReader, ICS20Keeper, ICS20Reader,
+    };
+    use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::ics20_fungible_token_transfer::handler::on_recv_packet::on_recv_packet;
+    use crate::ics20_fungible_token_transfer::handler::on_timeout_packet::on_timeout_packet;
+    use crate::ics20_fungible_token_transfer::handler::send_transfer::send_transfer;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_raw_msg_transfer;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_bech32_account;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn escrow_address_derivation() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_a = ChannelId::from_str("channelaaa").unwrap();
+        let channel_b = ChannelId::from_str("channelbbb").unwrap();
+
+        assert_eq!(
+            escrow_address(&port_id, &channel_a),
+            escrow_address(&port_id, &channel_a)
+        );
+        assert_ne!(
+            escrow_address(&port_id, &channel_a),
+            escrow_address(&port_id, &channel_b)
+        );
+    }
+
+    #[test]
+    fn transfer_flows_move_tokens() {
+        let account = string_to_account(get_dummy_bech32_account()).unwrap();
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::from_str("srchannelid").unwrap();
+        let cparty_channel_id = ChannelId::from_str("dstchannelid").unwrap();
+        let escrow = escrow_address(&port_id, &channel_id);
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let mut open_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(port_id.clone(), Some(cparty_channel_id.clone())),
+        );
+        open_chan_end.set_version("ics20-1".to_string());
+
+        let mut ctx = MockContext::default()
+            .with_client(&client_id, Height::new(1, 10))
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_channel(port_id.clone(), channel_id.clone(), open_chan_end)
+            .with_sequences(
+                port_id.clone(),
+                channel_id.clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            )
+            .with_balance(account, Coin::new("atom".to_string(), 150))
+            .with_port_capability(port_id.clone());
+        let capability = ctx.lookup_module_by_port(&port_id).unwrap();
+
+        // Native tokens are escrowed when sent out.
+        let msg = MsgTransfer::try_from(get_dummy_raw_msg_transfer("atom", 20)).unwrap();
+        let output = send_transfer(&ctx, &capability, msg).unwrap();
+        ctx.store_transfer_result(output.result).unwrap();
+        assert_eq!(ctx.balance(&account, "atom"), 50);
+        assert_eq!(ctx.balance(&escrow, "atom"), 100);
+
+        // The transfer fails altogether if the sender lacks funds.
+        let msg = MsgTransfer::try_from(get_dummy_raw_msg_transfer("atom", 20)).unwrap();
+        let output = send_transfer(&ctx, &capability, msg).unwrap();
+        assert!(ctx.store_transfer_result(output.result).is_err());
+        assert_eq!(ctx.balance(&account, "atom"), 50);
+
+        // Native tokens are released from escrow when the packet times out.
+        let sent_packet = Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: channel_id.clone(),
+            destination_port: port_id.clone(),
+            destination_channel: cparty_channel_id.clone(),
+            data: get_dummy_packet_data("atom", 100).to_bytes(),
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+        let output = on_timeout_packet(&sent_packet).unwrap();
+        ctx.store_transfer_result(output.result).unwrap();
+        assert_eq!(ctx.balance(&account, "atom"), 150);
+        assert_eq!(ctx.balance(&escrow, "atom"), 0);
+
+        // Foreign tokens are minted as vouchers, whose trace is recorded.
+        let received_packet = Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: cparty_channel_id,
+            destination_port: port_id.clone(),
+            destination_channel: channel_id.clone(),
+            data: get_dummy_packet_data("uatom", 100).to_bytes(),
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+        let voucher_trace =
+            DenomTrace::new("transfer/srchannelid".to_string(), "uatom".to_string());
+        let (result, _) = on_recv_packet(&received_packet).unwrap().result;
+        ctx.store_transfer_result(result).unwrap();
+        assert_eq!(ctx.balance(&account, &voucher_trace.ibc_denom()), 100);
+        assert_eq!(
+            ctx.denom_trace(&voucher_trace.hash()),
+            Some(voucher_trace.clone())
+        );
+
+        // Vouchers are burned when sent back to their source.
+        let msg = MsgTransfer::try_from(get_dummy_raw_msg_transfer(&voucher_trace.ibc_denom(), 20))
+            .unwrap();
+        let output = send_transfer(&ctx, &capability, msg).unwrap();
+        ctx.store_transfer_result(output.result).unwrap();
+        assert_eq!(ctx.balance(&account, &voucher_trace.ibc_denom()), 0);
+    }
+}
@@ -0,0 +1,243 @@
+use std::convert::TryFrom;
+
+use sha2::{Digest, Sha256};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+/// The prefix of the denomination of vouchers, followed by the hash of their trace.
+const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// The trace of a fungible token: the base denomination on the chain it originates from, and the
+/// path of `{port}/{channel}` pairs, most recent first, that the token went through to reach the
+/// local chain. Tokens native to the local chain have an empty path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DenomTrace {
+    pub path: String,
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    pub fn new(path: String, base_denom: String) -> Self {
+        Self { path, base_denom }
+    }
+
+    /// Parses a full denomination path, e.g., `transfer/channelid/atom`: the base denomination is
+    /// the last element, and the path is made of all the others.
+    pub fn parse(full_path: &str) -> Self {
+        match full_path.rfind('/') {
+            Some(idx) => Self::new(
+                full_path[..idx].to_string(),
+                full_path[idx + 1..].to_string(),
+            ),
+            None => Self::new(String::new(), full_path.to_string()),
+        }
+    }
+
+    /// The full denomination path, i.e., `{path}/{base_denom}`, which ICS20 packets carry.
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// The SHA-256 hash of the full denomination path.
+    pub fn hash(&self) -> Vec<u8> {
+        Sha256::digest(self.full_path().as_bytes()).to_vec()
+    }
+
+    /// The denomination of the token on the local chain: the base denomination for native tokens,
+    /// and `ibc/{hash}` (with the hash in upper case hex) for vouchers.
+    pub fn ibc_denom(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}{}", IBC_DENOM_PREFIX, encode_hex(&self.hash()))
+        }
+    }
+
+    /// Whether the token most recently went through the given channel end.
+    pub fn has_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let prefix = format!("{}/{}", port_id, channel_id);
+        self.path == prefix || self.path.starts_with(&format!("{}/", prefix))
+    }
+
+    /// The trace of the token once it goes through the given channel end, i.e., as seen by the
+    /// chain which receives it there.
+    pub fn add_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> Self {
+        let prefix = format!("{}/{}", port_id, channel_id);
+        let path = if self.path.is_empty() {
+            prefix
+        } else {
+            format!("{}/{}", prefix, self.path)
+        };
+        Self::new(path, self.base_denom.clone())
+    }
+
+    /// The trace of the token before it went through the given channel end, if it did.
+    pub fn remove_prefix(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Self> {
+        if !self.has_prefix(port_id, channel_id) {
+            return None;
+        }
+        let prefix_len = format!("{}/{}", port_id, channel_id).len();
+        let path = self.path[prefix_len..].trim_start_matches('/').to_string();
+        Some(Self::new(path, self.base_denom.clone()))
+    }
+}
+
+/// Parses the hash out of a voucher denomination `ibc/{hash}`. Returns `None` for any other
+/// denomination, i.e., for tokens native to the local chain.
+pub fn parse_ibc_denom(denom: &str) -> Result<Option<Vec<u8>>, Error> {
+    match denom.strip_prefix(IBC_DENOM_PREFIX) {
+        Some(hex_hash) => Ok(Some(decode_hex(hex_hash)?)),
+        None => Ok(None),
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// Decodes a hex encoded SHA-256 hash.
+fn decode_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    if hex.len() != 2 * Sha256::output_size() || !hex.is_ascii() {
+        return Err(Kind::InvalidDenomHash(hex.to_string()).into());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|e| Kind::InvalidDenomHash(hex.to_string()).context(e))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(Error::from)
+}
+
+impl Protobuf<RawDenomTrace> for DenomTrace {}
+
+impl TryFrom<RawDenomTrace> for DenomTrace {
+    type Error = Error;
+
+    fn try_from(raw: RawDenomTrace) -> Result<Self, Self::Error> {
+        if raw.base_denom.trim().is_empty() {
+            return Err(Kind::InvalidDenomTrace
+                .context("empty base denomination")
+                .into());
+        }
+
+        // The path is made of pairs of valid port and channel identifiers.
+        if !raw.path.is_empty() {
+            let identifiers: Vec<&str> = raw.path.split('/').collect();
+            if identifiers.len() % 2 != 0 {
+                return Err(Kind::InvalidDenomTrace
+                    .context(format!("odd number of identifiers in path {}", raw.path))
+                    .into());
+            }
+            for pair in identifiers.chunks(2) {
+                pair[0]
+                    .parse::<PortId>()
+                    .map_err(|e| Kind::InvalidDenomTrace.context(e))?;
+                pair[1]
+                    .parse::<ChannelId>()
+                    .map_err(|e| Kind::InvalidDenomTrace.context(e))?;
+            }
+        }
+
+        Ok(DenomTrace::new(raw.path, raw.base_denom))
+    }
+}
+
+impl From<DenomTrace> for RawDenomTrace {
+    fn from(trace: DenomTrace) -> Self {
+        RawDenomTrace {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use ibc_proto::ibc::applications::transfer::v1::DenomTrace as RawDenomTrace;
+
+    use crate::ics20_fungible_token_transfer::denom::{parse_ibc_denom, DenomTrace};
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+
+    #[test]
+    fn denom_trace_parse_and_hash() {
+        let native = DenomTrace::parse("uatom");
+        assert_eq!(native, DenomTrace::new("".to_string(), "uatom".to_string()));
+        assert_eq!(native.ibc_denom(), "uatom");
+
+        let voucher = DenomTrace::parse("transfer/channel-0/uatom");
+        assert_eq!(voucher.path, "transfer/channel-0");
+        assert_eq!(voucher.base_denom, "uatom");
+        assert_eq!(voucher.full_path(), "transfer/channel-0/uatom");
+
+        // The same denomination as for ATOM vouchers in the Cosmos SDK.
+        let ibc_denom = voucher.ibc_denom();
+        assert_eq!(
+            ibc_denom,
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert_eq!(parse_ibc_denom(&ibc_denom).unwrap(), Some(voucher.hash()));
+
+        assert_eq!(parse_ibc_denom("uatom").unwrap(), None);
+        assert!(parse_ibc_denom("ibc/27394F").is_err());
+        assert!(parse_ibc_denom(&format!("ibc/{}", "Z".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn denom_trace_prefixes() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_a = ChannelId::from_str("channelaaa").unwrap();
+        let channel_b = ChannelId::from_str("channelbbb").unwrap();
+
+        let native = DenomTrace::parse("uatom");
+        assert!(!native.has_prefix(&port_id, &channel_a));
+        assert_eq!(native.remove_prefix(&port_id, &channel_a), None);
+
+        let one_hop = native.add_prefix(&port_id, &channel_a);
+        assert_eq!(one_hop.full_path(), "transfer/channelaaa/uatom");
+        assert!(one_hop.has_prefix(&port_id, &channel_a));
+        assert!(!one_hop.has_prefix(&port_id, &channel_b));
+
+        let two_hops = one_hop.add_prefix(&port_id, &channel_b);
+        assert_eq!(
+            two_hops.full_path(),
+            "transfer/channelbbb/transfer/channelaaa/uatom"
+        );
+        assert!(!two_hops.has_prefix(&port_id, &channel_a));
+        assert_eq!(
+            two_hops.remove_prefix(&port_id, &channel_b),
+            Some(one_hop.clone())
+        );
+        assert_eq!(one_hop.remove_prefix(&port_id, &channel_a), Some(native));
+
+        // A channel identifier which merely starts like the prefix does not match it.
+        let similar = DenomTrace::parse("transfer/channelaaaa/uatom");
+        assert!(!similar.has_prefix(&port_id, &channel_a));
+    }
+
+    #[test]
+    fn denom_trace_try_from_raw() {
+        let raw = |path: &str, base_denom: &str| RawDenomTrace {
+            path: path.to_string(),
+            base_denom: base_denom.to_string(),
+        };
+
+        assert!(DenomTrace::try_from(raw("", "uatom")).is_ok());
+        assert!(DenomTrace::try_from(raw("transfer/channelaaa", "uatom")).is_ok());
+        assert!(DenomTrace::try_from(raw("transfer/channelaaa", "")).is_err());
+        assert!(DenomTrace::try_from(raw("transfer", "uatom")).is_err());
+        assert!(DenomTrace::try_from(raw("transfer/chan", "uatom")).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use anomaly::{BoxError, Context};
+use thiserror::Error;
+
+use tendermint::account::Id as AccountId;
+
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+pub type Error = anomaly::Error<Kind>;
+
+#[derive(Clone, Debug, Error)]
+pub enum Kind {
+    #[error("identifier error")]
+    IdentifierError,
+
+    #[error("invalid signer address")]
+    InvalidSigner,
+
+    #[error("invalid receiver address")]
+    InvalidReceiver,
+
+    #[error("missing token")]
+    MissingToken,
+
+    #[error("invalid token")]
+    InvalidToken,
+
+    #[error("invalid timeout height for the transfer")]
+    InvalidTimeoutHeight,
+
+    #[error("invalid packet data")]
+    InvalidPacketData,
+
+    #[error("invalid acknowledgement")]
+    InvalidAcknowledgement,
+
+    #[error("invalid denomination trace")]
+    InvalidDenomTrace,
+
+    #[error("invalid denomination hash {0}")]
+    InvalidDenomHash(String),
+
+    #[error("no denomination trace found for {0}")]
+    DenomTraceNotFound(String),
+
+    #[error("channel with port identifier {0} and channel identifier {1} not found")]
+    ChannelNotFound(PortId, ChannelId),
+
+    #[error("destination channel of port identifier {0} and channel identifier {1} not found")]
+    DestinationChannelNotFound(PortId, ChannelId),
+
+    #[error("missing sequence number for send packets on port {0} and channel {1}")]
+    SequenceSendNotFound(PortId, ChannelId),
+
+    #[error("invalid channel ordering {0}, transfer channels must be unordered")]
+    InvalidChannelOrder(String),
+
+    #[error("invalid version {0}, expected {1}")]
+    InvalidVersion(String, String),
+
+    #[error("transfer channels cannot be closed by the local chain")]
+    ChannelClosingForbidden,
+
+    #[error("the ICS 4 packet logic raised an error")]
+    PacketError,
+
+    #[error("the keeper failed to move tokens")]
+    TokenTransferFailure,
+
+    #[error("account {0} has insufficient funds to spend {1}")]
+    InsufficientFunds(AccountId, Coin),
+}
+
+impl Kind {
+    pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
+        Context::new(self, Some(source.into()))
+    }
+}
@@ -0,0 +1,145 @@
+//! This module implements the processing logic for ICS20 (fungible token transfer): sending tokens
+//! in response to a `MsgTransfer`, and handling the packets which carry them on either chain.
+
+use tendermint::account::Id as AccountId;
+
+use crate::address::string_to_account;
+use crate::handler::{Event, EventType};
+use crate::ics04_channel::handler::PacketResult;
+use crate::ics04_channel::packet::Packet;
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics20_fungible_token_transfer::packet::{Acknowledgement, FungibleTokenPacketData};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+
+pub mod on_ack_packet;
+pub mod on_recv_packet;
+pub mod on_timeout_packet;
+pub mod send_transfer;
+
+/// The name of the ICS20 module, as it appears in the events it emits.
+const MODULE_NAME: &str = "transfer";
+
+#[derive(Clone, Debug)]
+pub enum TransferEvent {
+    /// Tokens were sent to the chain at the other end of a channel.
+    Transfer(FungibleTokenPacketData),
+    /// Tokens were received, with the given outcome.
+    RecvPacket(FungibleTokenPacketData, Acknowledgement),
+    /// The receiving chain acknowledged tokens which were sent, with the given outcome.
+    AckPacket(FungibleTokenPacketData, Acknowledgement),
+    /// Tokens which were sent timed out, and are refunded.
+    Timeout(FungibleTokenPacketData),
+}
+
+/// The movement of tokens which results from processing ICS20 logic. Escrowed tokens are held in an
+/// escrow account specific to the given channel end.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenOperation {
+    Escrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sender: AccountId,
+        coin: Coin,
+    },
+    Unescrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+        receiver: AccountId,
+        coin: Coin,
+    },
+    Mint {
+        receiver: AccountId,
+        coin: Coin,
+    },
+    Burn {
+        sender: AccountId,
+        coin: Coin,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferResult {
+    /// The ICS4 packet result to apply to the host chain store, if any.
+    pub packet_result: Option<PacketResult>,
+    /// The tokens to move, if any.
+    pub token_op: Option<TokenOperation>,
+    /// The trace of newly minted vouchers, to record so that their `ibc/{hash}` denomination can be
+    /// resolved later on.
+    pub denom_trace: Option<DenomTrace>,
+}
+
+/// The attributes of any transfer event, with the same keys as in the Cosmos SDK.
+fn packet_data_attributes(data: &FungibleTokenPacketData) -> Vec<(String, String)> {
+    vec![
+        ("module".to_string(), MODULE_NAME.to_string()),
+        ("sender".to_string(), data.sender.clone()),
+        ("receiver".to_string(), data.receiver.clone()),
+        ("denom".to_string(), data.denom.clone()),
+        ("amount".to_string(), data.amount.to_string()),
+    ]
+}
+
+/// The attributes which describe the outcome of a transfer.
+fn acknowledgement_attributes(ack: &Acknowledgement) -> Vec<(String, String)> {
+    match ack {
+        Acknowledgement::Success => vec![("success".to_string(), "true".to_string())],
+        Acknowledgement::Error(e) => vec![
+            ("success".to_string(), "false".to_string()),
+            ("error".to_string(), e.clone()),
+        ],
+    }
+}
+
+impl From<TransferEvent> for Event {
+    fn from(ev: TransferEvent) -> Event {
+        match ev {
+            TransferEvent::Transfer(data) => Event::new(
+                EventType::Custom("ibc_transfer".to_string()),
+                packet_data_attributes(&data),
+            ),
+            TransferEvent::RecvPacket(data, ack) | TransferEvent::AckPacket(data, ack) => {
+                let mut attributes = packet_data_attributes(&data);
+                attributes.extend(acknowledgement_attributes(&ack));
+                Event::new(
+                    EventType::Custom("fungible_token_packet".to_string()),
+                    attributes,
+                )
+            }
+            TransferEvent::Timeout(data) => Event::new(
+                EventType::Custom("timeout".to_string()),
+                packet_data_attributes(&data),
+            ),
+        }
+    }
+}
+
+/// Whether the chain which sends tokens on the given channel end is their source, i.e., whether
+/// these tokens are not vouchers that this chain previously received on that same channel end.
+fn sender_chain_is_source(port_id: &PortId, channel_id: &ChannelId, trace: &DenomTrace) -> bool {
+    !trace.has_prefix(port_id, channel_id)
+}
+
+/// The movement of tokens which gives back to their sender the tokens of a packet that failed, or
+/// timed out. This undoes what the sending chain did when it sent the packet.
+fn refund_tokens(packet: &Packet, data: &FungibleTokenPacketData) -> Result<TokenOperation, Error> {
+    let sender =
+        string_to_account(data.sender.clone()).map_err(|e| Kind::InvalidSigner.context(e))?;
+    let trace = DenomTrace::parse(&data.denom);
+    let coin = Coin::new(trace.ibc_denom(), data.amount);
+
+    if sender_chain_is_source(&packet.source_port, &packet.source_channel, &trace) {
+        Ok(TokenOperation::Unescrow {
+            port_id: packet.source_port.clone(),
+            channel_id: packet.source_channel.clone(),
+            receiver: sender,
+            coin,
+        })
+    } else {
+        Ok(TokenOperation::Mint {
+            receiver: sender,
+            coin,
+        })
+    }
+}
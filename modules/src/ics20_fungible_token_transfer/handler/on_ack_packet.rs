@@ -0,0 +1,149 @@
+//! Protocol logic for processing, on the source chain, the acknowledgement of an ICS20 packet.
+//! The tokens are refunded to the sender if the destination chain acknowledged the packet with an
+//! error.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::packet::Packet;
+use crate::ics20_fungible_token_transfer::error::Error;
+use crate::ics20_fungible_token_transfer::handler::TransferEvent::AckPacket;
+use crate::ics20_fungible_token_transfer::handler::{refund_tokens, TransferResult};
+use crate::ics20_fungible_token_transfer::packet::{Acknowledgement, FungibleTokenPacketData};
+
+pub fn on_ack_packet(packet: &Packet, ack: &[u8]) -> HandlerResult<TransferResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+    let ack = Acknowledgement::from_bytes(ack)?;
+
+    let token_op = match ack {
+        Acknowledgement::Success => None,
+        Acknowledgement::Error(_) => Some(refund_tokens(packet, &data)?),
+    };
+
+    output.log(format!("success: transfer acknowledged: {:?}", ack));
+
+    let result = TransferResult {
+        packet_result: None,
+        token_op,
+        denom_trace: None,
+    };
+
+    output.emit(AckPacket(data, ack));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::address::string_to_account;
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::ics20_fungible_token_transfer::handler::on_ack_packet::on_ack_packet;
+    use crate::ics20_fungible_token_transfer::handler::TokenOperation;
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics20_fungible_token_transfer::packet::Acknowledgement;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::get_dummy_bech32_account;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn on_ack_packet_processing() {
+        struct Test {
+            name: String,
+            packet: Packet,
+            ack: Vec<u8>,
+            want_pass: bool,
+            want_op: Option<TokenOperation>,
+        }
+
+        let sender = string_to_account(get_dummy_bech32_account()).unwrap();
+        let port_id = PortId::from_str("transfer").unwrap();
+        let src_channel_id = ChannelId::from_str("srchannelid").unwrap();
+
+        let packet_with_denom = |denom: &str| Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: src_channel_id.clone(),
+            destination_port: port_id.clone(),
+            destination_channel: ChannelId::from_str("dstchannelid").unwrap(),
+            data: get_dummy_packet_data(denom, 100).to_bytes(),
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+
+        let error_ack = Acknowledgement::Error("failure".to_string()).to_bytes();
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the acknowledgement is invalid".to_string(),
+                packet: packet_with_denom("atom"),
+                ack: b"not json".to_vec(),
+                want_pass: false,
+                want_op: None,
+            },
+            Test {
+                name: "Nothing to do on success".to_string(),
+                packet: packet_with_denom("atom"),
+                ack: Acknowledgement::Success.to_bytes(),
+                want_pass: true,
+                want_op: None,
+            },
+            Test {
+                name: "Escrowed tokens are refunded on error".to_string(),
+                packet: packet_with_denom("atom"),
+                ack: error_ack.clone(),
+                want_pass: true,
+                want_op: Some(TokenOperation::Unescrow {
+                    port_id: port_id.clone(),
+                    channel_id: src_channel_id.clone(),
+                    receiver: sender,
+                    coin: Coin::new("atom".to_string(), 100),
+                }),
+            },
+            Test {
+                name: "Burned vouchers are minted back on error".to_string(),
+                packet: packet_with_denom("transfer/srchannelid/atom"),
+                ack: error_ack,
+                want_pass: true,
+                want_op: Some(TokenOperation::Mint {
+                    receiver: sender,
+                    coin: Coin::new(
+                        DenomTrace::parse("transfer/srchannelid/atom").ibc_denom(),
+                        100,
+                    ),
+                }),
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = on_ack_packet(&test.packet, &test.ack);
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "on_ack_packet: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.packet.clone(),
+                        test.ack.clone()
+                    );
+                    assert_eq!(proto_output.result.token_op, test.want_op);
+                    assert_eq!(proto_output.events.len(), 1);
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass, false,
+                        "on_ack_packet: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name, test.packet, test.ack, e,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Protocol logic for receiving an ICS20 packet on the destination chain, once the ICS4 handler
+//! accepted that packet. The tokens are either released from escrow, if they return to their
+//! source, or minted as vouchers. The resulting acknowledgement is for the ICS26 routing module to
+//! write.
+
+use crate::address::string_to_account;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::packet::Packet;
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics20_fungible_token_transfer::handler::TransferEvent::RecvPacket;
+use crate::ics20_fungible_token_transfer::handler::{TokenOperation, TransferResult};
+use crate::ics20_fungible_token_transfer::packet::{Acknowledgement, FungibleTokenPacketData};
+
+pub fn on_recv_packet(packet: &Packet) -> HandlerResult<(TransferResult, Acknowledgement), Error> {
+    // Processing errors do not fail the handler. Instead, the packet is acknowledged with an error,
+    // so that the sending chain refunds the tokens.
+    let (ack, token_op, denom_trace, event) =
+        match FungibleTokenPacketData::from_bytes(&packet.data) {
+            Ok(data) => {
+                let (ack, token_op, denom_trace) = match receive_tokens(packet, &data) {
+                    Ok((token_op, denom_trace)) => {
+                        (Acknowledgement::Success, Some(token_op), denom_trace)
+                    }
+                    Err(e) => (Acknowledgement::Error(e.to_string()), None, None),
+                };
+                let event = RecvPacket(data, ack.clone());
+                (ack, token_op, denom_trace, Some(event))
+            }
+            Err(e) => (Acknowledgement::Error(e.to_string()), None, None, None),
+        };
+
+    let mut output = HandlerOutput::builder();
+
+    output.log(format!(
+        "success: transfer received, acknowledgement: {:?}",
+        ack
+    ));
+
+    let result = TransferResult {
+        packet_result: None,
+        token_op,
+        denom_trace,
+    };
+
+    if let Some(event) = event {
+        output.emit(event);
+    }
+
+    Ok(output.with_result((result, ack)))
+}
+
+/// The movement of tokens which credits the receiver of a packet, along with the trace of the
+/// vouchers to mint, if any.
+fn receive_tokens(
+    packet: &Packet,
+    data: &FungibleTokenPacketData,
+) -> Result<(TokenOperation, Option<DenomTrace>), Error> {
+    let receiver =
+        string_to_account(data.receiver.clone()).map_err(|e| Kind::InvalidReceiver.context(e))?;
+
+    let trace = DenomTrace::parse(&data.denom);
+    match trace.remove_prefix(&packet.source_port, &packet.source_channel) {
+        // The tokens return to this chain, which escrowed them when it sent them out.
+        Some(local_trace) => Ok((
+            TokenOperation::Unescrow {
+                port_id: packet.destination_port.clone(),
+                channel_id: packet.destination_channel.clone(),
+                receiver,
+                coin: Coin::new(local_trace.ibc_denom(), data.amount),
+            },
+            None,
+        )),
+        // The tokens come from elsewhere: the receiver gets vouchers, which trace the channel they
+        // arrived on.
+        None => {
+            let voucher_trace =
+                trace.add_prefix(&packet.destination_port, &packet.destination_channel);
+            Ok((
+                TokenOperation::Mint {
+                    receiver,
+                    coin: Coin::new(voucher_trace.ibc_denom(), data.amount),
+                },
+                Some(voucher_trace),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::address::string_to_account;
+    use crate::handler::EventType;
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::ics20_fungible_token_transfer::handler::on_recv_packet::on_recv_packet;
+    use crate::ics20_fungible_token_transfer::handler::TokenOperation;
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::get_dummy_bech32_account;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn on_recv_packet_processing() {
+        struct Test {
+            name: String,
+            packet: Packet,
+            want_success: bool,
+            want_op: Option<TokenOperation>,
+            want_trace: Option<DenomTrace>,
+        }
+
+        let receiver = string_to_account(get_dummy_bech32_account()).unwrap();
+        let port_id = PortId::from_str("transfer").unwrap();
+        let src_channel_id = ChannelId::from_str("srchannelid").unwrap();
+        let dst_channel_id = ChannelId::from_str("dstchannelid").unwrap();
+
+        let packet_with_data = |data: Vec<u8>| Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: src_channel_id.clone(),
+            destination_port: port_id.clone(),
+            destination_channel: dst_channel_id.clone(),
+            data,
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+
+        let native_packet = packet_with_data(get_dummy_packet_data("atom", 100).to_bytes());
+        let returning_packet =
+            packet_with_data(get_dummy_packet_data("transfer/srchannelid/atom", 100).to_bytes());
+        let mut bad_receiver_data = get_dummy_packet_data("atom", 100);
+        bad_receiver_data.receiver = "not_an_address".to_string();
+        let bad_receiver_packet = packet_with_data(bad_receiver_data.to_bytes());
+        let bad_data_packet = packet_with_data(b"not json".to_vec());
+
+        let voucher_trace = DenomTrace::parse("transfer/dstchannelid/atom");
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Foreign tokens are minted as vouchers".to_string(),
+                packet: native_packet,
+                want_success: true,
+                want_op: Some(TokenOperation::Mint {
+                    receiver,
+                    coin: Coin::new(voucher_trace.ibc_denom(), 100),
+                }),
+                want_trace: Some(voucher_trace),
+            },
+            Test {
+                name: "Tokens returning to their source are released from escrow".to_string(),
+                packet: returning_packet,
+                want_success: true,
+                want_op: Some(TokenOperation::Unescrow {
+                    port_id: port_id.clone(),
+                    channel_id: dst_channel_id.clone(),
+                    receiver,
+                    coin: Coin::new("atom".to_string(), 100),
+                }),
+                want_trace: None,
+            },
+            Test {
+                name: "An invalid receiver is acknowledged with an error".to_string(),
+                packet: bad_receiver_packet,
+                want_success: false,
+                want_op: None,
+                want_trace: None,
+            },
+            Test {
+                name: "Invalid packet data is acknowledged with an error".to_string(),
+                packet: bad_data_packet,
+                want_success: false,
+                want_op: None,
+                want_trace: None,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            // Processing errors never fail the handler: they result in an error acknowledgement.
+            let proto_output = on_recv_packet(&test.packet).unwrap();
+            let (result, ack) = proto_output.result;
+
+            assert_eq!(
+                ack.is_success(),
+                test.want_success,
+                "on_recv_packet: unexpected acknowledgement for test: {}, \nparams {:?}, ack {:?}",
+                test.name,
+                test.packet,
+                ack
+            );
+            assert_eq!(result.token_op, test.want_op);
+            assert_eq!(result.denom_trace, test.want_trace);
+            assert_eq!(result.packet_result, None);
+
+            // Only packets with valid data result in an event.
+            if test.want_op.is_some() {
+                assert_eq!(
+                    proto_output.events[0].tpe,
+                    EventType::Custom("fungible_token_packet".to_string())
+                );
+            }
+        }
+    }
+}
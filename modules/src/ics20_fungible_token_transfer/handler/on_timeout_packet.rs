@@ -0,0 +1,81 @@
+//! Protocol logic for processing, on the source chain, an ICS20 packet which timed out: the tokens
+//! are refunded to the sender.
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::packet::Packet;
+use crate::ics20_fungible_token_transfer::error::Error;
+use crate::ics20_fungible_token_transfer::handler::TransferEvent::Timeout;
+use crate::ics20_fungible_token_transfer::handler::{refund_tokens, TransferResult};
+use crate::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+
+pub fn on_timeout_packet(packet: &Packet) -> HandlerResult<TransferResult, Error> {
+    let mut output = HandlerOutput::builder();
+
+    let data = FungibleTokenPacketData::from_bytes(&packet.data)?;
+    let token_op = refund_tokens(packet, &data)?;
+
+    output.log("success: transfer timed out, tokens refunded");
+
+    let result = TransferResult {
+        packet_result: None,
+        token_op: Some(token_op),
+        denom_trace: None,
+    };
+
+    output.emit(Timeout(data));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::address::string_to_account;
+    use crate::handler::EventType;
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::handler::on_timeout_packet::on_timeout_packet;
+    use crate::ics20_fungible_token_transfer::handler::TokenOperation;
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics24_host::identifier::{ChannelId, PortId};
+    use crate::test_utils::get_dummy_bech32_account;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn on_timeout_packet_processing() {
+        let sender = string_to_account(get_dummy_bech32_account()).unwrap();
+        let port_id = PortId::from_str("transfer").unwrap();
+        let src_channel_id = ChannelId::from_str("srchannelid").unwrap();
+
+        let mut packet = Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: src_channel_id.clone(),
+            destination_port: port_id.clone(),
+            destination_channel: ChannelId::from_str("dstchannelid").unwrap(),
+            data: get_dummy_packet_data("atom", 100).to_bytes(),
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+
+        let output = on_timeout_packet(&packet).unwrap();
+        assert_eq!(
+            output.result.token_op,
+            Some(TokenOperation::Unescrow {
+                port_id,
+                channel_id: src_channel_id,
+                receiver: sender,
+                coin: Coin::new("atom".to_string(), 100),
+            })
+        );
+        assert_eq!(
+            output.events[0].tpe,
+            EventType::Custom("timeout".to_string())
+        );
+
+        packet.data = b"not json".to_vec();
+        assert!(on_timeout_packet(&packet).is_err());
+    }
+}
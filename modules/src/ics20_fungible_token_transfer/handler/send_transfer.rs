@@ -0,0 +1,292 @@
+//! Protocol logic specific to ICS20 messages of type `MsgTransfer`.
+
+use crate::address::account_to_string;
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::handler::send_packet::send_packet;
+use crate::ics04_channel::packet::Packet;
+use crate::ics05_port::capabilities::Capability;
+use crate::ics20_fungible_token_transfer::context::ICS20Reader;
+use crate::ics20_fungible_token_transfer::denom::{parse_ibc_denom, DenomTrace};
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics20_fungible_token_transfer::handler::TransferEvent::Transfer;
+use crate::ics20_fungible_token_transfer::handler::{
+    sender_chain_is_source, TokenOperation, TransferResult,
+};
+use crate::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+use crate::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+
+/// Sends the tokens of `msg` over its source channel. The `capability` is the one which the transfer
+/// application holds for the source port.
+pub fn send_transfer<Ctx: ICS20Reader>(
+    ctx: &Ctx,
+    capability: &Capability,
+    msg: MsgTransfer,
+) -> HandlerResult<TransferResult, Error> {
+    let source_channel_end = ctx
+        .channel_end(msg.source_port(), msg.source_channel())
+        .ok_or_else(|| {
+            Kind::ChannelNotFound(msg.source_port().clone(), msg.source_channel().clone())
+        })?;
+
+    let destination_port = source_channel_end.counterparty().port_id().clone();
+    let destination_channel = source_channel_end
+        .counterparty()
+        .channel_id()
+        .cloned()
+        .ok_or_else(|| {
+            Kind::DestinationChannelNotFound(
+                msg.source_port().clone(),
+                msg.source_channel().clone(),
+            )
+        })?;
+
+    let sequence = ctx
+        .next_sequence_send(msg.source_port(), msg.source_channel())
+        .ok_or_else(|| {
+            Kind::SequenceSendNotFound(msg.source_port().clone(), msg.source_channel().clone())
+        })?;
+
+    // Vouchers are held under their `ibc/{hash}` denomination, while packets carry their full trace.
+    let token = msg.token().clone();
+    let trace = match parse_ibc_denom(&token.denom)? {
+        Some(hash) => ctx
+            .denom_trace(&hash)
+            .ok_or_else(|| Kind::DenomTraceNotFound(token.denom.clone()))?,
+        None => DenomTrace::parse(&token.denom),
+    };
+
+    let data = FungibleTokenPacketData {
+        denom: trace.full_path(),
+        amount: token.amount,
+        sender: account_to_string(*msg.sender()).map_err(|e| Kind::InvalidSigner.context(e))?,
+        receiver: msg.receiver().to_string(),
+    };
+
+    // Tokens which originate from this chain are escrowed, while vouchers which return to the chain
+    // they came from are burned.
+    let token_op = if sender_chain_is_source(msg.source_port(), msg.source_channel(), &trace) {
+        TokenOperation::Escrow {
+            port_id: msg.source_port().clone(),
+            channel_id: msg.source_channel().clone(),
+            sender: *msg.sender(),
+            coin: token,
+        }
+    } else {
+        TokenOperation::Burn {
+            sender: *msg.sender(),
+            coin: token,
+        }
+    };
+
+    let packet = Packet {
+        sequence,
+        source_port: msg.source_port().clone(),
+        source_channel: msg.source_channel().clone(),
+        destination_port,
+        destination_channel,
+        data: data.to_bytes(),
+        timeout_height: msg.timeout_height(),
+        timeout_timestamp: msg.timeout_timestamp(),
+    };
+
+    let packet_output =
+        send_packet(ctx, capability, packet).map_err(|e| Kind::PacketError.context(e))?;
+
+    let mut output = HandlerOutput::builder()
+        .with_log(packet_output.log)
+        .with_events(packet_output.events);
+
+    output.log("success: transfer sent");
+
+    let result = TransferResult {
+        packet_result: Some(packet_output.result),
+        token_op: Some(token_op),
+        denom_trace: None,
+    };
+
+    output.emit(Transfer(data));
+
+    Ok(output.with_result(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use crate::handler::EventType;
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics04_channel::channel::{Counterparty, Order, State};
+    use crate::ics04_channel::handler::PacketResult;
+    use crate::ics04_channel::packet::test_utils::{
+        get_context_with_other_capability, get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics04_channel::packet::Sequence;
+    use crate::ics05_port::context::PortReader;
+    use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::ics20_fungible_token_transfer::handler::send_transfer::send_transfer;
+    use crate::ics20_fungible_token_transfer::handler::TokenOperation;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_raw_msg_transfer;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::mock::context::MockContext;
+    use crate::Height;
+
+    #[test]
+    fn send_transfer_processing() {
+        struct Test {
+            name: String,
+            ctx: MockContext,
+            msg: MsgTransfer,
+            want_pass: bool,
+        }
+
+        let client_height = 10;
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        let msg_native =
+            MsgTransfer::try_from(get_dummy_raw_msg_transfer("atom", client_height + 10)).unwrap();
+        let voucher_trace = DenomTrace::parse("transfer/srchannelid/atom");
+        let msg_voucher = MsgTransfer::try_from(get_dummy_raw_msg_transfer(
+            &voucher_trace.ibc_denom(),
+            client_height + 10,
+        ))
+        .unwrap();
+        let msg_timed_out =
+            MsgTransfer::try_from(get_dummy_raw_msg_transfer("atom", client_height)).unwrap();
+
+        let context = MockContext::default().with_port_capability(msg_native.source_port().clone());
+        let capability = context
+            .lookup_module_by_port(msg_native.source_port())
+            .unwrap();
+
+        let open_conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+
+        let mut open_chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(
+                PortId::from_str("transfer").unwrap(),
+                Some(ChannelId::from_str("dstchannelid").unwrap()),
+            ),
+        );
+        open_chan_end.set_version("ics20-1".to_string());
+
+        let ready_ctx = context
+            .clone()
+            .with_client(&client_id, Height::new(1, client_height))
+            .with_connection(ConnectionId::default(), open_conn_end)
+            .with_channel(
+                msg_native.source_port().clone(),
+                msg_native.source_channel().clone(),
+                open_chan_end,
+            )
+            .with_sequences(
+                msg_native.source_port().clone(),
+                msg_native.source_channel().clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            );
+
+        let traced_ctx = ready_ctx.clone().with_denom_trace(voucher_trace.clone());
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Processing fails because the capability is not the one issued for the port"
+                    .to_string(),
+                ctx: get_context_with_other_capability(msg_native.source_port()),
+                msg: msg_native.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because no channel exists in the context".to_string(),
+                ctx: context,
+                msg: msg_native.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Processing fails because the counterparty already passed the timeout height"
+                    .to_string(),
+                ctx: ready_ctx.clone(),
+                msg: msg_timed_out,
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, native tokens".to_string(),
+                ctx: ready_ctx.clone(),
+                msg: msg_native,
+                want_pass: true,
+            },
+            Test {
+                name: "Processing fails because the trace of the vouchers is unknown".to_string(),
+                ctx: ready_ctx,
+                msg: msg_voucher.clone(),
+                want_pass: false,
+            },
+            Test {
+                name: "Good parameters, vouchers return to their source".to_string(),
+                ctx: traced_ctx,
+                msg: msg_voucher,
+                want_pass: true,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        for test in tests {
+            let res = send_transfer(&test.ctx, &capability, test.msg.clone());
+            // Additionally check the events and the output objects in the result.
+            match res {
+                Ok(proto_output) => {
+                    assert_eq!(
+                        test.want_pass,
+                        true,
+                        "send_transfer: test passed but was supposed to fail for test: {}, \nparams {:?} {:?}",
+                        test.name,
+                        test.msg.clone(),
+                        test.ctx.clone()
+                    );
+
+                    // The packet is sent, and the tokens are escrowed unless they are vouchers.
+                    assert!(matches!(
+                        proto_output.result.packet_result,
+                        Some(PacketResult::Send(_))
+                    ));
+                    match proto_output.result.token_op {
+                        Some(TokenOperation::Escrow { coin, .. }) => {
+                            assert_eq!(&coin, test.msg.token());
+                            assert_eq!(coin.denom, "atom");
+                        }
+                        Some(TokenOperation::Burn { coin, .. }) => {
+                            assert_eq!(&coin, test.msg.token());
+                            assert_eq!(coin.denom, voucher_trace.ibc_denom());
+                        }
+                        _ => panic!("unexpected token operation: expected an escrow or a burn!"),
+                    }
+
+                    let event_types: Vec<EventType> =
+                        proto_output.events.into_iter().map(|e| e.tpe).collect();
+                    assert_eq!(
+                        event_types,
+                        vec![
+                            EventType::Custom("send_packet".to_string()),
+                            EventType::Custom("ibc_transfer".to_string())
+                        ]
+                    );
+                }
+                Err(e) => {
+                    assert_eq!(
+                        test.want_pass,
+                        false,
+                        "send_transfer: did not pass test: {}, \nparams {:?} {:?} error: {:?}",
+                        test.name,
+                        test.msg,
+                        test.ctx.clone(),
+                        e,
+                    );
+                }
+            }
+        }
+    }
+}
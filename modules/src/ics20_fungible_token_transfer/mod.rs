@@ -1,2 +1,17 @@
 //! ICS 20: IBC Transfer implementation
+
+pub mod coin;
+/// Context definitions (dependencies for the protocol).
+pub mod context;
+pub mod denom;
+pub mod error;
 pub mod events;
+/// Message processing logic (protocol) for ICS 20.
+pub mod handler;
+/// The ICS 20 application, to bind to ports of the ICS 26 router.
+pub mod module;
+pub mod msgs;
+pub mod packet;
+
+/// The version of ICS 20, which channels between fungible token transfer applications must use.
+pub const VERSION: &str = "ics20-1";
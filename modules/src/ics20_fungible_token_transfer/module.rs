@@ -0,0 +1,261 @@
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::channel::{ChannelEnd, Order};
+use crate::ics04_channel::packet::Packet;
+use crate::ics20_fungible_token_transfer::context::{ICS20Keeper, ICS20Reader};
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics20_fungible_token_transfer::handler::on_ack_packet::on_ack_packet;
+use crate::ics20_fungible_token_transfer::handler::on_recv_packet::on_recv_packet;
+use crate::ics20_fungible_token_transfer::handler::on_timeout_packet::on_timeout_packet;
+use crate::ics20_fungible_token_transfer::packet::Acknowledgement;
+use crate::ics20_fungible_token_transfer::VERSION;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::error::{Error as ICS26Error, Kind as ICS26Kind};
+use crate::ics26_routing::module::Module;
+
+/// The fungible token transfer application. It holds no state of its own: the denomination traces
+/// and the balances live in the context.
+#[derive(Clone, Debug, Default)]
+pub struct ICS20Module;
+
+/// Transfer channels are unordered, and both ends use the ICS20 version.
+fn validate_channel(channel_end: &ChannelEnd) -> Result<(), Error> {
+    if channel_end.ordering() != &Order::Unordered {
+        return Err(
+            Kind::InvalidChannelOrder(channel_end.ordering().as_string().to_string()).into(),
+        );
+    }
+    validate_version(&channel_end.version())
+}
+
+fn validate_version(version: &str) -> Result<(), Error> {
+    if version != VERSION {
+        return Err(Kind::InvalidVersion(version.to_string(), VERSION.to_string()).into());
+    }
+    Ok(())
+}
+
+fn callback_error(e: Error) -> ICS26Error {
+    ICS26Kind::ModuleCallbackFailed.context(e).into()
+}
+
+impl<Ctx> Module<Ctx> for ICS20Module
+where
+    Ctx: ICS20Reader + ICS20Keeper + Clone,
+{
+    fn on_chan_open_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), ICS26Error> {
+        validate_channel(channel_end).map_err(callback_error)
+    }
+
+    fn on_chan_open_try(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+        counterparty_version: &str,
+    ) -> Result<(), ICS26Error> {
+        validate_channel(channel_end).map_err(callback_error)?;
+        validate_version(counterparty_version).map_err(callback_error)
+    }
+
+    fn on_chan_open_ack(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        counterparty_version: &str,
+    ) -> Result<(), ICS26Error> {
+        validate_version(counterparty_version).map_err(callback_error)
+    }
+
+    fn on_chan_close_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), ICS26Error> {
+        // Closing a channel would strand the tokens escrowed on it.
+        Err(callback_error(Kind::ChannelClosingForbidden.into()))
+    }
+
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet) -> HandlerResult<Vec<u8>, ICS26Error> {
+        let recv_output = on_recv_packet(packet).map_err(callback_error)?;
+        let (result, ack) = recv_output.result;
+
+        // Tokens which cannot be credited (e.g., for lack of escrowed funds) are acknowledged with
+        // an error, so that the sending chain refunds them. They are credited on a sandbox, so that
+        // a failure halfway through leaves the context untouched.
+        let mut sandbox = ctx.clone();
+        match sandbox.store_transfer_result(result) {
+            Ok(()) => {
+                *ctx = sandbox;
+                Ok(HandlerOutput::builder()
+                    .with_log(recv_output.log)
+                    .with_events(recv_output.events)
+                    .with_result(ack.to_bytes()))
+            }
+            Err(e) => {
+                let mut output = HandlerOutput::builder().with_log(recv_output.log);
+                output.log(format!("failure: tokens not credited: {}", e));
+                Ok(output.with_result(Acknowledgement::Error(e.to_string()).to_bytes()))
+            }
+        }
+    }
+
+    fn on_acknowledgement_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        acknowledgement: &[u8],
+    ) -> HandlerResult<(), ICS26Error> {
+        let ack_output = on_ack_packet(packet, acknowledgement).map_err(callback_error)?;
+        ctx.store_transfer_result(ack_output.result)
+            .map_err(callback_error)?;
+
+        Ok(HandlerOutput::builder()
+            .with_log(ack_output.log)
+            .with_events(ack_output.events)
+            .with_result(()))
+    }
+
+    fn on_timeout_packet(&self, ctx: &mut Ctx, packet: &Packet) -> HandlerResult<(), ICS26Error> {
+        let timeout_output = on_timeout_packet(packet).map_err(callback_error)?;
+        ctx.store_transfer_result(timeout_output.result)
+            .map_err(callback_error)?;
+
+        Ok(HandlerOutput::builder()
+            .with_log(timeout_output.log)
+            .with_events(timeout_output.events)
+            .with_result(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::address::string_to_account;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::context::BankReader;
+    use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+    use crate::ics20_fungible_token_transfer::module::ICS20Module;
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics20_fungible_token_transfer::packet::Acknowledgement;
+    use crate::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+    use crate::ics26_routing::module::Module;
+    use crate::mock::context::MockContext;
+    use crate::test_utils::get_dummy_bech32_account;
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn ics20_module_channel_callbacks() {
+        let mut ctx = MockContext::default();
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::from_str("channelaaa").unwrap();
+
+        let channel_end = |order: Order, version: &str| {
+            ChannelEnd::new(
+                State::Init,
+                order,
+                Counterparty::new(port_id.clone(), None),
+                vec![ConnectionId::default()],
+                version.to_string(),
+            )
+        };
+
+        let good_channel = channel_end(Order::Unordered, "ics20-1");
+        assert!(ICS20Module
+            .on_chan_open_init(&mut ctx, &port_id, &channel_id, &good_channel)
+            .is_ok());
+        assert!(ICS20Module
+            .on_chan_open_init(
+                &mut ctx,
+                &port_id,
+                &channel_id,
+                &channel_end(Order::Ordered, "ics20-1")
+            )
+            .is_err());
+        assert!(ICS20Module
+            .on_chan_open_init(
+                &mut ctx,
+                &port_id,
+                &channel_id,
+                &channel_end(Order::Unordered, "ics20-2")
+            )
+            .is_err());
+
+        assert!(ICS20Module
+            .on_chan_open_try(&mut ctx, &port_id, &channel_id, &good_channel, "ics20-1")
+            .is_ok());
+        assert!(ICS20Module
+            .on_chan_open_try(&mut ctx, &port_id, &channel_id, &good_channel, "ics20-2")
+            .is_err());
+        assert!(ICS20Module
+            .on_chan_open_ack(&mut ctx, &port_id, &channel_id, "ics20-2")
+            .is_err());
+
+        // The local chain never closes transfer channels, but the counterparty may do so.
+        assert!(ICS20Module
+            .on_chan_close_init(&mut ctx, &port_id, &channel_id)
+            .is_err());
+        assert!(ICS20Module
+            .on_chan_close_confirm(&mut ctx, &port_id, &channel_id)
+            .is_ok());
+    }
+
+    #[test]
+    fn ics20_module_recv_packet() {
+        let mut ctx = MockContext::default();
+        let receiver = string_to_account(get_dummy_bech32_account()).unwrap();
+        let port_id = PortId::from_str("transfer").unwrap();
+
+        let packet_with_denom = |denom: &str| Packet {
+            sequence: Sequence::from(1),
+            source_port: port_id.clone(),
+            source_channel: ChannelId::from_str("srchannelid").unwrap(),
+            destination_port: port_id.clone(),
+            destination_channel: ChannelId::from_str("dstchannelid").unwrap(),
+            data: get_dummy_packet_data(denom, 100).to_bytes(),
+            timeout_height: Height::new(1, 20),
+            timeout_timestamp: Timestamp::none(),
+        };
+
+        // Foreign tokens are minted as vouchers.
+        let output = ICS20Module
+            .on_recv_packet(&mut ctx, &packet_with_denom("atom"))
+            .unwrap();
+        assert_eq!(output.result, Acknowledgement::Success.to_bytes());
+        let voucher_denom = DenomTrace::parse("transfer/dstchannelid/atom").ibc_denom();
+        assert_eq!(ctx.balance(&receiver, &voucher_denom), 100);
+
+        // Tokens returning to this chain, but which it never escrowed, are acknowledged with an
+        // error.
+        let output = ICS20Module
+            .on_recv_packet(&mut ctx, &packet_with_denom("transfer/srchannelid/atom"))
+            .unwrap();
+        assert!(!Acknowledgement::from_bytes(&output.result)
+            .unwrap()
+            .is_success());
+        assert_eq!(ctx.balance(&receiver, "atom"), 0);
+
+        // Vouchers which would overflow the balance of the receiver are not credited at all.
+        let mut ctx = MockContext::default()
+            .with_balance(receiver, Coin::new(voucher_denom.clone(), u64::MAX - 50));
+        let output = ICS20Module
+            .on_recv_packet(&mut ctx, &packet_with_denom("atom"))
+            .unwrap();
+        assert!(!Acknowledgement::from_bytes(&output.result)
+            .unwrap()
+            .is_success());
+        assert_eq!(ctx.balance(&receiver, &voucher_denom), u64::MAX - 50);
+    }
+}
@@ -0,0 +1,3 @@
+//! Message definitions for the ICS20 fungible token transfer application.
+
+pub mod transfer;
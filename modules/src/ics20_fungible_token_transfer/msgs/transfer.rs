@@ -0,0 +1,306 @@
+use std::convert::{TryFrom, TryInto};
+
+use tendermint::account::Id as AccountId;
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+
+use crate::address::{account_to_string, string_to_account};
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::timestamp::Timestamp;
+use crate::tx_msg::Msg;
+use crate::Height;
+
+/// Message type for the `MsgTransfer` message.
+const TYPE_MSG_TRANSFER: &str = "transfer";
+
+///
+/// Message definition for the transfer of fungible tokens to the chain at the other end of a
+/// channel (`MsgTransfer`).
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MsgTransfer {
+    source_port: PortId,
+    source_channel: ChannelId,
+    token: Coin,
+    sender: AccountId,
+    receiver: String,
+    /// The height on the destination chain after which the transfer times out. The timeout is
+    /// disabled when set to zero.
+    timeout_height: Height,
+    /// The timestamp on the destination chain after which the transfer times out. The timeout is
+    /// disabled when set to `Timestamp::none()`.
+    timeout_timestamp: Timestamp,
+}
+
+impl MsgTransfer {
+    /// Getter for accessing the source port identifier of this message.
+    pub fn source_port(&self) -> &PortId {
+        &self.source_port
+    }
+
+    /// Getter for accessing the source channel identifier of this message.
+    pub fn source_channel(&self) -> &ChannelId {
+        &self.source_channel
+    }
+
+    /// Getter for accessing the tokens transferred by this message.
+    pub fn token(&self) -> &Coin {
+        &self.token
+    }
+
+    /// Getter for accessing the sender of this message.
+    pub fn sender(&self) -> &AccountId {
+        &self.sender
+    }
+
+    /// Getter for accessing the receiver address (on the destination chain) of this message.
+    pub fn receiver(&self) -> &str {
+        &self.receiver
+    }
+
+    /// Getter for accessing the timeout height of this message.
+    pub fn timeout_height(&self) -> Height {
+        self.timeout_height
+    }
+
+    /// Getter for accessing the timeout timestamp of this message.
+    pub fn timeout_timestamp(&self) -> Timestamp {
+        self.timeout_timestamp
+    }
+}
+
+impl Msg for MsgTransfer {
+    type ValidationError = Error;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn get_type(&self) -> String {
+        TYPE_MSG_TRANSFER.to_string()
+    }
+
+    fn validate_basic(&self) -> Result<(), Self::ValidationError> {
+        // Nothing to validate
+        // All the validation is performed on creation
+        Ok(())
+    }
+
+    fn get_signers(&self) -> Vec<AccountId> {
+        vec![self.sender]
+    }
+}
+
+impl Protobuf<RawMsgTransfer> for MsgTransfer {}
+
+impl TryFrom<RawMsgTransfer> for MsgTransfer {
+    type Error = anomaly::Error<Kind>;
+
+    fn try_from(raw_msg: RawMsgTransfer) -> Result<Self, Self::Error> {
+        let token: Coin = raw_msg.token.ok_or(Kind::MissingToken)?.try_into()?;
+        if token.amount == 0 {
+            return Err(Kind::InvalidToken
+                .context("the amount must be positive")
+                .into());
+        }
+
+        if raw_msg.receiver.is_empty() {
+            return Err(Kind::InvalidReceiver.into());
+        }
+
+        let timeout_height = match raw_msg.timeout_height {
+            Some(height) => height
+                .try_into()
+                .map_err(|e| Kind::InvalidTimeoutHeight.context(e))?,
+            None => Height::zero(),
+        };
+
+        Ok(MsgTransfer {
+            source_port: raw_msg
+                .source_port
+                .parse()
+                .map_err(|e| Kind::IdentifierError.context(e))?,
+            source_channel: raw_msg
+                .source_channel
+                .parse()
+                .map_err(|e| Kind::IdentifierError.context(e))?,
+            token,
+            sender: string_to_account(raw_msg.sender)
+                .map_err(|e| Kind::InvalidSigner.context(e))?,
+            receiver: raw_msg.receiver,
+            timeout_height,
+            timeout_timestamp: Timestamp::from_nanoseconds(raw_msg.timeout_timestamp),
+        })
+    }
+}
+
+impl From<MsgTransfer> for RawMsgTransfer {
+    fn from(domain_msg: MsgTransfer) -> Self {
+        RawMsgTransfer {
+            source_port: domain_msg.source_port.to_string(),
+            source_channel: domain_msg.source_channel.to_string(),
+            token: Some(domain_msg.token.into()),
+            sender: account_to_string(domain_msg.sender).unwrap(),
+            receiver: domain_msg.receiver,
+            timeout_height: Some(domain_msg.timeout_height.into()),
+            timeout_timestamp: domain_msg.timeout_timestamp.nanoseconds(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::test_utils::get_dummy_bech32_account;
+
+    /// Returns a dummy `RawMsgTransfer`, for testing only! The message transfers 100 tokens of the
+    /// given `denom` over the channel `srchannelid` of the `transfer` port, and times out at the
+    /// given `timeout_height`.
+    pub fn get_dummy_raw_msg_transfer(denom: &str, timeout_height: u64) -> RawMsgTransfer {
+        RawMsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "srchannelid".to_string(),
+            token: Some(RawCoin {
+                denom: denom.to_string(),
+                amount: "100".to_string(),
+            }),
+            sender: get_dummy_bech32_account(),
+            receiver: get_dummy_bech32_account(),
+            timeout_height: Some(RawHeight {
+                version_number: 1,
+                version_height: timeout_height,
+            }),
+            timeout_timestamp: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::{TryFrom, TryInto};
+
+    use ibc_proto::cosmos::base::v1beta1::Coin as RawCoin;
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
+
+    use crate::ics20_fungible_token_transfer::error::Error;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::test_util::get_dummy_raw_msg_transfer;
+    use crate::ics20_fungible_token_transfer::msgs::transfer::MsgTransfer;
+
+    #[test]
+    fn msg_transfer_try_from_raw() {
+        struct Test {
+            name: String,
+            raw: RawMsgTransfer,
+            want_pass: bool,
+        }
+
+        let default_raw_msg = get_dummy_raw_msg_transfer("atom", 10);
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "Good parameters".to_string(),
+                raw: default_raw_msg.clone(),
+                want_pass: true,
+            },
+            Test {
+                name: "Good parameters, no timeout height".to_string(),
+                raw: RawMsgTransfer {
+                    timeout_height: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Bad source port, name too short".to_string(),
+                raw: RawMsgTransfer {
+                    source_port: "p".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad source channel, name too short".to_string(),
+                raw: RawMsgTransfer {
+                    source_channel: "chan".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing token".to_string(),
+                raw: RawMsgTransfer {
+                    token: None,
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad token, zero amount".to_string(),
+                raw: RawMsgTransfer {
+                    token: Some(RawCoin {
+                        denom: "atom".to_string(),
+                        amount: "0".to_string(),
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad token, amount is not a number".to_string(),
+                raw: RawMsgTransfer {
+                    token: Some(RawCoin {
+                        denom: "atom".to_string(),
+                        amount: "ten".to_string(),
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing sender".to_string(),
+                raw: RawMsgTransfer {
+                    sender: "".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Missing receiver".to_string(),
+                raw: RawMsgTransfer {
+                    receiver: "".to_string(),
+                    ..default_raw_msg
+                },
+                want_pass: false,
+            },
+        ];
+
+        for test in tests {
+            let res_msg: Result<MsgTransfer, Error> = test.raw.clone().try_into();
+
+            assert_eq!(
+                res_msg.is_ok(),
+                test.want_pass,
+                "MsgTransfer::try_from failed for test {} \nraw message: {:?} with error: {:?}",
+                test.name,
+                test.raw,
+                res_msg.err()
+            );
+        }
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_raw_msg_transfer("transfer/dstchannelid/atom", 15);
+        let msg = MsgTransfer::try_from(raw.clone()).unwrap();
+        let raw_back = RawMsgTransfer::from(msg.clone());
+        let msg_back = MsgTransfer::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(msg, msg_back);
+    }
+}
@@ -0,0 +1,218 @@
+use std::convert::TryFrom;
+
+use serde_derive::{Deserialize, Serialize};
+use tendermint_proto::Protobuf;
+
+use ibc_proto::ibc::applications::transfer::v1::FungibleTokenPacketData as RawFungibleTokenPacketData;
+
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+
+/// The base64 encoding of the result of every successful ICS20 packet, i.e., `[1]`.
+const SUCCESS_ACK_RESULT: &str = "AQ==";
+
+/// The data of the ICS4 packets which carry fungible token transfers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FungibleTokenPacketData {
+    /// The full denomination of the transferred tokens, including the trace of the channels they
+    /// went through so far.
+    pub denom: String,
+    pub amount: u64,
+    /// The sender address, on the sending chain.
+    pub sender: String,
+    /// The receiver address, on the receiving chain.
+    pub receiver: String,
+}
+
+/// The JSON encoding of the packet data, with the same layout as in the Cosmos SDK: the keys are
+/// sorted, and the amount is a string.
+#[derive(Deserialize, Serialize)]
+struct JsonPacketData {
+    amount: String,
+    denom: String,
+    receiver: String,
+    sender: String,
+}
+
+impl FungibleTokenPacketData {
+    /// The bytes carried in the data field of an ICS4 packet.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let json = JsonPacketData {
+            amount: self.amount.to_string(),
+            denom: self.denom.clone(),
+            receiver: self.receiver.clone(),
+            sender: self.sender.clone(),
+        };
+        serde_json::to_vec(&json).unwrap()
+    }
+
+    /// Decodes and validates the data field of an ICS4 packet.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let json: JsonPacketData =
+            serde_json::from_slice(bytes).map_err(|e| Kind::InvalidPacketData.context(e))?;
+        let amount = json
+            .amount
+            .parse::<u64>()
+            .map_err(|e| Kind::InvalidPacketData.context(e))?;
+
+        Self::try_from(RawFungibleTokenPacketData {
+            denom: json.denom,
+            amount,
+            sender: json.sender,
+            receiver: json.receiver,
+        })
+    }
+}
+
+impl Protobuf<RawFungibleTokenPacketData> for FungibleTokenPacketData {}
+
+impl TryFrom<RawFungibleTokenPacketData> for FungibleTokenPacketData {
+    type Error = Error;
+
+    fn try_from(raw: RawFungibleTokenPacketData) -> Result<Self, Self::Error> {
+        if raw.denom.is_empty() {
+            return Err(Kind::InvalidPacketData.context("empty denomination").into());
+        }
+        if raw.amount == 0 {
+            return Err(Kind::InvalidPacketData
+                .context("the amount must be positive")
+                .into());
+        }
+        if raw.sender.is_empty() || raw.receiver.is_empty() {
+            return Err(Kind::InvalidPacketData
+                .context("missing sender or receiver address")
+                .into());
+        }
+
+        Ok(FungibleTokenPacketData {
+            denom: raw.denom,
+            amount: raw.amount,
+            sender: raw.sender,
+            receiver: raw.receiver,
+        })
+    }
+}
+
+impl From<FungibleTokenPacketData> for RawFungibleTokenPacketData {
+    fn from(data: FungibleTokenPacketData) -> Self {
+        RawFungibleTokenPacketData {
+            denom: data.denom,
+            amount: data.amount,
+            sender: data.sender,
+            receiver: data.receiver,
+        }
+    }
+}
+
+/// The acknowledgement which the receiving chain writes for every ICS20 packet. On error, the
+/// sending chain refunds the tokens to the sender.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Acknowledgement {
+    Success,
+    Error(String),
+}
+
+/// The JSON encoding of the ICS4 acknowledgement envelope, as in the Cosmos SDK.
+#[derive(Deserialize, Serialize)]
+struct JsonAcknowledgement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Acknowledgement {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Acknowledgement::Success)
+    }
+
+    /// The bytes which the receiving chain writes as acknowledgement of a packet.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let json = match self {
+            Acknowledgement::Success => JsonAcknowledgement {
+                result: Some(SUCCESS_ACK_RESULT.to_string()),
+                error: None,
+            },
+            Acknowledgement::Error(e) => JsonAcknowledgement {
+                result: None,
+                error: Some(e.clone()),
+            },
+        };
+        serde_json::to_vec(&json).unwrap()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let json: JsonAcknowledgement =
+            serde_json::from_slice(bytes).map_err(|e| Kind::InvalidAcknowledgement.context(e))?;
+
+        match (json.result, json.error) {
+            (_, Some(e)) => Ok(Acknowledgement::Error(e)),
+            (Some(result), None) if result == SUCCESS_ACK_RESULT => Ok(Acknowledgement::Success),
+            _ => Err(Kind::InvalidAcknowledgement.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::ics20_fungible_token_transfer::packet::FungibleTokenPacketData;
+    use crate::test_utils::get_dummy_bech32_account;
+
+    /// Returns a dummy `FungibleTokenPacketData`, for testing only! Both the sender and the receiver
+    /// are the dummy account.
+    pub fn get_dummy_packet_data(denom: &str, amount: u64) -> FungibleTokenPacketData {
+        FungibleTokenPacketData {
+            denom: denom.to_string(),
+            amount,
+            sender: get_dummy_bech32_account(),
+            receiver: get_dummy_bech32_account(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ics20_fungible_token_transfer::packet::test_util::get_dummy_packet_data;
+    use crate::ics20_fungible_token_transfer::packet::{Acknowledgement, FungibleTokenPacketData};
+
+    #[test]
+    fn packet_data_to_and_from_bytes() {
+        let data = get_dummy_packet_data("transfer/channelzero/atom", 100);
+        let bytes = data.to_bytes();
+
+        // The encoding matches the one of the Cosmos SDK, with sorted keys.
+        assert_eq!(
+            String::from_utf8(bytes.clone()).unwrap(),
+            format!(
+                r#"{{"amount":"100","denom":"transfer/channelzero/atom","receiver":"{0}","sender":"{0}"}}"#,
+                data.sender
+            )
+        );
+        assert_eq!(FungibleTokenPacketData::from_bytes(&bytes).unwrap(), data);
+
+        let zero_amount = get_dummy_packet_data("atom", 0);
+        assert!(FungibleTokenPacketData::from_bytes(&zero_amount.to_bytes()).is_err());
+        assert!(FungibleTokenPacketData::from_bytes(b"not json").is_err());
+    }
+
+    #[test]
+    fn acknowledgement_to_and_from_bytes() {
+        let success = Acknowledgement::Success;
+        assert_eq!(success.to_bytes(), br#"{"result":"AQ=="}"#.to_vec());
+        assert_eq!(
+            Acknowledgement::from_bytes(&success.to_bytes()).unwrap(),
+            success
+        );
+
+        let error = Acknowledgement::Error("insufficient funds".to_string());
+        assert_eq!(
+            error.to_bytes(),
+            br#"{"error":"insufficient funds"}"#.to_vec()
+        );
+        assert_eq!(
+            Acknowledgement::from_bytes(&error.to_bytes()).unwrap(),
+            error
+        );
+
+        assert!(Acknowledgement::from_bytes(b"{}").is_err());
+    }
+}
@@ -7,6 +7,12 @@ pub type Error = anomaly::Error<Kind>;
 pub enum Kind {
     #[error("invalid raw merkle proof")]
     InvalidRawMerkleProof,
+
+    #[error("invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[error("the merkle proof does not match the path or the commitment root")]
+    VerificationFailure,
 }
 
 impl Kind {
@@ -1,67 +1,86 @@
 use std::convert::TryFrom;
+use std::fmt;
+
+use prost::Message;
 
-use ibc_proto::ibc::core::commitment::v1::MerklePath;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ibc::core::commitment::v1::{
+    Key, KeyEncoding, KeyPath, MerklePath as RawMerklePath,
+};
+use ics23::commitment_proof::Proof;
+use ics23::{
+    calculate_existence_root, verify_membership, verify_non_membership, CommitmentProof,
+    ExistenceProof, NonExistenceProof, ProofSpec,
+};
+use tendermint_proto::crypto::ProofOp;
+
+use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+use crate::ics23_commitment::error::{Error, Kind};
+
+/// The path of an object in a chain of nested stores: the key of the outermost store under which
+/// the next store is committed, and so on down to the key of the object in the innermost store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath {
+    pub key_path: Vec<String>,
+}
+
+/// Formats the path as the Cosmos SDK does, i.e., as the concatenation of its URL-escaped keys,
+/// each preceded by a `/`. Solo machines sign over paths in this form.
+impl fmt::Display for MerklePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for key in self.key_path.iter() {
+            write!(f, "/")?;
+            for byte in key.bytes() {
+                match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => write!(f, "{}", byte as char)?,
+                    b'-' | b'_' | b'.' | b'~' | b'$' | b'&' | b'+' | b'=' | b':' | b'@' => {
+                        write!(f, "{}", byte as char)?
+                    }
+                    _ => write!(f, "%{:02X}", byte)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<MerklePath> for RawMerklePath {
+    fn from(value: MerklePath) -> Self {
+        let keys = value
+            .key_path
+            .into_iter()
+            .map(|key| Key {
+                name: key.into_bytes(),
+                enc: KeyEncoding::UrlUnspecified as i32,
+            })
+            .collect();
 
-use crate::ics23_commitment::commitment::CommitmentPrefix;
-use crate::ics23_commitment::error::Error;
+        RawMerklePath {
+            key_path: Some(KeyPath { keys }),
+        }
+    }
+}
 
+/// Builds the path under which the counterparty chain stores the object at the ICS24 `path`: the
+/// key of the IBC store (i.e., the commitment prefix), then the key within that store.
 pub fn apply_prefix(
     prefix: &CommitmentPrefix,
-    _path: String,
+    path: String,
 ) -> Result<MerklePath, Box<dyn std::error::Error>> {
     if prefix.is_empty() {
         return Err("empty prefix".into());
     }
 
-    // TODO
-    Ok(MerklePath { key_path: None })
+    let store_key = String::from_utf8(prefix.0.clone())?;
+    Ok(MerklePath {
+        key_path: vec![store_key, path],
+    })
 }
 
-// TODO - get this from the ics23 crate proof
-pub fn cosmos_specs() -> Vec<ibc_proto::ics23::ProofSpec> {
-    vec![
-        // Format of proofs-iavl (iavl merkle proofs)
-        ibc_proto::ics23::ProofSpec {
-            leaf_spec: Some(ibc_proto::ics23::LeafOp {
-                hash: 1,
-                prehash_key: 0,
-                prehash_value: 1,
-                length: 1,
-                prefix: vec![0],
-            }),
-            inner_spec: Some(ibc_proto::ics23::InnerSpec {
-                child_order: vec![0, 1],
-                child_size: 33,
-                min_prefix_length: 4,
-                max_prefix_length: 12,
-                empty_child: vec![],
-                hash: 1,
-            }),
-            max_depth: 0,
-            min_depth: 0,
-        },
-        // Format of proofs-tendermint (crypto/ merkle SimpleProof)
-        ibc_proto::ics23::ProofSpec {
-            leaf_spec: Some(ibc_proto::ics23::LeafOp {
-                hash: 1,
-                prehash_key: 0,
-                prehash_value: 1,
-                length: 1,
-                prefix: vec![0],
-            }),
-            inner_spec: Some(ibc_proto::ics23::InnerSpec {
-                child_order: vec![0, 1],
-                child_size: 32,
-                min_prefix_length: 1,
-                max_prefix_length: 1,
-                empty_child: vec![],
-                hash: 1,
-            }),
-            max_depth: 0,
-            min_depth: 0,
-        },
-    ]
+/// The specifications of the proofs of the Cosmos SDK stores: the IAVL tree of each store, then the
+/// simple Merkle tree of the multistore, which commits to the roots of the stores.
+pub fn cosmos_specs() -> Vec<ProofSpec> {
+    vec![ics23::iavl_spec(), ics23::tendermint_spec()]
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -69,6 +88,153 @@ pub struct MerkleProof {
     pub proof: Option<tendermint_proto::crypto::ProofOps>,
 }
 
+impl MerkleProof {
+    /// Verifies that `value` is stored at `path` in the store committed to by `root`. The proof is
+    /// a chain of ICS23 existence proofs, one per store, from the innermost store (e.g., the IAVL
+    /// tree of the IBC store) to the outermost one (e.g., the multistore), each verified against
+    /// the spec at the same position in `specs`. The key path lists keys outermost first.
+    pub fn verify_membership(
+        &self,
+        specs: &[ProofSpec],
+        root: &CommitmentRoot,
+        path: MerklePath,
+        value: Vec<u8>,
+    ) -> Result<(), Error> {
+        let keys = path_keys(path);
+        let ops = self.ops_for(specs, &keys)?;
+
+        verify_chained_existence(ops, specs, &keys, value, root)
+    }
+
+    /// Verifies that nothing is stored at `path` in the store committed to by `root`. The first
+    /// proof of the chain is an ICS23 non-existence proof for the key in the innermost store; the
+    /// other ones are existence proofs for the roots of the stores, as for `verify_membership`.
+    pub fn verify_non_membership(
+        &self,
+        specs: &[ProofSpec],
+        root: &CommitmentRoot,
+        path: MerklePath,
+    ) -> Result<(), Error> {
+        let keys = path_keys(path);
+        let ops = self.ops_for(specs, &keys)?;
+        let (key, store_keys) = keys.split_last().unwrap();
+
+        check_op_key(&ops[0], key)?;
+        let proof = decode_proof(&ops[0])?;
+        let subroot = match &proof.proof {
+            Some(Proof::Nonexist(non_existence_proof)) => non_existence_root(non_existence_proof)?,
+            _ => {
+                return Err(Kind::InvalidMerkleProof
+                    .context("not a non-existence proof")
+                    .into())
+            }
+        };
+        if !verify_non_membership(&proof, &specs[0], &subroot, key) {
+            return Err(Kind::VerificationFailure
+                .context("the proof does not prove the absence of the key")
+                .into());
+        }
+
+        verify_chained_existence(&ops[1..], &specs[1..], store_keys, subroot, root)
+    }
+
+    /// Returns the proofs of the chain, after checking that there is one per key and per spec.
+    fn ops_for(&self, specs: &[ProofSpec], keys: &[Vec<u8>]) -> Result<&[ProofOp], Error> {
+        let ops = match &self.proof {
+            Some(proof_ops) if !proof_ops.ops.is_empty() => &proof_ops.ops,
+            _ => return Err(Kind::InvalidMerkleProof.context("empty proof").into()),
+        };
+
+        if ops.len() != specs.len() || keys.len() != ops.len() {
+            return Err(Kind::InvalidMerkleProof
+                .context(format!(
+                    "{} proofs for {} keys and {} specs",
+                    ops.len(),
+                    keys.len(),
+                    specs.len()
+                ))
+                .into());
+        }
+        Ok(ops.as_slice())
+    }
+}
+
+fn path_keys(path: MerklePath) -> Vec<Vec<u8>> {
+    path.key_path.into_iter().map(String::into_bytes).collect()
+}
+
+fn check_op_key(op: &ProofOp, key: &[u8]) -> Result<(), Error> {
+    if op.key != key {
+        return Err(Kind::VerificationFailure
+            .context("the proof is for another path")
+            .into());
+    }
+    Ok(())
+}
+
+fn decode_proof(op: &ProofOp) -> Result<CommitmentProof, Error> {
+    let proof = CommitmentProof::decode(op.data.as_slice())
+        .map_err(|e| Kind::InvalidMerkleProof.context(e))?;
+    if proof.proof.is_none() {
+        return Err(Kind::InvalidMerkleProof.context("empty proof").into());
+    }
+    Ok(proof)
+}
+
+fn existence_root(proof: &ExistenceProof) -> Result<Vec<u8>, Error> {
+    calculate_existence_root(proof)
+        .map_err(|e| Kind::InvalidMerkleProof.context(e.to_string()).into())
+}
+
+/// The root of the tree which a non-existence proof is for, i.e., the root that the existence proof
+/// of either neighbour of the absent key commits to.
+fn non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, Error> {
+    match proof.left.as_ref().or_else(|| proof.right.as_ref()) {
+        Some(neighbour) => existence_root(neighbour),
+        None => Err(Kind::InvalidMerkleProof
+            .context("non-existence proof without neighbours")
+            .into()),
+    }
+}
+
+/// Verifies the chain of existence proofs which starts from `value` in the innermost store and ends
+/// at `root`: the root of each store is the value which the proof for the next store proves.
+fn verify_chained_existence(
+    ops: &[ProofOp],
+    specs: &[ProofSpec],
+    keys: &[Vec<u8>],
+    value: Vec<u8>,
+    root: &CommitmentRoot,
+) -> Result<(), Error> {
+    let mut subroot = value;
+    for ((op, spec), key) in ops.iter().zip(specs).zip(keys.iter().rev()) {
+        check_op_key(op, key)?;
+        let proof = decode_proof(op)?;
+        let store_root = match &proof.proof {
+            Some(Proof::Exist(existence_proof)) => existence_root(existence_proof)?,
+            _ => {
+                return Err(Kind::InvalidMerkleProof
+                    .context("not an existence proof")
+                    .into())
+            }
+        };
+        if !verify_membership(&proof, spec, &store_root, key, &subroot) {
+            return Err(Kind::VerificationFailure
+                .context("the proof does not prove the value at the key")
+                .into());
+        }
+
+        subroot = store_root;
+    }
+
+    if subroot != root.0 {
+        return Err(Kind::VerificationFailure
+            .context("the proof is for another root")
+            .into());
+    }
+    Ok(())
+}
+
 // Merkle Proof serialization notes:
 // "Proof" id currently defined in a number of forms and included in a number of places
 // - TmProof: in tendermint-rs/src/merkle/proof.rs:Proof
@@ -83,8 +249,8 @@ pub struct MerkleProof {
 //  - Vec<u8>: RawMerkleProof is not explicitly used but, serialized as Vec<u8>, it is
 //       included in all handshake messages that require proofs (i.e. all except the two `OpenInit`),
 //       and also in all queries that require proofs
-//  - MerkleProof: Domain type for RawMerkleProof, currently not used and identical to RawMerkleProof.
-//       This will change with verification implementation.
+//  - MerkleProof: Domain type for RawMerkleProof, which light clients use to verify proofs (see
+//       `MerkleProof::verify_membership` and `MerkleProof::verify_non_membership`).
 //  - CommitmentProof: Defined in ibc-rs as Vec<u8> and currently used in all its messages
 //
 // Here are a couple of flows that illustrate the different conversions:
@@ -120,3 +286,226 @@ impl From<MerkleProof> for RawMerkleProof {
         RawMerkleProof { proof: value.proof }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+    use tendermint_proto::crypto::{ProofOp, ProofOps};
+
+    use ics23::commitment_proof::Proof;
+    use ics23::{
+        calculate_existence_root, CommitmentProof, ExistenceProof, InnerOp, NonExistenceProof,
+    };
+
+    use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentRoot};
+    use crate::ics23_commitment::merkle::{apply_prefix, cosmos_specs, MerkleProof};
+
+    /// The prefixes of a leaf, and of an inner node of height 1 whose child on the path is on the
+    /// left, in a tree of the spec at `spec_index`. IAVL nodes start with their height, size and
+    /// version, as signed varints, and prefix the hash of each child with its length.
+    fn node_prefixes(spec_index: usize) -> (Vec<u8>, Vec<u8>) {
+        match spec_index {
+            0 => (vec![0, 2, 2], vec![2, 4, 2, 32]),
+            _ => (vec![0], vec![1]),
+        }
+    }
+
+    /// Builds the proof that `value` is stored under `key` in a tree of the spec at `spec_index`,
+    /// which holds a single other node, and returns it together with the root of the tree.
+    fn existence_proof(spec_index: usize, key: &[u8], value: &[u8]) -> (ProofOp, Vec<u8>) {
+        let spec = cosmos_specs().remove(spec_index);
+        let inner_spec = spec.inner_spec.unwrap();
+        let (leaf_prefix, inner_prefix) = node_prefixes(spec_index);
+
+        // The other node is on the right, hence its (length-prefixed) hash goes into the suffix.
+        let proof = ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: spec.leaf_spec.map(|leaf| ics23::LeafOp {
+                prefix: leaf_prefix,
+                ..leaf
+            }),
+            path: vec![InnerOp {
+                hash: inner_spec.hash,
+                prefix: inner_prefix,
+                suffix: [vec![32; inner_spec.child_size as usize - 32], vec![7; 32]].concat(),
+            }],
+        };
+        let root = calculate_existence_root(&proof).unwrap();
+
+        (proof_op(key, Proof::Exist(proof)), root)
+    }
+
+    fn proof_op(key: &[u8], proof: Proof) -> ProofOp {
+        let mut data = Vec::new();
+        CommitmentProof { proof: Some(proof) }
+            .encode(&mut data)
+            .unwrap();
+
+        ProofOp {
+            r#type: "ics23".to_string(),
+            key: key.to_vec(),
+            data,
+        }
+    }
+
+    /// Builds the existence proofs of the two leaves of an IAVL tree, and returns them together
+    /// with the root of the tree.
+    fn iavl_leaves(
+        (left_key, left_value): (&[u8], &[u8]),
+        (right_key, right_value): (&[u8], &[u8]),
+    ) -> (ExistenceProof, ExistenceProof, Vec<u8>) {
+        let (leaf_prefix, _) = node_prefixes(0);
+        let leaf = |key: &[u8], value: &[u8]| ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: cosmos_specs()
+                .remove(0)
+                .leaf_spec
+                .map(|leaf| ics23::LeafOp {
+                    prefix: leaf_prefix.clone(),
+                    ..leaf
+                }),
+            path: vec![],
+        };
+        let mut left = leaf(left_key, left_value);
+        let mut right = leaf(right_key, right_value);
+        let left_hash = calculate_existence_root(&left).unwrap();
+        let right_hash = calculate_existence_root(&right).unwrap();
+
+        // The root hashes the header of the node, then each child hash prefixed by its length.
+        let header = vec![2, 4, 2];
+        let mut right_prefix = header.clone();
+        right_prefix.push(32);
+        right_prefix.extend(&left_hash);
+        right_prefix.push(32);
+        left.path.push(InnerOp {
+            hash: 1,
+            prefix: [header.as_slice(), &[32]].concat(),
+            suffix: [&[32], right_hash.as_slice()].concat(),
+        });
+        right.path.push(InnerOp {
+            hash: 1,
+            prefix: right_prefix,
+            suffix: vec![],
+        });
+
+        let root = calculate_existence_root(&left).unwrap();
+        assert_eq!(root, calculate_existence_root(&right).unwrap());
+        (left, right, root)
+    }
+
+    #[test]
+    fn verify_chained_membership() {
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let path = "connections/connectionid".to_string();
+        let value = b"connection end".to_vec();
+        let specs = cosmos_specs();
+
+        // The IBC store, then the multistore which commits to the root of the IBC store.
+        let (store_op, store_root) = existence_proof(0, path.as_bytes(), &value);
+        let (multistore_op, root) = existence_proof(1, b"ibc", &store_root);
+        let root = CommitmentRoot::from(root);
+        let proof = MerkleProof {
+            proof: Some(ProofOps {
+                ops: vec![store_op.clone(), multistore_op],
+            }),
+        };
+
+        let merkle_path = apply_prefix(&prefix, path.clone()).unwrap();
+        assert_eq!(merkle_path.key_path.len(), 2);
+        assert_eq!(merkle_path.to_string(), "/ibc/connections%2Fconnectionid");
+        assert!(proof
+            .verify_membership(&specs, &root, merkle_path.clone(), value.clone())
+            .is_ok());
+
+        // Another value, another path or another root.
+        assert!(proof
+            .verify_membership(&specs, &root, merkle_path.clone(), b"other".to_vec())
+            .is_err());
+        let other_path = apply_prefix(&prefix, "connections/otherid".to_string()).unwrap();
+        assert!(proof
+            .verify_membership(&specs, &root, other_path, value.clone())
+            .is_err());
+        assert!(proof
+            .verify_membership(
+                &specs,
+                &CommitmentRoot::from(store_root),
+                merkle_path.clone(),
+                value.clone()
+            )
+            .is_err());
+
+        // The proof for the IBC store alone says nothing about the root of the multistore.
+        let partial_proof = MerkleProof {
+            proof: Some(ProofOps {
+                ops: vec![store_op],
+            }),
+        };
+        assert!(partial_proof
+            .verify_membership(&specs, &root, merkle_path, value)
+            .is_err());
+    }
+
+    #[test]
+    fn verify_chained_non_membership() {
+        let prefix = CommitmentPrefix::from(b"ibc".to_vec());
+        let specs = cosmos_specs();
+        let (left, right, store_root) = iavl_leaves((b"a", b"value a"), (b"c", b"value c"));
+        let (multistore_op, root) = existence_proof(1, b"ibc", &store_root);
+        let root = CommitmentRoot::from(root);
+
+        let non_membership_proof = |key: &[u8], left: Option<ExistenceProof>, right| MerkleProof {
+            proof: Some(ProofOps {
+                ops: vec![
+                    proof_op(
+                        key,
+                        Proof::Nonexist(NonExistenceProof {
+                            key: key.to_vec(),
+                            left,
+                            right,
+                        }),
+                    ),
+                    multistore_op.clone(),
+                ],
+            }),
+        };
+        let path = |key: &str| apply_prefix(&prefix, key.to_string()).unwrap();
+
+        // The neighbours of the absent key are the two leaves of the tree.
+        let proof = non_membership_proof(b"b", Some(left.clone()), Some(right.clone()));
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("b"))
+            .is_ok());
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("a"))
+            .is_err());
+        assert!(proof
+            .verify_non_membership(&specs, &CommitmentRoot::from(store_root), path("b"))
+            .is_err());
+
+        // A key beyond the last leaf only needs a left neighbour, which must be the last leaf.
+        let proof = non_membership_proof(b"d", Some(right.clone()), None);
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("d"))
+            .is_ok());
+        let proof = non_membership_proof(b"d", Some(left.clone()), None);
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("d"))
+            .is_err());
+
+        // Neighbours which are not around the key, or a present key.
+        let proof = non_membership_proof(b"d", Some(left.clone()), Some(right.clone()));
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("d"))
+            .is_err());
+        let proof = non_membership_proof(b"a", None, Some(left));
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("a"))
+            .is_err());
+        let proof = non_membership_proof(b"b", None, Some(right));
+        assert!(proof
+            .verify_non_membership(&specs, &root, path("b"))
+            .is_err());
+    }
+}
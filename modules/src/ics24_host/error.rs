@@ -24,6 +24,12 @@ pub enum ValidationKind {
 
     #[error("chain identifiers are expected to be in epoch format {id}")]
     ChainIdInvalidFormat { id: String },
+
+    #[error("{path} is not a valid ICS24 path")]
+    InvalidPath { path: String },
+
+    #[error("the identifiers of clients of type {client_type} have no common prefix")]
+    MissingPrefix { client_type: String },
 }
 
 impl ValidationKind {
@@ -52,6 +58,14 @@ impl ValidationKind {
         Self::ChainIdInvalidFormat { id }
     }
 
+    pub fn invalid_path(path: String) -> Self {
+        Self::InvalidPath { path }
+    }
+
+    pub fn missing_prefix(client_type: String) -> Self {
+        Self::MissingPrefix { client_type }
+    }
+
     pub fn context(self, source: impl Into<BoxError>) -> Context<Self> {
         Context::new(self, Some(source.into()))
     }
@@ -2,8 +2,9 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-use super::error::ValidationError;
+use super::error::{ValidationError, ValidationKind};
 use super::validate::*;
+use crate::ics02_client::client_type::ClientType;
 
 /// This type is subject to future changes.
 ///
@@ -111,6 +112,51 @@ impl From<tendermint::chain::Id> for ChainId {
 pub struct ClientId(String);
 
 impl ClientId {
+    /// Builds a new client identifier. Client identifiers are deterministically formed from two
+    /// elements: a prefix derived from the client type `ctype`, and a monotonically increasing
+    /// `counter`; these are separated by a dash "-".
+    ///
+    /// ```
+    /// # use ibc::ics24_host::identifier::ClientId;
+    /// # use ibc::ics02_client::client_type::ClientType;
+    /// let tm_client_id = ClientId::new(ClientType::Tendermint, 0);
+    /// assert!(tm_client_id.is_ok());
+    /// tm_client_id.map(|id| { assert_eq!(&id, "07-tendermint-0") });
+    /// ```
+    pub fn new(ctype: ClientType, counter: u64) -> Result<Self, ValidationError> {
+        let prefix = Self::prefix(ctype)
+            .ok_or_else(|| ValidationKind::missing_prefix(ctype.as_string().to_string()))?;
+        Self::with_prefix(prefix, counter)
+    }
+
+    /// Builds a new client identifier out of the given `prefix`, e.g., the client type which a
+    /// registered light client supplies, and a monotonically increasing `counter`.
+    ///
+    /// ```
+    /// # use ibc::ics24_host::identifier::ClientId;
+    /// let wasm_client_id = ClientId::with_prefix("10-wasm", 3);
+    /// assert!(wasm_client_id.is_ok());
+    /// wasm_client_id.map(|id| { assert_eq!(&id, "10-wasm-3") });
+    /// ```
+    pub fn with_prefix(prefix: &str, counter: u64) -> Result<Self, ValidationError> {
+        let id = format!("{}-{}", prefix, counter);
+        Self::from_str(id.as_str())
+    }
+
+    /// Returns the prefix of the identifiers of the built-in clients of type `client_type`.
+    /// Registered clients have no common prefix: each supplies its own, see `LightClient`.
+    pub fn prefix(client_type: ClientType) -> Option<&'static str> {
+        match client_type {
+            ClientType::Tendermint => Some("07-tendermint"),
+            ClientType::SoloMachine => Some("06-solomachine"),
+            ClientType::Localhost => Some("09-localhost"),
+            ClientType::Registered => None,
+
+            #[cfg(any(test, feature = "mocks"))]
+            ClientType::Mock => Some("9999-mock"),
+        }
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -161,6 +207,26 @@ impl PartialEq<str> for ClientId {
 pub struct ConnectionId(String);
 
 impl ConnectionId {
+    /// Builds a new connection identifier. Connection identifiers are deterministically formed from
+    /// two elements: a prefix `prefix`, and a monotonically increasing `counter`; these are
+    /// separated by a dash "-". The prefix is currently determined statically (see
+    /// `ConnectionId::prefix()`) so this method accepts a single argument, the `counter`.
+    ///
+    /// ```
+    /// # use ibc::ics24_host::identifier::ConnectionId;
+    /// let conn_id = ConnectionId::new(11);
+    /// assert_eq!(&conn_id, "connection-11");
+    /// ```
+    pub fn new(counter: u64) -> Self {
+        let id = format!("{}-{}", Self::prefix(), counter);
+        Self(id)
+    }
+
+    /// Returns the static prefix to be used across all connection identifiers.
+    pub fn prefix() -> &'static str {
+        "connection"
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -246,6 +312,27 @@ impl Default for PortId {
 pub struct ChannelId(String);
 
 impl ChannelId {
+    /// Builds a new channel identifier. Like client and connection identifiers, channel ids are
+    /// deterministically formed from two elements: a prefix `prefix`, and a monotonically
+    /// increasing `counter`, separated by a dash "-".
+    /// The prefix is currently determined statically (see `ChannelId::prefix()`) so this method
+    /// accepts a single argument, the `counter`.
+    ///
+    /// ```
+    /// # use ibc::ics24_host::identifier::ChannelId;
+    /// let chan_id = ChannelId::new(27);
+    /// assert_eq!(chan_id.to_string(), "channel-27");
+    /// ```
+    pub fn new(counter: u64) -> Self {
+        let id = format!("{}-{}", Self::prefix(), counter);
+        Self(id)
+    }
+
+    /// Returns the static prefix to be used across all channel identifiers.
+    pub fn prefix() -> &'static str {
+        "channel"
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
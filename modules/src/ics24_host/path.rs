@@ -2,15 +2,21 @@
 /// https://github.com/cosmos/ics/tree/master/spec/ics-024-host-requirements#path-space
 /// Some of these are implemented in other ICSs, but ICS-024 has a nice summary table.
 ///
+use crate::ics24_host::error::{ValidationError, ValidationKind};
 use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
 /// IBC Query Path is hard-coded
 pub const IBC_QUERY_PATH: &str = "store/ibc/key";
 
 /// The Path enum abstracts out the different sub-paths
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Path {
+    NextClientSequence,
+    NextConnectionSequence,
+    NextChannelSequence,
     ClientType(ClientId),
     ClientState(ClientId),
     ClientConsensusState {
@@ -35,12 +41,25 @@ pub enum Path {
         channel_id: ChannelId,
         sequence: u64,
     },
+    Receipts {
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: u64,
+    },
+    /// The client state which a chain commits for the counterparty clients to upgrade to, along
+    /// with the plan of its upgrade at the given height.
+    UpgradedClientState(u64),
+    UpgradedClientConsensusState(u64),
 }
 
 impl Path {
-    /// Indication if the path is provable.
+    /// Indication if the path is provable. The identifier counters only serve the bookkeeping of
+    /// the host chain, which no counterparty verifies.
     pub fn is_provable(&self) -> bool {
         match &self {
+            Path::NextClientSequence => false,
+            Path::NextConnectionSequence => false,
+            Path::NextChannelSequence => false,
             Path::ClientConnections(_) => false,
             Path::Ports(_) => false,
             _ => true,
@@ -58,6 +77,9 @@ impl Path {
 impl Display for Path {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match &self {
+            Path::NextClientSequence => write!(f, "nextClientSequence"),
+            Path::NextConnectionSequence => write!(f, "nextConnectionSequence"),
+            Path::NextChannelSequence => write!(f, "nextChannelSequence"),
             Path::ClientType(client_id) => write!(f, "clients/{}/clientType", client_id),
             Path::ClientState(client_id) => write!(f, "clients/{}/clientState", client_id),
             Path::ClientConsensusState {
@@ -108,6 +130,303 @@ impl Display for Path {
                 "acks/ports/{}/channels/{}/acknowledgements/{}",
                 port_id, channel_id, sequence
             ),
+            Path::Receipts {
+                port_id,
+                channel_id,
+                sequence,
+            } => write!(
+                f,
+                "receipts/ports/{}/channels/{}/sequences/{}",
+                port_id, channel_id, sequence
+            ),
+            Path::UpgradedClientState(height) => {
+                write!(f, "upgradedIBCState/{}/upgradedClient", height)
+            }
+            Path::UpgradedClientConsensusState(height) => {
+                write!(f, "upgradedIBCState/{}/upgradedConsState", height)
+            }
+        }
+    }
+}
+
+/// Parses the paths displayed above, e.g., the store keys found in the logs of ABCI queries.
+impl FromStr for Path {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || ValidationKind::invalid_path(s.to_string());
+        let seq = |sequence: &str| sequence.parse::<u64>().map_err(|e| invalid().context(e));
+
+        let parts: Vec<&str> = s.split('/').collect();
+        let path = match parts.as_slice() {
+            ["nextClientSequence"] => Path::NextClientSequence,
+            ["nextConnectionSequence"] => Path::NextConnectionSequence,
+            ["nextChannelSequence"] => Path::NextChannelSequence,
+            ["clients", client_id, "clientType"] => Path::ClientType(client_id.parse()?),
+            ["clients", client_id, "clientState"] => Path::ClientState(client_id.parse()?),
+            ["clients", client_id, "consensusState", epoch_height] => {
+                let mut split = epoch_height.splitn(2, '-');
+                match (split.next(), split.next()) {
+                    (Some(epoch), Some(height)) => Path::ClientConsensusState {
+                        client_id: client_id.parse()?,
+                        epoch: seq(epoch)?,
+                        height: seq(height)?,
+                    },
+                    _ => return Err(invalid().into()),
+                }
+            }
+            ["clients", client_id, "connections"] => Path::ClientConnections(client_id.parse()?),
+            ["connections", connection_id] => Path::Connections(connection_id.parse()?),
+            ["ports", port_id] => Path::Ports(port_id.parse()?),
+            ["channelEnds", "ports", port_id, "channels", channel_id] => {
+                Path::ChannelEnds(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqSends", "ports", port_id, "channels", channel_id, "nextSequenceSend"] => {
+                Path::SeqSends(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqRecvs", "ports", port_id, "channels", channel_id, "nextSequenceRecv"] => {
+                Path::SeqRecvs(port_id.parse()?, channel_id.parse()?)
+            }
+            ["seqAcks", "ports", port_id, "channels", channel_id, "nextSequenceAck"] => {
+                Path::SeqAcks(port_id.parse()?, channel_id.parse()?)
+            }
+            ["commitments", "ports", port_id, "channels", channel_id, "packets", sequence] => {
+                Path::Commitments {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: seq(sequence)?,
+                }
+            }
+            ["acks", "ports", port_id, "channels", channel_id, "acknowledgements", sequence] => {
+                Path::Acks {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: seq(sequence)?,
+                }
+            }
+            ["receipts", "ports", port_id, "channels", channel_id, "sequences", sequence] => {
+                Path::Receipts {
+                    port_id: port_id.parse()?,
+                    channel_id: channel_id.parse()?,
+                    sequence: seq(sequence)?,
+                }
+            }
+            ["upgradedIBCState", height, "upgradedClient"] => {
+                Path::UpgradedClientState(seq(height)?)
+            }
+            ["upgradedIBCState", height, "upgradedConsState"] => {
+                Path::UpgradedClientConsensusState(seq(height)?)
+            }
+            _ => return Err(invalid().into()),
+        };
+
+        Ok(path)
+    }
+}
+
+/// Parses the raw store keys, as produced by `Path::into_bytes`.
+impl TryFrom<&[u8]> for Path {
+    type Error = ValidationError;
+
+    fn try_from(bytes: &[u8]) -> std::result::Result<Self, Self::Error> {
+        let s = std::str::from_utf8(bytes).map_err(|e| {
+            ValidationKind::invalid_path(String::from_utf8_lossy(bytes).to_string()).context(e)
+        })?;
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    use proptest::prelude::*;
+
+    use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
+    use crate::ics24_host::Path;
+
+    /// Identifiers made of the characters which `validate_identifier` accepts, of the given lengths.
+    fn identifier(min: usize, max: usize) -> impl Strategy<Value = String> {
+        proptest::string::string_regex(&format!(r"[a-zA-Z0-9._+#\[\]<>-]{{{},{}}}", min, max))
+            .unwrap()
+    }
+
+    fn client_id() -> impl Strategy<Value = ClientId> {
+        identifier(9, 64).prop_map(|id| ClientId::from_str(&id).unwrap())
+    }
+
+    fn connection_id() -> impl Strategy<Value = ConnectionId> {
+        identifier(10, 64).prop_map(|id| ConnectionId::from_str(&id).unwrap())
+    }
+
+    fn port_id() -> impl Strategy<Value = PortId> {
+        identifier(2, 64).prop_map(|id| PortId::from_str(&id).unwrap())
+    }
+
+    fn channel_id() -> impl Strategy<Value = ChannelId> {
+        identifier(8, 64).prop_map(|id| ChannelId::from_str(&id).unwrap())
+    }
+
+    /// Every variant of `Path`, out of arbitrary identifiers and numbers.
+    fn path() -> impl Strategy<Value = Path> {
+        prop_oneof![
+            Just(Path::NextClientSequence),
+            Just(Path::NextConnectionSequence),
+            Just(Path::NextChannelSequence),
+            client_id().prop_map(Path::ClientType),
+            client_id().prop_map(Path::ClientState),
+            (client_id(), any::<u64>(), any::<u64>()).prop_map(|(client_id, epoch, height)| {
+                Path::ClientConsensusState {
+                    client_id,
+                    epoch,
+                    height,
+                }
+            }),
+            client_id().prop_map(Path::ClientConnections),
+            connection_id().prop_map(Path::Connections),
+            port_id().prop_map(Path::Ports),
+            (port_id(), channel_id()).prop_map(|(p, c)| Path::ChannelEnds(p, c)),
+            (port_id(), channel_id()).prop_map(|(p, c)| Path::SeqSends(p, c)),
+            (port_id(), channel_id()).prop_map(|(p, c)| Path::SeqRecvs(p, c)),
+            (port_id(), channel_id()).prop_map(|(p, c)| Path::SeqAcks(p, c)),
+            (port_id(), channel_id(), any::<u64>()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Commitments {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+            }),
+            (port_id(), channel_id(), any::<u64>()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Acks {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+            }),
+            (port_id(), channel_id(), any::<u64>()).prop_map(|(port_id, channel_id, sequence)| {
+                Path::Receipts {
+                    port_id,
+                    channel_id,
+                    sequence,
+                }
+            }),
+            any::<u64>().prop_map(Path::UpgradedClientState),
+            any::<u64>().prop_map(Path::UpgradedClientConsensusState),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn parse_displayed_paths(path in path()) {
+            prop_assert_eq!(Path::from_str(&path.to_string()).unwrap(), path.clone());
+            prop_assert_eq!(
+                Path::try_from(path.clone().into_bytes().as_slice()).unwrap(),
+                path
+            );
         }
     }
+
+    #[test]
+    fn parse_known_paths() {
+        assert_eq!(
+            Path::from_str("clients/07-tendermint-0/consensusState/1-42").unwrap(),
+            Path::ClientConsensusState {
+                client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                epoch: 1,
+                height: 42,
+            }
+        );
+        assert_eq!(
+            Path::from_str("acks/ports/transfer/channels/channel-0/acknowledgements/7").unwrap(),
+            Path::Acks {
+                port_id: PortId::from_str("transfer").unwrap(),
+                channel_id: ChannelId::new(0),
+                sequence: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn provable_paths() {
+        let port_id = PortId::from_str("transfer").unwrap();
+        let channel_id = ChannelId::new(0);
+
+        struct Test {
+            path: Path,
+            provable: bool,
+        }
+
+        let tests = vec![
+            Test {
+                path: Path::NextClientSequence,
+                provable: false,
+            },
+            Test {
+                path: Path::NextConnectionSequence,
+                provable: false,
+            },
+            Test {
+                path: Path::NextChannelSequence,
+                provable: false,
+            },
+            Test {
+                path: Path::Ports(port_id.clone()),
+                provable: false,
+            },
+            Test {
+                path: Path::Receipts {
+                    port_id,
+                    channel_id,
+                    sequence: 1,
+                },
+                provable: true,
+            },
+            Test {
+                path: Path::UpgradedClientState(10),
+                provable: true,
+            },
+            Test {
+                path: Path::UpgradedClientConsensusState(10),
+                provable: true,
+            },
+        ];
+
+        for test in tests {
+            assert_eq!(test.path.is_provable(), test.provable, "{}", test.path);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_paths() {
+        let invalid = [
+            "",
+            "clients",
+            "clients/07-tendermint-0",
+            "clients/07-tendermint-0/clientStates",
+            "clients/07-tendermint-0/consensusState/42",
+            "clients/07-tendermint-0/consensusState/1-",
+            "clients/07-tendermint-0/consensusState/one-42",
+            "clients/short/clientState",
+            "/connections/connection-0",
+            "connections/connection-0/",
+            "channelEnds/ports/transfer/channel/channel-0",
+            "seqSends/ports/transfer/channels/channel-0/nextSequenceRecv",
+            "commitments/ports/transfer/channels/channel-0/packets/-1",
+            "receipts/ports/transfer/channels/channel-0/sequences",
+            "nextClientSequence/0",
+            "upgradedIBCState/upgradedClient",
+            "upgradedIBCState/10/upgradedConsensusState",
+        ];
+
+        for path in invalid.iter() {
+            assert!(
+                Path::from_str(path).is_err(),
+                "parsed invalid path {}",
+                path
+            );
+        }
+
+        assert!(Path::try_from(&[0xff, 0xfe][..]).is_err());
+    }
 }
@@ -79,10 +79,10 @@ pub fn validate_port_identifier(id: &str) -> Result<(), ValidationError> {
 
 /// Default validator function for Channel identifiers.
 ///
-/// A valid Identifier must be between 10-64 characters and only contain lowercase
+/// A valid Identifier must be between 8-64 characters and only contain lowercase
 /// alphabetic characters,
 pub fn validate_channel_identifier(id: &str) -> Result<(), ValidationError> {
-    validate_identifier(id, 10, 64)
+    validate_identifier(id, 8, 64)
 }
 
 #[cfg(test)]
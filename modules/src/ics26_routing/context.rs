@@ -1,6 +1,25 @@
 use crate::ics02_client::context::{ClientKeeper, ClientReader};
+use crate::ics02_client::registry::ClientRegistry;
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
+use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics26_routing::router::Router;
 
 /// This trait captures all the functional dependencies (i.e., context) which the ICS26 module
 /// requires to be able to dispatch messages to their corresponding ICS handler.
-pub trait ICS26Context: ClientReader + ClientKeeper + ConnectionReader + ConnectionKeeper {}
+pub trait ICS26Context:
+    ClientReader
+    + ClientKeeper
+    + ConnectionReader
+    + ConnectionKeeper
+    + ChannelReader
+    + ChannelKeeper
+{
+    /// Returns the router which binds the IBC applications of the host chain to their ports.
+    fn router(&self) -> &Router<Self>
+    where
+        Self: Sized;
+
+    /// Returns the light clients which the host chain supports on top of the built-in ones, and
+    /// whose states and headers the ICS2 messages may thus carry.
+    fn client_registry(&self) -> &ClientRegistry;
+}
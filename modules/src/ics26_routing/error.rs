@@ -1,6 +1,8 @@
 use anomaly::{BoxError, Context};
 use thiserror::Error;
 
+use crate::ics24_host::identifier::PortId;
+
 pub type Error = anomaly::Error<Kind>;
 
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
@@ -10,6 +12,24 @@ pub enum Kind {
 
     #[error("error raised by the keeper functionality in message handler")]
     KeeperRaisedError,
+
+    #[error("no application is bound to port {0}")]
+    ModuleNotFound(PortId),
+
+    #[error("an application is already bound to port {0}")]
+    RouteAlreadyExists(PortId),
+
+    #[error("failed to bind port {0}")]
+    PortBindingFailed(PortId),
+
+    #[error("error raised by the application callback")]
+    ModuleCallbackFailed,
+
+    #[error("unknown type URL {0}")]
+    UnknownMessageTypeUrl(String),
+
+    #[error("the message is malformed and cannot be decoded")]
+    MalformedMessageBytes,
 }
 
 impl Kind {
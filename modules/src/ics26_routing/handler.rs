@@ -1,21 +1,52 @@
+use std::sync::Arc;
+
 use crate::handler::HandlerOutput;
 use crate::ics02_client::handler::dispatch as ics2_msg_dispatcher;
 use crate::ics03_connection::handler::dispatch as ics3_msg_dispatcher;
+use crate::ics04_channel::handler::dispatch as ics4_msg_dispatcher;
+use crate::ics04_channel::handler::packet_dispatch as ics4_packet_msg_dispatcher;
+use crate::ics04_channel::handler::write_acknowledgement::write_acknowledgement;
+use crate::ics04_channel::handler::ChannelResult;
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics24_host::identifier::PortId;
 use crate::ics26_routing::context::ICS26Context;
 use crate::ics26_routing::error::{Error, Kind};
+use crate::ics26_routing::module::Module;
 use crate::ics26_routing::msgs::ICS26Envelope;
-use crate::ics26_routing::msgs::ICS26Envelope::{ICS2Msg, ICS3Msg};
+use crate::ics26_routing::msgs::ICS26Envelope::{ICS2Msg, ICS3Msg, ICS4Msg, ICS4PacketMsg};
 use ibc_proto::cosmos::tx::v1beta1::Tx;
 
-// TODO: Implement this (the tx type is probably wrong also). Rough sketch:
-// 1. deserialize & validate each message in the tx
-// 2. invoke dispatch(ctx, ms)
-// 3. if all message in the tx pass through correctly, then apply the side-effects to the context
-pub fn deliver_tx<Ctx>(_ctx: &mut Ctx, _tx: Tx) -> Result<(), Error>
+/// Mimics the DeliverTx ABCI interface: decodes and validates every message in the transaction,
+/// then dispatches them in order. The transaction is atomic: the side-effects of its messages are
+/// applied to the context only if all of them succeed. Returns the logs and events of all messages.
+pub fn deliver_tx<Ctx>(ctx: &mut Ctx, tx: Tx) -> Result<HandlerOutput<()>, Error>
 where
-    Ctx: ICS26Context,
+    Ctx: ICS26Context + Clone,
 {
-    unimplemented!()
+    let messages = tx.body.map(|body| body.messages).unwrap_or_default();
+
+    // Decode all messages upfront, so that a malformed message fails the transaction early.
+    let envelopes = messages
+        .into_iter()
+        .map(|msg| ICS26Envelope::decode(msg, ctx.client_registry()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Process the messages against a copy of the context: a failing message then leaves no partial
+    // writes from earlier messages.
+    let mut sandbox = ctx.clone();
+    let mut output = HandlerOutput::builder();
+    for envelope in envelopes {
+        let msg_output = dispatch(&mut sandbox, envelope)?;
+        output = output
+            .with_log(msg_output.log)
+            .with_events(msg_output.events);
+    }
+
+    // Every message went through: commit the side-effects.
+    *ctx = sandbox;
+
+    Ok(output.with_result(()))
 }
 
 /// Top-level ICS dispatch function. Routes incoming IBC messages to their corresponding module.
@@ -52,40 +83,210 @@ where
                 .with_log(handler_output.log)
                 .with_events(handler_output.events)
                 .with_result(())
-        } // TODO: add dispatchers for ICS4 and others.
+        }
+
+        ICS4Msg(msg) => {
+            // The channel handlers act on behalf of the application bound to the port.
+            let (module, capability) = bound_module(ctx, msg.port_id())?;
+            let handler_output = ics4_msg_dispatcher(ctx, &capability, msg.clone())
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            // The application bound to the port must accept the handshake step.
+            channel_callback(ctx, module.as_ref(), &msg, &handler_output.result)?;
+
+            // Apply any results to the host chain store.
+            ctx.store_channel_result(handler_output.result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+            HandlerOutput::builder()
+                .with_log(handler_output.log)
+                .with_events(handler_output.events)
+                .with_result(())
+        }
+
+        ICS4PacketMsg(msg) => {
+            let (module, capability) = bound_module(ctx, msg.port_id())?;
+            let handler_output = ics4_packet_msg_dispatcher(ctx, &capability, msg.clone())
+                .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+
+            // Hand the packet over to the application bound to the port. This happens before any
+            // write of the packet handler, so that a failing application leaves no trace of the
+            // packet in the host chain store.
+            let module_output = packet_callback(ctx, module.as_ref(), &msg)?;
+
+            // Apply any results to the host chain store.
+            ctx.store_packet_result(handler_output.result)
+                .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+
+            let mut output = HandlerOutput::builder()
+                .with_log(handler_output.log)
+                .with_events(handler_output.events)
+                .with_log(module_output.log)
+                .with_events(module_output.events);
+
+            // Write the acknowledgement of a received packet. An empty acknowledgement is written
+            // later on, by the application itself.
+            if let PacketMsg::RecvPacket(msg) = msg {
+                if !module_output.result.is_empty() {
+                    let ack_output = write_acknowledgement(
+                        ctx,
+                        &capability,
+                        msg.packet().clone(),
+                        module_output.result,
+                    )
+                    .map_err(|e| Kind::HandlerRaisedError.context(e))?;
+                    ctx.store_packet_result(ack_output.result)
+                        .map_err(|e| Kind::KeeperRaisedError.context(e))?;
+                    output = output
+                        .with_log(ack_output.log)
+                        .with_events(ack_output.events);
+                }
+            }
+
+            output.with_result(())
+        }
     };
 
     Ok(output)
 }
 
+/// Returns the application bound to the given port, along with the capability it holds for it.
+fn bound_module<Ctx>(
+    ctx: &Ctx,
+    port_id: &PortId,
+) -> Result<(Arc<dyn Module<Ctx>>, Capability), Error>
+where
+    Ctx: ICS26Context,
+{
+    let router = ctx.router();
+    router
+        .get_route(port_id)
+        .zip(router.get_capability(port_id))
+        .ok_or_else(|| Kind::ModuleNotFound(port_id.clone()).into())
+}
+
+/// Invokes the callback of the application for the channel handshake step in `msg`.
+fn channel_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &ChannelMsg,
+    result: &ChannelResult,
+) -> Result<(), Error>
+where
+    Ctx: ICS26Context,
+{
+    let (port_id, channel_id) = (&result.port_id, &result.channel_id);
+
+    match msg {
+        ChannelMsg::ChannelOpenInit(_) => {
+            module.on_chan_open_init(ctx, port_id, channel_id, &result.channel_end)
+        }
+        ChannelMsg::ChannelOpenTry(msg) => module.on_chan_open_try(
+            ctx,
+            port_id,
+            channel_id,
+            &result.channel_end,
+            &msg.counterparty_version,
+        ),
+        ChannelMsg::ChannelOpenAck(msg) => {
+            module.on_chan_open_ack(ctx, port_id, channel_id, &msg.counterparty_version)
+        }
+        ChannelMsg::ChannelOpenConfirm(_) => module.on_chan_open_confirm(ctx, port_id, channel_id),
+        ChannelMsg::ChannelCloseInit(_) => module.on_chan_close_init(ctx, port_id, channel_id),
+        ChannelMsg::ChannelCloseConfirm(_) => {
+            module.on_chan_close_confirm(ctx, port_id, channel_id)
+        }
+    }
+}
+
+/// Invokes the callback of the application for the packet in `msg`. Returns the output of the
+/// application, whose result is the acknowledgement of a received packet, and is empty otherwise.
+fn packet_callback<Ctx>(
+    ctx: &mut Ctx,
+    module: &dyn Module<Ctx>,
+    msg: &PacketMsg,
+) -> Result<HandlerOutput<Vec<u8>>, Error>
+where
+    Ctx: ICS26Context,
+{
+    match msg {
+        PacketMsg::RecvPacket(msg) => module.on_recv_packet(ctx, msg.packet()),
+        PacketMsg::Acknowledgement(msg) => {
+            let module_output =
+                module.on_acknowledgement_packet(ctx, msg.packet(), msg.acknowledgement())?;
+            Ok(without_ack(module_output))
+        }
+        PacketMsg::Timeout(msg) => Ok(without_ack(module.on_timeout_packet(ctx, msg.packet())?)),
+        PacketMsg::TimeoutOnClose(msg) => {
+            Ok(without_ack(module.on_timeout_packet(ctx, msg.packet())?))
+        }
+    }
+}
+
+/// Keeps the logs and events of an application callback which acknowledges nothing.
+fn without_ack(output: HandlerOutput<()>) -> HandlerOutput<Vec<u8>> {
+    HandlerOutput::builder()
+        .with_log(output.log)
+        .with_events(output.events)
+        .with_result(vec![])
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
     use std::str::FromStr;
+    use std::sync::Arc;
+
+    use ibc_proto::cosmos::tx::v1beta1::{Tx, TxBody};
+    use ibc_proto::ibc::core::client::v1::{MsgCreateClient, MsgUpdateClient};
+    use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
+    use prost_types::Any;
 
+    use crate::handler::{HandlerOutput, HandlerResult};
     use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState};
+    use crate::ics02_client::client_type::ClientType;
     use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
     use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
     use crate::ics02_client::msgs::ClientMsg;
+    use crate::ics02_client::registry::test_util::{
+        dummy_any, dummy_registry, DUMMY_CLIENT_STATE_TYPE_URL, DUMMY_CLIENT_TYPE,
+        DUMMY_CONSENSUS_STATE_TYPE_URL, DUMMY_HEADER_TYPE_URL,
+    };
+    use crate::ics03_connection::connection::State as ConnectionState;
+    use crate::ics03_connection::context::ConnectionReader;
     use crate::ics03_connection::msgs::conn_open_init::test_util::get_dummy_msg_conn_open_init;
     use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
     use crate::ics03_connection::msgs::conn_open_try::test_util::get_dummy_msg_conn_open_try;
     use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
     use crate::ics03_connection::msgs::ConnectionMsg;
-    use crate::ics24_host::identifier::ClientId;
-    use crate::ics26_routing::handler::dispatch;
+    use crate::ics04_channel::channel::{ChannelEnd, Counterparty, Order, State};
+    use crate::ics04_channel::msgs::chan_open_init::test_util::get_dummy_raw_msg_chan_open_init;
+    use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+    use crate::ics04_channel::msgs::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
+    use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+    use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+    use crate::ics04_channel::packet::test_utils::{
+        get_dummy_channel_end, get_dummy_connection_end,
+    };
+    use crate::ics04_channel::packet::{Packet, Sequence};
+    use crate::ics24_host::identifier::{ClientId, ConnectionId, PortId};
+    use crate::ics26_routing::error::{Error, Kind};
+    use crate::ics26_routing::handler::{deliver_tx, dispatch};
+    use crate::ics26_routing::module::Module;
     use crate::ics26_routing::msgs::ICS26Envelope;
     use crate::mock::client_state::{MockClientState, MockConsensusState};
     use crate::mock::context::MockContext;
     use crate::mock::header::MockHeader;
+    use crate::mock::module::MockModule;
     use crate::test_utils::get_dummy_account_id;
+    use crate::tx_msg::Msg;
     use crate::Height;
 
     #[test]
     // These tests exercise two main paths: (1) the ability of the ICS26 routing module to dispatch
     // messages to the correct module handler, and more importantly: (2) the ability of ICS handlers
-    // to work with the context and correctly store results (i.e., the ClientKeeper and
-    // ConnectionKeeper traits).
+    // to work with the context and correctly store results (i.e., the ClientKeeper,
+    // ConnectionKeeper, and ChannelKeeper traits).
     fn routing_module_and_keepers() {
         // Test parameters
         struct Test {
@@ -93,13 +294,14 @@ mod tests {
             msg: ICS26Envelope,
             want_pass: bool,
         }
-        let default_client_id = ClientId::from_str("client_id").unwrap();
+        // The host generates the identifiers of the client, connection and channel created below.
+        let default_client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let default_connection_id = ConnectionId::new(0);
         let default_signer = get_dummy_account_id();
         let start_client_height = Height::new(0, 42);
         let update_client_height = Height::new(0, 50);
 
         let create_client_msg = MsgCreateAnyClient::new(
-            ClientId::from_str("client_id").unwrap(),
             AnyClientState::from(MockClientState(MockHeader(start_client_height))),
             AnyConsensusState::from(MockConsensusState(MockHeader(start_client_height))),
             get_dummy_account_id(),
@@ -110,11 +312,38 @@ mod tests {
             MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init()).unwrap();
         let incorrect_msg_conn_try =
             MsgConnectionOpenTry::try_from(get_dummy_msg_conn_open_try(10, 34)).unwrap();
-        let msg_conn_try_good_height =
-            MsgConnectionOpenTry::try_from(get_dummy_msg_conn_open_try(10, 29)).unwrap();
+        // This message targets the connection end created upon the `MsgConnectionOpenInit` below.
+        let msg_conn_try_good_height = MsgConnectionOpenTry {
+            connection_id: default_connection_id.clone(),
+            counterparty_chosen_connection_id: Some(default_connection_id.clone()),
+            ..MsgConnectionOpenTry::try_from(get_dummy_msg_conn_open_try(10, 29)).unwrap()
+        };
+
+        let msg_chan_init =
+            MsgChannelOpenInit::try_from(get_dummy_raw_msg_chan_open_init()).unwrap();
+        // The same message, but the channel is built on top of the connection created above.
+        let msg_chan_init_good_hops = MsgChannelOpenInit {
+            channel: ChannelEnd::new(
+                State::Init,
+                *msg_chan_init.channel.ordering(),
+                msg_chan_init.channel.counterparty(),
+                vec![default_connection_id],
+                msg_chan_init.channel.version(),
+            ),
+            ..msg_chan_init.clone()
+        };
+
+        let msg_recv_packet = MsgRecvPacket::try_from(get_dummy_raw_msg_recv_packet(35)).unwrap();
+
+        // The same message, but on a port which no application is bound to.
+        let msg_chan_init_unbound_port = MsgChannelOpenInit {
+            port_id: PortId::from_str("unboundport").unwrap(),
+            ..msg_chan_init_good_hops.clone()
+        };
 
         // We reuse this same context across all tests. Nothing in particular needs parametrizing.
-        let mut ctx = MockContext::default();
+        let mut ctx =
+            MockContext::default().with_module(msg_chan_init.port_id.clone(), Arc::new(MockModule));
 
         let tests: Vec<Test> = vec![
             // Test the ICS2 client functionality.
@@ -171,6 +400,37 @@ mod tests {
                 ))),
                 want_pass: false,
             },
+            // Test the ICS4 channel functionality.
+            Test {
+                name: "Channel open init fails due to missing connection".to_string(),
+                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(msg_chan_init)),
+                want_pass: false,
+            },
+            Test {
+                name: "Channel open init fails due to no application bound to the port".to_string(),
+                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(
+                    msg_chan_init_unbound_port,
+                )),
+                want_pass: false,
+            },
+            Test {
+                name: "Channel open init success".to_string(),
+                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(
+                    msg_chan_init_good_hops.clone(),
+                )),
+                want_pass: true,
+            },
+            Test {
+                name: "Channel open init success again, with the next channel identifier"
+                    .to_string(),
+                msg: ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(msg_chan_init_good_hops)),
+                want_pass: true,
+            },
+            Test {
+                name: "Receive packet fails due to missing channel".to_string(),
+                msg: ICS26Envelope::ICS4PacketMsg(PacketMsg::RecvPacket(Box::new(msg_recv_packet))),
+                want_pass: false,
+            },
         ]
         .into_iter()
         .collect();
@@ -188,4 +448,212 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn deliver_tx_is_atomic() {
+        let client_id = ClientId::new(ClientType::Mock, 0).unwrap();
+        let start_client_height = Height::new(0, 42);
+        let update_client_height = Height::new(0, 50);
+
+        let tx_with = |messages: Vec<Any>| Tx {
+            body: Some(TxBody {
+                messages,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let create_client = MsgCreateAnyClient::new(
+            AnyClientState::from(MockClientState(MockHeader(start_client_height))),
+            AnyConsensusState::from(MockConsensusState(MockHeader(start_client_height))),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<MsgCreateClient>();
+        let update_client = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: MockHeader(update_client_height).into(),
+            signer: get_dummy_account_id(),
+        }
+        .to_any::<MsgUpdateClient>();
+        let conn_init = MsgConnectionOpenInit::try_from(get_dummy_msg_conn_open_init())
+            .unwrap()
+            .with_client_id(client_id.clone())
+            .to_any::<RawMsgConnectionOpenInit>();
+
+        let mut ctx = MockContext::default();
+
+        // All messages succeed, and the output gathers the events of every message.
+        let output = deliver_tx(
+            &mut ctx,
+            tx_with(vec![create_client, update_client.clone()]),
+        );
+        assert!(output.is_ok(), "{:?}", output);
+        assert_eq!(output.unwrap().events.len(), 2);
+
+        // The second update uses a stale header: the connection of the first message is not stored.
+        let output = deliver_tx(&mut ctx, tx_with(vec![conn_init.clone(), update_client]));
+        assert!(output.is_err());
+        assert!(ctx.connection_end(&ConnectionId::new(0)).is_none());
+
+        // Messages which cannot be decoded fail the transaction before any is processed.
+        let unknown = Any {
+            type_url: "/unknown.Msg".to_string(),
+            value: vec![],
+        };
+        let output = deliver_tx(&mut ctx, tx_with(vec![conn_init.clone(), unknown]));
+        assert!(output.is_err());
+        assert!(ctx.connection_end(&ConnectionId::new(0)).is_none());
+
+        assert!(deliver_tx(&mut ctx, tx_with(vec![conn_init])).is_ok());
+        assert!(ctx.connection_end(&ConnectionId::new(0)).is_some());
+    }
+
+    #[test]
+    fn deliver_tx_with_registered_client() {
+        let registry = dummy_registry();
+        let client_id = ClientId::with_prefix(DUMMY_CLIENT_TYPE, 0).unwrap();
+        let height = Height::new(1, 10);
+
+        let tx_with = |messages: Vec<Any>| Tx {
+            body: Some(TxBody {
+                messages,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let create_client = MsgCreateAnyClient::new(
+            registry
+                .decode_client_state(dummy_any(DUMMY_CLIENT_STATE_TYPE_URL, height))
+                .unwrap(),
+            registry
+                .decode_consensus_state(dummy_any(DUMMY_CONSENSUS_STATE_TYPE_URL, height))
+                .unwrap(),
+            get_dummy_account_id(),
+        )
+        .unwrap()
+        .to_any::<MsgCreateClient>();
+        let update_client = MsgUpdateAnyClient {
+            client_id: client_id.clone(),
+            header: registry
+                .decode_header(dummy_any(DUMMY_HEADER_TYPE_URL, height.increment()))
+                .unwrap(),
+            signer: get_dummy_account_id(),
+        }
+        .to_any::<MsgUpdateClient>();
+
+        // A host which does not support the client cannot decode the messages about it.
+        let mut ctx = MockContext::default();
+        let output = deliver_tx(&mut ctx, tx_with(vec![create_client.clone()]));
+        assert!(output.is_err());
+
+        let mut ctx = MockContext::default().with_client_registry(registry);
+        let output = deliver_tx(&mut ctx, tx_with(vec![create_client, update_client]));
+        assert!(output.is_ok(), "{:?}", output);
+
+        let client_state = ConnectionReader::client_state(&ctx, &client_id).unwrap();
+        assert_eq!(client_state.client_type(), ClientType::Registered);
+        assert_eq!(client_state.latest_height(), height.increment());
+    }
+
+    /// An application which rejects every packet it receives.
+    struct RejectingModule;
+
+    impl<Ctx> Module<Ctx> for RejectingModule {
+        fn on_recv_packet(
+            &self,
+            _ctx: &mut Ctx,
+            _packet: &Packet,
+        ) -> HandlerResult<Vec<u8>, Error> {
+            Err(Kind::ModuleCallbackFailed.into())
+        }
+
+        fn on_acknowledgement_packet(
+            &self,
+            _ctx: &mut Ctx,
+            _packet: &Packet,
+            _acknowledgement: &[u8],
+        ) -> HandlerResult<(), Error> {
+            Ok(HandlerOutput::builder().with_result(()))
+        }
+
+        fn on_timeout_packet(&self, _ctx: &mut Ctx, _packet: &Packet) -> HandlerResult<(), Error> {
+            Ok(HandlerOutput::builder().with_result(()))
+        }
+    }
+
+    #[test]
+    fn dispatch_stores_packets_after_the_application() {
+        use crate::ics04_channel::context::ChannelReader;
+
+        let client_id = ClientId::from_str("mock_clientid").unwrap();
+
+        let mut raw_msg = get_dummy_raw_msg_recv_packet(10);
+        if let Some(raw_packet) = raw_msg.packet.as_mut() {
+            raw_packet.sequence = 1;
+        }
+        let msg = MsgRecvPacket::try_from(raw_msg).unwrap();
+        let packet = msg.packet().clone();
+
+        let conn_end = get_dummy_connection_end(ConnectionState::Open, &client_id);
+        let chan_end = get_dummy_channel_end(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(
+                packet.source_port.clone(),
+                Some(packet.source_channel.clone()),
+            ),
+        );
+
+        // A context which is ready to receive the packet, but for the application on the port.
+        let ctx = MockContext::default()
+            .with_client(&client_id, Height::new(1, 10))
+            .with_connection(ConnectionId::default(), conn_end)
+            .with_channel(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                chan_end,
+            )
+            .with_sequences(
+                packet.destination_port.clone(),
+                packet.destination_channel.clone(),
+                Sequence::from(1),
+                Sequence::from(1),
+                Sequence::from(1),
+            );
+        let envelope = ICS26Envelope::ICS4PacketMsg(PacketMsg::RecvPacket(Box::new(msg)));
+
+        // The application rejects the packet: the host store keeps no receipt for it.
+        let mut rejecting_ctx = ctx
+            .clone()
+            .with_module(packet.destination_port.clone(), Arc::new(RejectingModule));
+        assert!(dispatch(&mut rejecting_ctx, envelope.clone()).is_err());
+        assert!(rejecting_ctx
+            .packet_receipt(
+                &packet.destination_port,
+                &packet.destination_channel,
+                packet.sequence
+            )
+            .is_none());
+
+        // The application accepts the packet: both the receipt and the acknowledgement are stored.
+        let mut accepting_ctx =
+            ctx.with_module(packet.destination_port.clone(), Arc::new(MockModule));
+        assert!(dispatch(&mut accepting_ctx, envelope).is_ok());
+        assert!(accepting_ctx
+            .packet_receipt(
+                &packet.destination_port,
+                &packet.destination_channel,
+                packet.sequence
+            )
+            .is_some());
+        assert!(accepting_ctx
+            .packet_acknowledgement(
+                &packet.destination_port,
+                &packet.destination_channel,
+                packet.sequence
+            )
+            .is_some());
+    }
 }
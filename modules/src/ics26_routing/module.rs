@@ -0,0 +1,94 @@
+//! The callbacks through which the ICS26 routing module hands channel handshakes and packets over
+//! to IBC applications (e.g., ICS20).
+
+use crate::handler::HandlerResult;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Packet;
+use crate::ics24_host::identifier::{ChannelId, PortId};
+use crate::ics26_routing::error::Error;
+
+/// An IBC application, bound to one or more ports. The routing module invokes these callbacks once
+/// the ICS4 handlers accepted a message on such a port; an error from a callback fails the message.
+/// Applications keep their own state in the context `Ctx`, which callbacks are free to modify.
+///
+/// By default, applications accept every step of the channel handshakes.
+pub trait Module<Ctx> {
+    /// Accepts (or rejects) a channel that the local chain starts opening.
+    fn on_chan_open_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _channel_end: &ChannelEnd,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Accepts (or rejects) a channel that the counterparty chain started opening.
+    fn on_chan_open_try(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _channel_end: &ChannelEnd,
+        _counterparty_version: &str,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Accepts (or rejects) the version that the counterparty chain chose for the channel.
+    fn on_chan_open_ack(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty_version: &str,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Notifies the application that the channel is open on both ends.
+    fn on_chan_open_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Accepts (or rejects) closing a channel, on the initiative of the local chain.
+    fn on_chan_close_init(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Notifies the application that the counterparty chain closed the channel.
+    fn on_chan_close_confirm(
+        &self,
+        _ctx: &mut Ctx,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Processes a received packet, and returns the acknowledgement that the routing module then
+    /// writes. An empty acknowledgement means the application will write it later on, by itself.
+    fn on_recv_packet(&self, ctx: &mut Ctx, packet: &Packet) -> HandlerResult<Vec<u8>, Error>;
+
+    /// Processes the acknowledgement of a packet which the application sent.
+    fn on_acknowledgement_packet(
+        &self,
+        ctx: &mut Ctx,
+        packet: &Packet,
+        acknowledgement: &[u8],
+    ) -> HandlerResult<(), Error>;
+
+    /// Processes a packet which the application sent, but which timed out.
+    fn on_timeout_packet(&self, ctx: &mut Ctx, packet: &Packet) -> HandlerResult<(), Error>;
+}
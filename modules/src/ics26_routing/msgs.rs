@@ -1,10 +1,168 @@
+use std::convert::TryFrom;
+
+use prost_types::Any;
+use tendermint_proto::Protobuf;
+
+use crate::ics02_client::msgs::create_client::MsgCreateAnyClient;
+use crate::ics02_client::msgs::misbehaviour::MsgSubmitAnyMisbehaviour;
+use crate::ics02_client::msgs::update_client::MsgUpdateAnyClient;
+use crate::ics02_client::msgs::upgrade_client::MsgUpgradeAnyClient;
 use crate::ics02_client::msgs::ClientMsg;
+use crate::ics02_client::registry::ClientRegistry;
+use crate::ics03_connection::msgs::conn_open_ack::MsgConnectionOpenAck;
+use crate::ics03_connection::msgs::conn_open_confirm::MsgConnectionOpenConfirm;
+use crate::ics03_connection::msgs::conn_open_init::MsgConnectionOpenInit;
+use crate::ics03_connection::msgs::conn_open_try::MsgConnectionOpenTry;
 use crate::ics03_connection::msgs::ConnectionMsg;
+use crate::ics04_channel::msgs::acknowledgement::MsgAcknowledgement;
+use crate::ics04_channel::msgs::chan_close_confirm::MsgChannelCloseConfirm;
+use crate::ics04_channel::msgs::chan_close_init::MsgChannelCloseInit;
+use crate::ics04_channel::msgs::chan_open_ack::MsgChannelOpenAck;
+use crate::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
+use crate::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
+use crate::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use crate::ics04_channel::msgs::recv_packet::MsgRecvPacket;
+use crate::ics04_channel::msgs::timeout::MsgTimeout;
+use crate::ics04_channel::msgs::timeout_on_close::MsgTimeoutOnClose;
+use crate::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use crate::ics26_routing::error::{Error, Kind};
 
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
 #[derive(Clone, Debug)]
 pub enum ICS26Envelope {
     ICS2Msg(ClientMsg),
     ICS3Msg(ConnectionMsg),
-    // ICS4Msg(ChannelMsg),
+    ICS4Msg(ChannelMsg),
+    ICS4PacketMsg(PacketMsg),
+}
+
+impl TryFrom<Any> for ICS26Envelope {
+    type Error = Error;
+
+    /// Decodes (and validates) a message from its `Any` encoding, as found in transactions. Only
+    /// the built-in clients are known: see `ICS26Envelope::decode` for registered clients.
+    fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
+        ICS26Envelope::decode(any_msg, &ClientRegistry::default())
+    }
+}
+
+impl ICS26Envelope {
+    /// Decodes (and validates) a message from its `Any` encoding, as found in transactions. The
+    /// client states, consensus states and headers of ICS2 messages may be those of any client in
+    /// `registry`, on top of the built-in ones.
+    pub fn decode(any_msg: Any, registry: &ClientRegistry) -> Result<Self, Error> {
+        let bytes = any_msg.value.as_slice();
+        let malformed = |e| Kind::MalformedMessageBytes.context(e);
+        let malformed_client_msg = |e| Kind::MalformedMessageBytes.context(e);
+
+        let envelope = match any_msg.type_url.as_str() {
+            // ICS2 messages
+            "/ibc.core.client.v1.MsgCreateClient" => {
+                ICS26Envelope::ICS2Msg(ClientMsg::CreateClient(
+                    MsgCreateAnyClient::try_from_raw(decode_raw(bytes)?, registry)
+                        .map_err(malformed_client_msg)?,
+                ))
+            }
+            "/ibc.core.client.v1.MsgUpdateClient" => {
+                ICS26Envelope::ICS2Msg(ClientMsg::UpdateClient(
+                    MsgUpdateAnyClient::try_from_raw(decode_raw(bytes)?, registry)
+                        .map_err(malformed_client_msg)?,
+                ))
+            }
+            "/ibc.core.client.v1.MsgUpgradeClient" => {
+                ICS26Envelope::ICS2Msg(ClientMsg::UpgradeClient(
+                    MsgUpgradeAnyClient::try_from_raw(decode_raw(bytes)?, registry)
+                        .map_err(malformed_client_msg)?,
+                ))
+            }
+            "/ibc.core.client.v1.MsgSubmitMisbehaviour" => {
+                ICS26Envelope::ICS2Msg(ClientMsg::Misbehaviour(
+                    MsgSubmitAnyMisbehaviour::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+
+            // ICS3 messages
+            "/ibc.core.connection.v1.MsgConnectionOpenInit" => {
+                ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenInit(
+                    MsgConnectionOpenInit::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+            "/ibc.core.connection.v1.MsgConnectionOpenTry" => {
+                ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenTry(Box::new(
+                    MsgConnectionOpenTry::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+            "/ibc.core.connection.v1.MsgConnectionOpenAck" => {
+                ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenAck(Box::new(
+                    MsgConnectionOpenAck::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+            "/ibc.core.connection.v1.MsgConnectionOpenConfirm" => {
+                ICS26Envelope::ICS3Msg(ConnectionMsg::ConnectionOpenConfirm(
+                    MsgConnectionOpenConfirm::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+
+            // ICS4 channel messages
+            "/ibc.core.channel.v1.MsgChannelOpenInit" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenInit(
+                    MsgChannelOpenInit::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+            "/ibc.core.channel.v1.MsgChannelOpenTry" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenTry(Box::new(
+                    MsgChannelOpenTry::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+            "/ibc.core.channel.v1.MsgChannelOpenAck" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenAck(
+                    MsgChannelOpenAck::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+            "/ibc.core.channel.v1.MsgChannelOpenConfirm" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelOpenConfirm(
+                    MsgChannelOpenConfirm::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+            "/ibc.core.channel.v1.MsgChannelCloseInit" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelCloseInit(
+                    MsgChannelCloseInit::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+            "/ibc.core.channel.v1.MsgChannelCloseConfirm" => {
+                ICS26Envelope::ICS4Msg(ChannelMsg::ChannelCloseConfirm(
+                    MsgChannelCloseConfirm::decode_vec(bytes).map_err(malformed)?,
+                ))
+            }
+
+            // ICS4 packet messages
+            "/ibc.core.channel.v1.MsgRecvPacket" => {
+                ICS26Envelope::ICS4PacketMsg(PacketMsg::RecvPacket(Box::new(
+                    MsgRecvPacket::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+            "/ibc.core.channel.v1.MsgAcknowledgement" => {
+                ICS26Envelope::ICS4PacketMsg(PacketMsg::Acknowledgement(Box::new(
+                    MsgAcknowledgement::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+            "/ibc.core.channel.v1.MsgTimeout" => ICS26Envelope::ICS4PacketMsg(PacketMsg::Timeout(
+                Box::new(MsgTimeout::decode_vec(bytes).map_err(malformed)?),
+            )),
+            "/ibc.core.channel.v1.MsgTimeoutOnClose" => {
+                ICS26Envelope::ICS4PacketMsg(PacketMsg::TimeoutOnClose(Box::new(
+                    MsgTimeoutOnClose::decode_vec(bytes).map_err(malformed)?,
+                )))
+            }
+
+            _ => return Err(Kind::UnknownMessageTypeUrl(any_msg.type_url).into()),
+        };
+
+        Ok(envelope)
+    }
+}
+
+/// Decodes the raw (i.e., protobuf) message encoded in `bytes`.
+fn decode_raw<M: prost::Message + Default>(bytes: &[u8]) -> Result<M, Error> {
+    M::decode(bytes).map_err(|e| Kind::MalformedMessageBytes.context(e).into())
 }
@@ -0,0 +1,80 @@
+//! The router which associates ports to the IBC applications bound to them.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::ics05_port::capabilities::Capability;
+use crate::ics05_port::context::PortKeeper;
+use crate::ics24_host::identifier::PortId;
+use crate::ics26_routing::error::{Error, Kind};
+use crate::ics26_routing::module::Module;
+
+/// The IBC applications of a host chain, indexed by the ports they are bound to. An application may
+/// be bound to several ports, hence the shared pointers. Along with each application, the router
+/// holds the capability issued when its port was bound, i.e., the proof that it owns the port.
+pub struct Router<Ctx> {
+    routes: HashMap<PortId, (Arc<dyn Module<Ctx>>, Capability)>,
+}
+
+impl<Ctx> Router<Ctx> {
+    pub fn new() -> Self {
+        Self {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Binds the application `module` to the given port, which must be free: the port is bound
+    /// through `keeper`, and the capability issued for it is kept on behalf of the application.
+    pub fn add_route(
+        &mut self,
+        keeper: &mut dyn PortKeeper,
+        port_id: PortId,
+        module: Arc<dyn Module<Ctx>>,
+    ) -> Result<Capability, Error> {
+        if self.routes.contains_key(&port_id) {
+            return Err(Kind::RouteAlreadyExists(port_id).into());
+        }
+        let capability = keeper
+            .bind_port(&port_id)
+            .map_err(|e| Kind::PortBindingFailed(port_id.clone()).context(e))?;
+        self.routes.insert(port_id, (module, capability));
+        Ok(capability)
+    }
+
+    /// Returns the application bound to the given port, if any.
+    pub fn get_route(&self, port_id: &PortId) -> Option<Arc<dyn Module<Ctx>>> {
+        self.routes.get(port_id).map(|(module, _)| module.clone())
+    }
+
+    /// Returns the capability held by the application bound to the given port, if any.
+    pub fn get_capability(&self, port_id: &PortId) -> Option<Capability> {
+        self.routes.get(port_id).map(|(_, capability)| *capability)
+    }
+
+    pub fn has_route(&self, port_id: &PortId) -> bool {
+        self.routes.contains_key(port_id)
+    }
+}
+
+impl<Ctx> Default for Router<Ctx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Ctx> Clone for Router<Ctx> {
+    fn clone(&self) -> Self {
+        Self {
+            routes: self.routes.clone(),
+        }
+    }
+}
+
+impl<Ctx> fmt::Debug for Router<Ctx> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router")
+            .field("ports", &self.routes.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
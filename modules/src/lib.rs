@@ -15,7 +15,10 @@
 //! - ICS 02: Client
 //! - ICS 03: Connection
 //! - ICS 04: Channel
+//! - ICS 05: Port
+//! - ICS 06: Solo Machine Client
 //! - ICS 07: Tendermint Client
+//! - ICS 09: Loopback Client
 //! - ICS 18: Basic relayer functions
 //! - ICS 20: Fungible Token
 //! - ICS 23: Vector Commitment Scheme
@@ -27,7 +30,10 @@ pub mod handler;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod ics05_port;
+pub mod ics06_solomachine;
 pub mod ics07_tendermint;
+pub mod ics09_localhost;
 pub mod ics18_relayer;
 pub mod ics20_fungible_token_transfer;
 pub mod ics23_commitment;
@@ -36,6 +42,7 @@ pub mod ics26_routing;
 pub mod keys;
 pub mod macros;
 pub mod proofs;
+pub mod timestamp;
 pub mod tx_msg;
 
 /// Re-export of ICS 002 Height domain type
@@ -0,0 +1,120 @@
+//! An in-memory bank, which holds the balances of the accounts of a mock host chain.
+
+use std::collections::HashMap;
+
+use tendermint::account::Id as AccountId;
+
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::context::{BankKeeper, BankReader};
+use crate::ics20_fungible_token_transfer::error::{Error, Kind};
+
+#[derive(Clone, Debug, Default)]
+pub struct MockBank {
+    /// The balances of all accounts, indexed by (account, denomination).
+    balances: HashMap<(AccountId, String), u64>,
+}
+
+impl MockBank {
+    /// Adds the given tokens to the balance of the account.
+    pub fn with_balance(self, account: AccountId, coin: Coin) -> Self {
+        let mut balances = self.balances.clone();
+        *balances.entry((account, coin.denom)).or_insert(0) += coin.amount;
+        Self { balances }
+    }
+}
+
+impl BankReader for MockBank {
+    fn balance(&self, account: &AccountId, denom: &str) -> u64 {
+        self.balances
+            .get(&(*account, denom.to_string()))
+            .cloned()
+            .unwrap_or(0)
+    }
+}
+
+impl BankKeeper for MockBank {
+    fn send_coins(&mut self, from: &AccountId, to: &AccountId, coin: &Coin) -> Result<(), Error> {
+        // Check both sides before moving anything, so that a failed transfer loses no tokens.
+        let from_balance = self.balance(from, &coin.denom);
+        if from_balance < coin.amount {
+            return Err(Kind::InsufficientFunds(*from, coin.clone()).into());
+        }
+        if from != to {
+            self.balance(to, &coin.denom)
+                .checked_add(coin.amount)
+                .ok_or_else(|| overflow_error(coin))?;
+        }
+
+        self.burn_coins(from, coin)?;
+        self.mint_coins(to, coin)
+    }
+
+    fn mint_coins(&mut self, account: &AccountId, coin: &Coin) -> Result<(), Error> {
+        let balance = self
+            .balances
+            .entry((*account, coin.denom.clone()))
+            .or_insert(0);
+        *balance = balance
+            .checked_add(coin.amount)
+            .ok_or_else(|| overflow_error(coin))?;
+        Ok(())
+    }
+
+    fn burn_coins(&mut self, account: &AccountId, coin: &Coin) -> Result<(), Error> {
+        let balance = self.balance(account, &coin.denom);
+        if balance < coin.amount {
+            return Err(Kind::InsufficientFunds(*account, coin.clone()).into());
+        }
+        self.balances
+            .insert((*account, coin.denom.clone()), balance - coin.amount);
+        Ok(())
+    }
+}
+
+fn overflow_error(coin: &Coin) -> Error {
+    Kind::TokenTransferFailure
+        .context(format!("balance overflow for {}", coin.denom))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::address::string_to_account;
+    use crate::ics20_fungible_token_transfer::coin::Coin;
+    use crate::ics20_fungible_token_transfer::context::{BankKeeper, BankReader};
+    use crate::mock::bank::MockBank;
+    use crate::test_utils::{get_dummy_account_id, get_dummy_bech32_account};
+
+    #[test]
+    fn mock_bank_transfers() {
+        let alice = get_dummy_account_id();
+        let bob = string_to_account(get_dummy_bech32_account()).unwrap();
+        let atoms = |amount| Coin::new("atom".to_string(), amount);
+
+        let mut bank = MockBank::default().with_balance(alice, atoms(100));
+        assert_eq!(bank.balance(&alice, "atom"), 100);
+        assert_eq!(bank.balance(&bob, "atom"), 0);
+
+        bank.send_coins(&alice, &bob, &atoms(40)).unwrap();
+        assert_eq!(bank.balance(&alice, "atom"), 60);
+        assert_eq!(bank.balance(&bob, "atom"), 40);
+
+        // Failed transfers leave the balances untouched.
+        assert!(bank.send_coins(&bob, &alice, &atoms(41)).is_err());
+        assert_eq!(bank.balance(&alice, "atom"), 60);
+        assert_eq!(bank.balance(&bob, "atom"), 40);
+
+        bank.mint_coins(&bob, &atoms(10)).unwrap();
+        bank.burn_coins(&alice, &atoms(60)).unwrap();
+        assert_eq!(bank.balance(&alice, "atom"), 0);
+        assert_eq!(bank.balance(&bob, "atom"), 50);
+        assert!(bank.burn_coins(&alice, &atoms(1)).is_err());
+        assert!(bank.mint_coins(&bob, &atoms(u64::MAX)).is_err());
+
+        // Transfers which would overflow the balance of the receiver do not burn the tokens sent.
+        let mut bank = bank.with_balance(alice, atoms(u64::MAX));
+        assert!(bank.send_coins(&bob, &alice, &atoms(50)).is_err());
+        assert_eq!(bank.balance(&alice, "atom"), u64::MAX);
+        assert_eq!(bank.balance(&bob, "atom"), 50);
+    }
+}
@@ -1,11 +1,15 @@
 use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, ClientDef};
+use crate::ics02_client::context::ClientReader;
 use crate::ics03_connection::connection::ConnectionEnd;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::packet::Sequence;
 use crate::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof, CommitmentRoot};
 use crate::ics23_commitment::merkle::apply_prefix;
-use crate::ics24_host::identifier::{ClientId, ConnectionId};
+use crate::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use crate::ics24_host::Path;
 use crate::mock::client_state::{MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
+use crate::mock::misbehaviour::MockMisbehaviour;
 use crate::Height;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -13,11 +17,14 @@ pub struct MockClient;
 
 impl ClientDef for MockClient {
     type Header = MockHeader;
+    type Misbehaviour = MockMisbehaviour;
     type ClientState = MockClientState;
     type ConsensusState = MockConsensusState;
 
     fn check_header_and_update_state(
         &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
         client_state: Self::ClientState,
         header: Self::Header,
     ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
@@ -30,10 +37,44 @@ impl ClientDef for MockClient {
         Ok((MockClientState(header), MockConsensusState(header)))
     }
 
+    fn check_misbehaviour_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        _client_state: Self::ClientState,
+        _misbehaviour: Self::Misbehaviour,
+    ) -> Result<Self::ClientState, Box<dyn std::error::Error>> {
+        // TODO: `MockClientState` cannot be frozen yet.
+        Err("mock clients cannot be frozen".into())
+    }
+
+    fn verify_upgrade_and_update_state(
+        &self,
+        _ctx: &dyn ClientReader,
+        _client_id: ClientId,
+        client_state: Self::ClientState,
+        upgraded_client_state: Self::ClientState,
+        _upgrade_height: Height,
+        _proof_upgrade: &CommitmentProof,
+    ) -> Result<(Self::ClientState, Self::ConsensusState), Box<dyn std::error::Error>> {
+        if client_state.latest_height() >= upgraded_client_state.latest_height() {
+            return Err(
+                "upgraded client height is lower than (or equal to) client latest height".into(),
+            );
+        }
+
+        Ok((
+            upgraded_client_state,
+            MockConsensusState(upgraded_client_state.0),
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn verify_client_consensus_state(
         &self,
         _client_state: &Self::ClientState,
         height: Height,
+        _root: &CommitmentRoot,
         prefix: &CommitmentPrefix,
         _proof: &CommitmentProof,
         client_id: &ClientId,
@@ -49,18 +90,16 @@ impl ClientDef for MockClient {
 
         let _path = apply_prefix(prefix, client_prefixed_path)?;
 
-        // TODO - add ctx to all client verification functions
-        // let cs = ctx.fetch_self_consensus_state(height);
-        // TODO - implement this
-        // proof.verify_membership(cs.root(), path, expected_consensus_state)
-
+        // Mock proofs carry no commitment, hence there is nothing to verify against the root.
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn verify_connection_state(
         &self,
         _client_state: &Self::ClientState,
         _height: Height,
+        _root: &CommitmentRoot,
         _prefix: &CommitmentPrefix,
         _proof: &CommitmentProof,
         _connection_id: &ConnectionId,
@@ -69,6 +108,84 @@ impl ClientDef for MockClient {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn verify_channel_state(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _expected_channel_end: &ChannelEnd,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_data(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_acknowledgement(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+        _ack_commitment: &[u8],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_next_sequence_recv(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _next_sequence_recv: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_packet_receipt_absence(
+        &self,
+        _client_state: &Self::ClientState,
+        _height: Height,
+        _root: &CommitmentRoot,
+        _prefix: &CommitmentPrefix,
+        _proof: &CommitmentProof,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _sequence: Sequence,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn verify_client_full_state(
         &self,
         _client_state: &Self::ClientState,
@@ -13,6 +13,7 @@ use crate::ics02_client::error::Kind;
 use crate::ics02_client::state::{ClientState, ConsensusState};
 use crate::ics23_commitment::commitment::CommitmentRoot;
 use crate::mock::header::MockHeader;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 /// A mock of an IBC client record as it is stored in a mock context.
@@ -96,6 +97,9 @@ impl From<MockConsensusState> for MockClientState {
     }
 }
 
+/// Mock chains commit to no store, hence all mock consensus states share an empty root.
+static MOCK_COMMITMENT_ROOT: CommitmentRoot = CommitmentRoot(Vec::new());
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct MockConsensusState(pub MockHeader);
 
@@ -135,7 +139,11 @@ impl ConsensusState for MockConsensusState {
     }
 
     fn root(&self) -> &CommitmentRoot {
-        todo!()
+        &MOCK_COMMITMENT_ROOT
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::none()
     }
 
     fn validate_basic(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -7,28 +7,53 @@ use crate::ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHead
 use crate::ics02_client::client_type::ClientType;
 use crate::ics02_client::context::{ClientKeeper, ClientReader};
 use crate::ics02_client::error::Error as ICS2Error;
+use crate::ics02_client::registry::ClientRegistry;
 use crate::ics03_connection::connection::ConnectionEnd;
 use crate::ics03_connection::context::{ConnectionKeeper, ConnectionReader};
 use crate::ics03_connection::error::Error as ICS3Error;
+use crate::ics04_channel::channel::ChannelEnd;
+use crate::ics04_channel::context::{ChannelKeeper, ChannelReader};
+use crate::ics04_channel::error::Error as ICS4Error;
+use crate::ics04_channel::packet::{Receipt, Sequence};
+use crate::ics05_port::capabilities::Capability;
+use crate::ics05_port::context::{PortKeeper, PortReader};
+use crate::ics05_port::error::{Error as ICS5Error, Kind as ICS5ErrorKind};
 use crate::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+use crate::ics09_localhost::client_state::ClientState as LocalhostClientState;
+use crate::ics09_localhost::consensus_state::ConsensusState as LocalhostConsensusState;
 use crate::ics18_relayer::context::ICS18Context;
 use crate::ics18_relayer::error::{Error as ICS18Error, Kind as ICS18ErrorKind};
+use crate::ics20_fungible_token_transfer::coin::Coin;
+use crate::ics20_fungible_token_transfer::context::{
+    BankKeeper, BankReader, ICS20Keeper, ICS20Reader,
+};
+use crate::ics20_fungible_token_transfer::denom::DenomTrace;
+use crate::ics20_fungible_token_transfer::error::Error as ICS20Error;
 use crate::ics23_commitment::commitment::CommitmentPrefix;
-use crate::ics24_host::identifier::{ChainId, ClientId, ConnectionId};
+use crate::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
 use crate::ics26_routing::context::ICS26Context;
 use crate::ics26_routing::handler::dispatch;
+use crate::ics26_routing::module::Module;
 use crate::ics26_routing::msgs::ICS26Envelope;
+use crate::ics26_routing::router::Router;
+use crate::mock::bank::MockBank;
 use crate::mock::client_state::{MockClientRecord, MockClientState, MockConsensusState};
 use crate::mock::header::MockHeader;
 use crate::mock::host::{HostBlock, HostType};
+use crate::timestamp::Timestamp;
 use crate::Height;
 
 use std::cmp::min;
 use std::collections::HashMap;
 use std::error::Error;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 use tendermint::account::Id;
 
+/// The maximum time in seconds which the mock host chain expects between two blocks.
+const DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK: u64 = 30;
+
 /// A context implementing the dependencies necessary for testing any IBC module.
 #[derive(Clone, Debug)]
 pub struct MockContext {
@@ -51,11 +76,63 @@ pub struct MockContext {
     /// The set of all clients, indexed by their id.
     clients: HashMap<ClientId, MockClientRecord>,
 
+    /// Counter for the client identifiers, used by the handler which creates clients.
+    client_ids_counter: u64,
+
+    /// The time and height of the host chain at which each client got its consensus state at
+    /// each height, indexed by (client id, consensus state height).
+    client_processed_times: HashMap<(ClientId, Height), Timestamp>,
+    client_processed_heights: HashMap<(ClientId, Height), Height>,
+
+    /// The maximum time the host chain expects between two blocks.
+    max_expected_time_per_block: Duration,
+
     /// Association between client ids and connection ids.
     client_connections: HashMap<ClientId, ConnectionId>,
 
     /// All the connections in the store.
     connections: HashMap<ConnectionId, ConnectionEnd>,
+
+    /// Counter for connection identifiers (see `increase_connection_counter`).
+    connection_ids_counter: u64,
+
+    /// Association between connection ids and the (port id, channel id) pairs built on top.
+    connection_channels: HashMap<ConnectionId, Vec<(PortId, ChannelId)>>,
+
+    /// All the channels in the store, indexed by their (port id, channel id) pair.
+    channels: HashMap<(PortId, ChannelId), ChannelEnd>,
+
+    /// Counter for channel identifiers (see `increase_channel_counter`).
+    channel_ids_counter: u64,
+
+    /// The next sequence numbers to send, receive and acknowledge on each channel end.
+    next_sequence_send: HashMap<(PortId, ChannelId), Sequence>,
+    next_sequence_recv: HashMap<(PortId, ChannelId), Sequence>,
+    next_sequence_ack: HashMap<(PortId, ChannelId), Sequence>,
+
+    /// Commitments to the packets sent, indexed by (port id, channel id, sequence).
+    packet_commitments: HashMap<(PortId, ChannelId, Sequence), Vec<u8>>,
+
+    /// Receipts for the packets received on unordered channels.
+    packet_receipts: HashMap<(PortId, ChannelId, Sequence), Receipt>,
+
+    /// Commitments to the acknowledgements written for the packets received.
+    packet_acknowledgements: HashMap<(PortId, ChannelId, Sequence), Vec<u8>>,
+
+    /// The traces of ICS20 vouchers, indexed by their hash.
+    denom_traces: HashMap<Vec<u8>, DenomTrace>,
+
+    /// The balances of the accounts of the host chain.
+    bank: MockBank,
+
+    /// The IBC applications of the host chain, indexed by the ports they are bound to.
+    router: Router<MockContext>,
+
+    /// The capabilities issued for the bound ports.
+    port_capabilities: HashMap<PortId, Capability>,
+
+    /// The light clients supported on top of the built-in ones.
+    client_registry: ClientRegistry,
 }
 
 /// Returns a MockContext with bare minimum initialization: no clients, no connections are
@@ -115,8 +192,27 @@ impl MockContext {
                 })
                 .collect(),
             connections: Default::default(),
+            connection_ids_counter: 0,
             clients: Default::default(),
+            client_ids_counter: 0,
+            client_processed_times: Default::default(),
+            client_processed_heights: Default::default(),
+            max_expected_time_per_block: Duration::from_secs(DEFAULT_MAX_EXPECTED_TIME_PER_BLOCK),
             client_connections: Default::default(),
+            connection_channels: Default::default(),
+            channels: Default::default(),
+            channel_ids_counter: 0,
+            next_sequence_send: Default::default(),
+            next_sequence_recv: Default::default(),
+            next_sequence_ack: Default::default(),
+            packet_commitments: Default::default(),
+            packet_receipts: Default::default(),
+            packet_acknowledgements: Default::default(),
+            denom_traces: Default::default(),
+            bank: Default::default(),
+            router: Default::default(),
+            port_capabilities: Default::default(),
+            client_registry: Default::default(),
         }
     }
 
@@ -139,8 +235,31 @@ impl MockContext {
         client_state_height: Height,
         client_type: Option<ClientType>,
         consensus_state_height: Option<Height>,
+    ) -> Self {
+        let client_chain_id = self.host_chain_id.clone();
+        self.with_client_parametrized_with_chain_id(
+            client_id,
+            client_state_height,
+            client_type,
+            consensus_state_height,
+            client_chain_id,
+        )
+    }
+
+    /// Similar to `with_client_parametrized`, but the client tracks the chain `client_chain_id`
+    /// instead of this context's own chain. This matters for Tendermint clients, which only accept
+    /// headers from the chain they track.
+    pub fn with_client_parametrized_with_chain_id(
+        self,
+        client_id: &ClientId,
+        client_state_height: Height,
+        client_type: Option<ClientType>,
+        consensus_state_height: Option<Height>,
+        client_chain_id: ChainId,
     ) -> Self {
         let mut clients = self.clients.clone();
+        let mut client_processed_times = self.client_processed_times.clone();
+        let mut client_processed_heights = self.client_processed_heights.clone();
         let cs_height = consensus_state_height.unwrap_or(client_state_height);
 
         let client_type = client_type.unwrap_or(ClientType::Mock);
@@ -152,10 +271,8 @@ impl MockContext {
             ),
             // If it's a Tendermint client, we need TM states.
             ClientType::Tendermint => {
-                let light_block = HostBlock::generate_tm_block(
-                    self.host_chain_id.clone(),
-                    cs_height.version_height,
-                );
+                let light_block =
+                    HostBlock::generate_tm_block(client_chain_id, cs_height.version_height);
                 let consensus_state = AnyConsensusState::from(light_block.clone());
                 let client_state =
                     get_dummy_tendermint_client_state(light_block.signed_header.header);
@@ -163,6 +280,17 @@ impl MockContext {
                 // Return the tuple.
                 (Some(client_state), consensus_state)
             }
+            // Solo machines have no blocks from which the context could build their states.
+            ClientType::SoloMachine => panic!("the mock context has no solo machine clients"),
+            ClientType::Registered => panic!("the mock context has no registered clients"),
+            // A loopback client tracks the host chain itself.
+            ClientType::Localhost => (
+                Some(AnyClientState::Localhost(LocalhostClientState::new(
+                    client_chain_id.to_string(),
+                    client_state_height,
+                ))),
+                AnyConsensusState::Localhost(LocalhostConsensusState),
+            ),
         };
         let consensus_states = vec![(cs_height, consensus_state)].into_iter().collect();
 
@@ -173,7 +301,16 @@ impl MockContext {
         };
         clients.insert(client_id.clone(), client_record);
 
-        Self { clients, ..self }
+        // The client got its consensus state just now, at the latest height of the host chain.
+        client_processed_times.insert((client_id.clone(), cs_height), self.host_timestamp());
+        client_processed_heights.insert((client_id.clone(), cs_height), self.latest_height);
+
+        Self {
+            clients,
+            client_processed_times,
+            client_processed_heights,
+            ..self
+        }
     }
 
     /// Associates a connection to this context.
@@ -190,6 +327,114 @@ impl MockContext {
         }
     }
 
+    /// Associates a channel to this context.
+    pub fn with_channel(
+        self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        channel_end: ChannelEnd,
+    ) -> Self {
+        let mut channels = self.channels.clone();
+        channels.insert((port_id, channel_id), channel_end);
+        Self { channels, ..self }
+    }
+
+    /// Sets the next sequence numbers to send, receive and acknowledge on a channel end.
+    pub fn with_sequences(
+        self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        send: Sequence,
+        recv: Sequence,
+        ack: Sequence,
+    ) -> Self {
+        let key = (port_id, channel_id);
+        let mut next_sequence_send = self.next_sequence_send.clone();
+        next_sequence_send.insert(key.clone(), send);
+        let mut next_sequence_recv = self.next_sequence_recv.clone();
+        next_sequence_recv.insert(key.clone(), recv);
+        let mut next_sequence_ack = self.next_sequence_ack.clone();
+        next_sequence_ack.insert(key, ack);
+        Self {
+            next_sequence_send,
+            next_sequence_recv,
+            next_sequence_ack,
+            ..self
+        }
+    }
+
+    /// Associates a packet commitment to this context.
+    pub fn with_packet_commitment(
+        self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        seq: Sequence,
+        commitment: Vec<u8>,
+    ) -> Self {
+        let mut packet_commitments = self.packet_commitments.clone();
+        packet_commitments.insert((port_id, channel_id, seq), commitment);
+        Self {
+            packet_commitments,
+            ..self
+        }
+    }
+
+    /// Associates a packet receipt to this context.
+    pub fn with_packet_receipt(
+        self,
+        port_id: PortId,
+        channel_id: ChannelId,
+        seq: Sequence,
+        receipt: Receipt,
+    ) -> Self {
+        let mut packet_receipts = self.packet_receipts.clone();
+        packet_receipts.insert((port_id, channel_id, seq), receipt);
+        Self {
+            packet_receipts,
+            ..self
+        }
+    }
+
+    /// Associates the trace of ICS20 vouchers to this context.
+    pub fn with_denom_trace(self, denom_trace: DenomTrace) -> Self {
+        let mut denom_traces = self.denom_traces.clone();
+        denom_traces.insert(denom_trace.hash(), denom_trace);
+        Self {
+            denom_traces,
+            ..self
+        }
+    }
+
+    /// Adds the given tokens to the balance of an account of the host chain.
+    pub fn with_balance(self, account: Id, coin: Coin) -> Self {
+        let bank = self.bank.clone().with_balance(account, coin);
+        Self { bank, ..self }
+    }
+
+    /// Binds the given port of this context, i.e., issues a capability for it.
+    pub fn with_port_capability(self, port_id: PortId) -> Self {
+        let mut ctx = self;
+        ctx.bind_port(&port_id).unwrap();
+        ctx
+    }
+
+    /// Binds an IBC application to the given port of this context. The application holds the
+    /// capability for the port.
+    pub fn with_module(self, port_id: PortId, module: Arc<dyn Module<MockContext>>) -> Self {
+        let mut ctx = self;
+        let mut router = ctx.router.clone();
+        router.add_route(&mut ctx, port_id, module).unwrap();
+        Self { router, ..ctx }
+    }
+
+    /// Supports the light clients of `client_registry` in this context, on top of the built-in ones.
+    pub fn with_client_registry(self, client_registry: ClientRegistry) -> Self {
+        Self {
+            client_registry,
+            ..self
+        }
+    }
+
     /// Accessor for a block of the local (host) chain from this context.
     /// Returns `None` if the block at the requested height does not exist.
     fn host_block(&self, target_height: Height) -> Option<&HostBlock> {
@@ -262,7 +507,36 @@ impl MockContext {
     }
 }
 
-impl ICS26Context for MockContext {}
+impl ICS26Context for MockContext {
+    fn router(&self) -> &Router<Self> {
+        &self.router
+    }
+
+    fn client_registry(&self) -> &ClientRegistry {
+        &self.client_registry
+    }
+}
+
+impl PortReader for MockContext {
+    fn lookup_module_by_port(&self, port_id: &PortId) -> Option<Capability> {
+        self.port_capabilities.get(port_id).cloned()
+    }
+
+    fn authenticate(&self, capability: &Capability, port_id: &PortId) -> bool {
+        self.port_capabilities.get(port_id) == Some(capability)
+    }
+}
+
+impl PortKeeper for MockContext {
+    fn bind_port(&mut self, port_id: &PortId) -> Result<Capability, ICS5Error> {
+        if self.port_capabilities.contains_key(port_id) {
+            return Err(ICS5ErrorKind::PortAlreadyBound(port_id.clone()).into());
+        }
+        let capability = Capability::new(self.port_capabilities.len() as u64);
+        self.port_capabilities.insert(port_id.clone(), capability);
+        Ok(capability)
+    }
+}
 
 impl ConnectionReader for MockContext {
     fn connection_end(&self, cid: &ConnectionId) -> Option<ConnectionEnd> {
@@ -300,6 +574,10 @@ impl ConnectionReader for MockContext {
         let block_ref = self.host_block(height);
         block_ref.cloned().map(Into::into)
     }
+
+    fn connection_counter(&self) -> u64 {
+        self.connection_ids_counter
+    }
 }
 
 impl ConnectionKeeper for MockContext {
@@ -322,6 +600,254 @@ impl ConnectionKeeper for MockContext {
             .insert(client_id.clone(), connection_id.clone());
         Ok(())
     }
+
+    fn increase_connection_counter(&mut self) {
+        self.connection_ids_counter += 1;
+    }
+}
+
+impl ChannelReader for MockContext {
+    fn channel_end(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<ChannelEnd> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn connection_end(&self, cid: &ConnectionId) -> Option<ConnectionEnd> {
+        // Forward method call to the ICS3 Connection-specific method.
+        ConnectionReader::connection_end(self, cid)
+    }
+
+    fn client_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: Height,
+    ) -> Option<AnyConsensusState> {
+        // Forward method call to the ICS2 Client-specific method.
+        self.consensus_state(client_id, height)
+    }
+
+    fn host_current_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn client_update_time(&self, client_id: &ClientId, height: Height) -> Option<Timestamp> {
+        self.client_processed_times
+            .get(&(client_id.clone(), height))
+            .cloned()
+    }
+
+    fn client_update_height(&self, client_id: &ClientId, height: Height) -> Option<Height> {
+        self.client_processed_heights
+            .get(&(client_id.clone(), height))
+            .cloned()
+    }
+
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.max_expected_time_per_block
+    }
+
+    fn next_sequence_send(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence> {
+        self.next_sequence_send
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn next_sequence_recv(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence> {
+        self.next_sequence_recv
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn next_sequence_ack(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<Sequence> {
+        self.next_sequence_ack
+            .get(&(port_id.clone(), channel_id.clone()))
+            .cloned()
+    }
+
+    fn packet_commitment(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Vec<u8>> {
+        self.packet_commitments
+            .get(&(port_id.clone(), channel_id.clone(), seq))
+            .cloned()
+    }
+
+    fn packet_receipt(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Receipt> {
+        self.packet_receipts
+            .get(&(port_id.clone(), channel_id.clone(), seq))
+            .cloned()
+    }
+
+    fn packet_acknowledgement(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Option<Vec<u8>> {
+        self.packet_acknowledgements
+            .get(&(port_id.clone(), channel_id.clone(), seq))
+            .cloned()
+    }
+
+    fn channel_counter(&self) -> u64 {
+        self.channel_ids_counter
+    }
+}
+
+impl ChannelKeeper for MockContext {
+    fn store_channel(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        channel_end: &ChannelEnd,
+    ) -> Result<(), ICS4Error> {
+        self.channels
+            .insert((port_id.clone(), channel_id.clone()), channel_end.clone());
+        Ok(())
+    }
+
+    fn store_connection_channels(
+        &mut self,
+        connection_id: &ConnectionId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(), ICS4Error> {
+        let port_channel = (port_id.clone(), channel_id.clone());
+        let channels = self
+            .connection_channels
+            .entry(connection_id.clone())
+            .or_insert_with(Vec::new);
+        if !channels.contains(&port_channel) {
+            channels.push(port_channel);
+        }
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        self.next_sequence_send
+            .insert((port_id.clone(), channel_id.clone()), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        self.next_sequence_recv
+            .insert((port_id.clone(), channel_id.clone()), seq);
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        self.next_sequence_ack
+            .insert((port_id.clone(), channel_id.clone()), seq);
+        Ok(())
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        commitment: Vec<u8>,
+    ) -> Result<(), ICS4Error> {
+        self.packet_commitments
+            .insert((port_id.clone(), channel_id.clone(), seq), commitment);
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+    ) -> Result<(), ICS4Error> {
+        self.packet_commitments
+            .remove(&(port_id.clone(), channel_id.clone(), seq));
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        receipt: Receipt,
+    ) -> Result<(), ICS4Error> {
+        self.packet_receipts
+            .insert((port_id.clone(), channel_id.clone(), seq), receipt);
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        seq: Sequence,
+        ack_commitment: Vec<u8>,
+    ) -> Result<(), ICS4Error> {
+        self.packet_acknowledgements
+            .insert((port_id.clone(), channel_id.clone(), seq), ack_commitment);
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) {
+        self.channel_ids_counter += 1;
+    }
+}
+
+impl ICS20Reader for MockContext {
+    fn denom_trace(&self, hash: &[u8]) -> Option<DenomTrace> {
+        self.denom_traces.get(hash).cloned()
+    }
+}
+
+impl ICS20Keeper for MockContext {
+    fn store_denom_trace(&mut self, denom_trace: DenomTrace) -> Result<(), ICS20Error> {
+        self.denom_traces.insert(denom_trace.hash(), denom_trace);
+        Ok(())
+    }
+}
+
+impl BankReader for MockContext {
+    fn balance(&self, account: &Id, denom: &str) -> u64 {
+        self.bank.balance(account, denom)
+    }
+}
+
+impl BankKeeper for MockContext {
+    fn send_coins(&mut self, from: &Id, to: &Id, coin: &Coin) -> Result<(), ICS20Error> {
+        self.bank.send_coins(from, to, coin)
+    }
+
+    fn mint_coins(&mut self, account: &Id, coin: &Coin) -> Result<(), ICS20Error> {
+        self.bank.mint_coins(account, coin)
+    }
+
+    fn burn_coins(&mut self, account: &Id, coin: &Coin) -> Result<(), ICS20Error> {
+        self.bank.burn_coins(account, coin)
+    }
 }
 
 impl ClientReader for MockContext {
@@ -348,6 +874,22 @@ impl ClientReader for MockContext {
             None => None,
         }
     }
+
+    fn host_timestamp(&self) -> Timestamp {
+        // The mock host clock follows the time of the latest block.
+        self.history
+            .last()
+            .map(HostBlock::timestamp)
+            .unwrap_or_else(Timestamp::none)
+    }
+
+    fn host_height(&self) -> Height {
+        self.latest_height
+    }
+
+    fn client_counter(&self) -> u64 {
+        self.client_ids_counter
+    }
 }
 
 impl ClientKeeper for MockContext {
@@ -398,11 +940,37 @@ impl ClientKeeper for MockContext {
             .insert(height, consensus_state);
         Ok(())
     }
+
+    fn store_update_time(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        timestamp: Timestamp,
+    ) -> Result<(), ICS2Error> {
+        self.client_processed_times
+            .insert((client_id, height), timestamp);
+        Ok(())
+    }
+
+    fn store_update_height(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_height: Height,
+    ) -> Result<(), ICS2Error> {
+        self.client_processed_heights
+            .insert((client_id, height), host_height);
+        Ok(())
+    }
+
+    fn increase_client_counter(&mut self) {
+        self.client_ids_counter += 1;
+    }
 }
 
 impl ICS18Context for MockContext {
     fn query_latest_height(&self) -> Height {
-        self.host_current_height()
+        self.latest_height
     }
 
     fn query_client_full_state(&self, client_id: &ClientId) -> Option<AnyClientState> {
@@ -411,7 +979,7 @@ impl ICS18Context for MockContext {
     }
 
     fn query_latest_header(&self) -> Option<AnyHeader> {
-        let block_ref = self.host_block(self.host_current_height());
+        let block_ref = self.host_block(self.latest_height);
         block_ref.cloned().map(Into::into)
     }
 
@@ -426,9 +994,16 @@ impl ICS18Context for MockContext {
 
 #[cfg(test)]
 mod tests {
-    use crate::ics24_host::identifier::ChainId;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use crate::ics05_port::context::{PortKeeper, PortReader};
+    use crate::ics24_host::identifier::{ChainId, PortId};
+    use crate::ics26_routing::context::ICS26Context;
+    use crate::ics26_routing::router::Router;
     use crate::mock::context::MockContext;
     use crate::mock::host::HostType;
+    use crate::mock::module::MockModule;
     use crate::Height;
 
     #[test]
@@ -569,4 +1144,41 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_port_binding() {
+        let transfer = PortId::from_str("transfer").unwrap();
+        let other = PortId::from_str("otherport").unwrap();
+
+        let mut ctx = MockContext::default().with_port_capability(transfer.clone());
+        let transfer_cap = ctx.lookup_module_by_port(&transfer).unwrap();
+        assert!(ctx.authenticate(&transfer_cap, &transfer));
+        assert!(ctx.lookup_module_by_port(&other).is_none());
+
+        // A port is bound only once, and its capability does not authenticate any other port.
+        assert!(ctx.bind_port(&transfer).is_err());
+        let other_cap = ctx.bind_port(&other).unwrap();
+        assert_ne!(transfer_cap, other_cap);
+        assert!(!ctx.authenticate(&transfer_cap, &other));
+        assert!(ctx.authenticate(&other_cap, &other));
+    }
+
+    #[test]
+    fn test_module_port_ownership() {
+        let transfer = PortId::from_str("transfer").unwrap();
+        let other = PortId::from_str("otherport").unwrap();
+
+        // Registering an application binds its port, and the application holds the capability.
+        let mut ctx = MockContext::default().with_module(transfer.clone(), Arc::new(MockModule));
+        let transfer_cap = ctx.router().get_capability(&transfer).unwrap();
+        assert_eq!(ctx.lookup_module_by_port(&transfer), Some(transfer_cap));
+
+        // A port bound outside of the router cannot be taken over by an application.
+        ctx.bind_port(&other).unwrap();
+        let mut router = Router::<MockContext>::new();
+        assert!(router
+            .add_route(&mut ctx, other.clone(), Arc::new(MockModule))
+            .is_err());
+        assert!(router.get_capability(&other).is_none());
+    }
 }
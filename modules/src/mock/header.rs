@@ -30,7 +30,9 @@ impl TryFrom<RawMockHeader> for MockHeader {
 
 impl From<MockHeader> for RawMockHeader {
     fn from(value: MockHeader) -> Self {
-        value.into()
+        RawMockHeader {
+            height: Some(value.height().into()),
+        }
     }
 }
 
@@ -5,13 +5,17 @@ use crate::ics07_tendermint::consensus_state::ConsensusState as TMConsensusState
 use crate::ics07_tendermint::header::Header as TMHeader;
 use crate::ics24_host::identifier::ChainId;
 use crate::mock::header::MockHeader;
+use crate::timestamp::Timestamp;
 use crate::Height;
 
-use tendermint::chain::Id as TMChainId;
 use tendermint_testgen::light_block::TMLightBlock;
-use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+use tendermint_testgen::{
+    Commit as TestgenCommit, Generator, LightBlock as TestgenLightBlock,
+    Validator as TestgenValidator,
+};
 
-use std::convert::TryFrom;
+/// The time (in seconds after the Unix epoch) at which the history of every mock chain starts.
+const GENESIS_TIME: u64 = 1_600_000_000;
 
 /// Defines the different types of host chains that a mock context can emulate.
 /// The variants are as follows:
@@ -44,6 +48,18 @@ impl HostBlock {
         }
     }
 
+    /// Returns the time of a block, which the host clock of a mock context follows.
+    pub fn timestamp(&self) -> Timestamp {
+        match self {
+            HostBlock::Mock(header) => Timestamp::from_nanoseconds(
+                Self::tm_block_time(header.height().version_height) * 1_000_000_000,
+            ),
+            HostBlock::SyntheticTendermint(light_block) => {
+                Timestamp::from(light_block.signed_header.header.time)
+            }
+        }
+    }
+
     /// Generates a new block at `height` for the given chain identifier and chain type.
     pub fn generate_block(chain_id: ChainId, chain_type: HostType, height: u64) -> HostBlock {
         match chain_type {
@@ -54,11 +70,61 @@ impl HostBlock {
         }
     }
 
+    /// Generates a Tendermint light block at `height` for the given chain identifier. The header
+    /// and its commit are generated for this chain, so that the commit signatures are valid.
     pub fn generate_tm_block(chain_id: ChainId, height: u64) -> TMLightBlock {
-        let mut block = TestgenLightBlock::new_default(height).generate().unwrap();
-        block.signed_header.header.chain_id = TMChainId::try_from(chain_id.to_string()).unwrap();
+        Self::generate_tm_block_with_time(chain_id, height, Self::tm_block_time(height))
+    }
+
+    /// Similar to `generate_tm_block`, but the block is timestamped `time` seconds after the Unix
+    /// epoch. Generating two blocks at the same height with different times yields conflicting
+    /// blocks, i.e., evidence of misbehaviour.
+    pub fn generate_tm_block_with_time(chain_id: ChainId, height: u64, time: u64) -> TMLightBlock {
+        let mut light_block = TestgenLightBlock::new_default(height);
+        let header = light_block
+            .header
+            .take()
+            .unwrap()
+            .chain_id(chain_id.as_str())
+            .time(time);
+        light_block.commit = Some(TestgenCommit::new(header.clone(), 1));
+        light_block.header = Some(header);
+
+        light_block.generate().unwrap()
+    }
+
+    /// Similar to `generate_tm_block`, but `new_validators` join the default validators at this
+    /// block: the validator set of the block differs from the one its predecessor committed to.
+    pub fn generate_tm_block_with_new_validators(
+        chain_id: ChainId,
+        height: u64,
+        new_validators: &[TestgenValidator],
+    ) -> TMLightBlock {
+        let mut light_block = TestgenLightBlock::new_default(height);
+        let mut validators = light_block.validators.take().unwrap();
+        validators.extend_from_slice(new_validators);
+
+        let header = light_block
+            .header
+            .take()
+            .unwrap()
+            .chain_id(chain_id.as_str())
+            .time(Self::tm_block_time(height))
+            .validators(&validators)
+            .next_validators(&validators);
+        light_block.commit = Some(TestgenCommit::new(header.clone(), 1));
+        light_block.header = Some(header);
+        light_block.validators = Some(validators.clone());
+        light_block.next_validators = Some(validators);
+
+        light_block.generate().unwrap()
+    }
 
-        block
+    /// Blocks are timestamped `height` seconds after the genesis of mock chains, so that block
+    /// times increase with the height. A host thus sees the headers of a chain above its own
+    /// height as coming from the future.
+    pub fn tm_block_time(height: u64) -> u64 {
+        GENESIS_TIME + height
     }
 }
 
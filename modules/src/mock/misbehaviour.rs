@@ -0,0 +1,69 @@
+use std::convert::{TryFrom, TryInto};
+
+use ibc_proto::ibc::mock::Misbehaviour as RawMisbehaviour;
+use tendermint_proto::Protobuf;
+
+use crate::ics02_client::client_def::AnyMisbehaviour;
+use crate::ics02_client::client_type::ClientType;
+use crate::ics02_client::error::{self, Error};
+use crate::ics24_host::identifier::ClientId;
+use crate::mock::header::MockHeader;
+use crate::Height;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockMisbehaviour {
+    pub client_id: ClientId,
+    pub header1: MockHeader,
+    pub header2: MockHeader,
+}
+
+impl crate::ics02_client::misbehaviour::Misbehaviour for MockMisbehaviour {
+    fn client_type(&self) -> ClientType {
+        ClientType::Mock
+    }
+
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        self.header1.height()
+    }
+
+    fn wrap_any(self) -> AnyMisbehaviour {
+        AnyMisbehaviour::Mock(self)
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for MockMisbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for MockMisbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|e| error::Kind::InvalidRawMisbehaviour.context(e))?,
+            header1: raw
+                .header1
+                .ok_or_else(|| error::Kind::InvalidRawMisbehaviour.context("missing header 1"))?
+                .try_into()?,
+            header2: raw
+                .header2
+                .ok_or_else(|| error::Kind::InvalidRawMisbehaviour.context("missing header 2"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<MockMisbehaviour> for RawMisbehaviour {
+    fn from(value: MockMisbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            header1: Some(value.header1.into()),
+            header2: Some(value.header2.into()),
+        }
+    }
+}
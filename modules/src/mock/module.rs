@@ -0,0 +1,34 @@
+//! A mock IBC application, which accepts every channel and every packet.
+
+// Only used in tests (i.e., not covered in `mocks` feature).
+#![allow(dead_code)]
+
+use crate::handler::{HandlerOutput, HandlerResult};
+use crate::ics04_channel::packet::Packet;
+use crate::ics26_routing::error::Error;
+use crate::ics26_routing::module::Module;
+
+/// The acknowledgement that the mock application writes for every packet it receives.
+pub const MOCK_ACKNOWLEDGEMENT: &[u8] = b"mock acknowledgement";
+
+#[derive(Clone, Debug, Default)]
+pub struct MockModule;
+
+impl<Ctx> Module<Ctx> for MockModule {
+    fn on_recv_packet(&self, _ctx: &mut Ctx, _packet: &Packet) -> HandlerResult<Vec<u8>, Error> {
+        Ok(HandlerOutput::builder().with_result(MOCK_ACKNOWLEDGEMENT.to_vec()))
+    }
+
+    fn on_acknowledgement_packet(
+        &self,
+        _ctx: &mut Ctx,
+        _packet: &Packet,
+        _acknowledgement: &[u8],
+    ) -> HandlerResult<(), Error> {
+        Ok(HandlerOutput::builder().with_result(()))
+    }
+
+    fn on_timeout_packet(&self, _ctx: &mut Ctx, _packet: &Packet) -> HandlerResult<(), Error> {
+        Ok(HandlerOutput::builder().with_result(()))
+    }
+}
@@ -0,0 +1,179 @@
+//! The timestamps of IBC, e.g., the timeout timestamps of packets or the timestamps of consensus
+//! states, which count nanoseconds since the Unix epoch.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use std::time::{Duration, UNIX_EPOCH};
+
+use chrono::{TimeZone, Utc};
+use serde_derive::{Deserialize, Serialize};
+use tendermint::time::Time;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// A timestamp with nanosecond precision. The value zero stands for no timestamp at all, as on
+/// the wire: for instance, a packet without a timeout timestamp, or the consensus state of a
+/// client which does not track the time of its counterparty chain.
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    /// The absence of a timestamp, e.g., a packet which never times out based on time.
+    pub fn none() -> Self {
+        Timestamp(0)
+    }
+
+    /// The current time of the local clock.
+    pub fn now() -> Self {
+        Time::now().into()
+    }
+
+    pub fn from_nanoseconds(nanoseconds: u64) -> Self {
+        Timestamp(nanoseconds)
+    }
+
+    /// The number of nanoseconds since the Unix epoch, or zero for no timestamp.
+    pub fn nanoseconds(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Tells whether this timeout timestamp has passed at time `now`. Timeouts expire once `now`
+    /// reaches them. Without either timestamp, nothing expires.
+    pub fn has_expired_at(&self, now: &Timestamp) -> bool {
+        !self.is_none() && !now.is_none() && now >= self
+    }
+
+    /// The timestamp `duration` after this one, saturating at the latest representable timestamp.
+    pub fn add(&self, duration: Duration) -> Timestamp {
+        let nanoseconds = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        Timestamp(self.0.saturating_add(nanoseconds))
+    }
+
+    /// Converts this timestamp into a Tendermint time, unless there is no timestamp.
+    pub fn into_tm_time(self) -> Option<Time> {
+        if self.is_none() {
+            return None;
+        }
+
+        let seconds = (self.0 / NANOS_PER_SECOND) as i64;
+        let nanoseconds = (self.0 % NANOS_PER_SECOND) as u32;
+        Some(Utc.timestamp(seconds, nanoseconds).into())
+    }
+}
+
+/// Displays the number of nanoseconds, as in the attributes of the packet events.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Parses the number of nanoseconds, e.g., from the attributes of the packet events.
+impl FromStr for Timestamp {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Timestamp(s.parse()?))
+    }
+}
+
+/// Converts a Tendermint time, e.g., the time of a block header. Times before the Unix epoch, or
+/// too far in the future for nanoseconds to fit in 64 bits, have no timestamp.
+impl From<Time> for Timestamp {
+    fn from(time: Time) -> Self {
+        let nanoseconds = time
+            .to_system_time()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .and_then(|d| u64::try_from(d.as_nanos()).ok())
+            .unwrap_or(0);
+
+        Timestamp(nanoseconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timestamp::Timestamp;
+
+    #[test]
+    fn timestamp_expiry() {
+        let now = Timestamp::now();
+        let later = Timestamp::from_nanoseconds(now.nanoseconds() + 1);
+
+        struct Test {
+            name: String,
+            timeout: Timestamp,
+            now: Timestamp,
+            expired: bool,
+        }
+
+        let tests: Vec<Test> = vec![
+            Test {
+                name: "A timeout in the future has not expired".to_string(),
+                timeout: later,
+                now,
+                expired: false,
+            },
+            Test {
+                name: "A timeout expires once reached".to_string(),
+                timeout: now,
+                now,
+                expired: true,
+            },
+            Test {
+                name: "A timeout in the past has expired".to_string(),
+                timeout: now,
+                now: later,
+                expired: true,
+            },
+            Test {
+                name: "No timeout never expires".to_string(),
+                timeout: Timestamp::none(),
+                now: later,
+                expired: false,
+            },
+            Test {
+                name: "Nothing expires without the current time".to_string(),
+                timeout: now,
+                now: Timestamp::none(),
+                expired: false,
+            },
+        ];
+
+        for test in tests {
+            assert_eq!(
+                test.timeout.has_expired_at(&test.now),
+                test.expired,
+                "timestamp_expiry: test {} failed",
+                test.name
+            );
+        }
+    }
+
+    #[test]
+    fn tendermint_time_round_trip() {
+        let timestamp = Timestamp::from_nanoseconds(1_607_017_389_123_456_789);
+        let time = timestamp.into_tm_time().unwrap();
+        assert_eq!(Timestamp::from(time), timestamp);
+
+        let elapsed = time
+            .to_system_time()
+            .unwrap()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap();
+        assert_eq!(elapsed, Duration::new(1_607_017_389, 123_456_789));
+
+        assert!(Timestamp::none().into_tm_time().is_none());
+    }
+}
@@ -12,6 +12,10 @@ pub struct MsgConnectionOpenInit {
     pub version: std::string::String,
     #[prost(string, tag = "5")]
     pub signer: std::string::String,
+    /// delay period that must pass before a consensus state can be used for
+    /// packet-verification, in nanoseconds.
+    #[prost(uint64, tag = "6")]
+    pub delay_period: u64,
 }
 /// MsgConnectionOpenInitResponse defines the Msg/ConnectionOpenInit response type.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -48,6 +52,10 @@ pub struct MsgConnectionOpenTry {
     pub consensus_height: ::std::option::Option<super::super::client::v1::Height>,
     #[prost(string, tag = "12")]
     pub signer: std::string::String,
+    /// delay period that must pass before a consensus state can be used for
+    /// packet-verification, in nanoseconds.
+    #[prost(uint64, tag = "13")]
+    pub delay_period: u64,
 }
 /// MsgConnectionOpenTryResponse defines the Msg/ConnectionOpenTry response type.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -122,6 +130,10 @@ pub struct ConnectionEnd {
     /// counterparty chain associated with this connection.
     #[prost(message, optional, tag = "4")]
     pub counterparty: ::std::option::Option<Counterparty>,
+    /// delay period that must pass before a consensus state can be used for
+    /// packet-verification, in nanoseconds.
+    #[prost(uint64, tag = "5")]
+    pub delay_period: u64,
 }
 /// IdentifiedConnection defines a connection with additional connection
 /// identifier field.
@@ -143,6 +155,10 @@ pub struct IdentifiedConnection {
     /// counterparty chain associated with this connection.
     #[prost(message, optional, tag = "5")]
     pub counterparty: ::std::option::Option<Counterparty>,
+    /// delay period that must pass before a consensus state can be used for
+    /// packet-verification, in nanoseconds.
+    #[prost(uint64, tag = "6")]
+    pub delay_period: u64,
 }
 /// Counterparty defines the counterparty chain associated with a connection end.
 #[derive(Clone, PartialEq, ::prost::Message)]
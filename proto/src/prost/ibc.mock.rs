@@ -13,3 +13,12 @@ pub struct ConsensusState {
     #[prost(message, optional, tag = "1")]
     pub header: ::std::option::Option<Header>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Misbehaviour {
+    #[prost(string, tag = "1")]
+    pub client_id: std::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub header1: ::std::option::Option<Header>,
+    #[prost(message, optional, tag = "3")]
+    pub header2: ::std::option::Option<Header>,
+}
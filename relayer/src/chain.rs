@@ -19,7 +19,7 @@ use ibc::ics02_client::header::Header;
 use ibc::ics02_client::state::{ClientState, ConsensusState};
 use ibc::ics03_connection::connection::ConnectionEnd;
 
-use ibc::ics03_connection::version::get_compatible_versions;
+use ibc::ics03_connection::version::{get_compatible_versions, Version};
 use ibc::ics23_commitment::commitment::{CommitmentPrefix, CommitmentProof};
 use ibc::ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId};
 use ibc::ics24_host::Path;
@@ -118,7 +118,7 @@ pub trait Chain {
         ))
     }
 
-    fn query_compatible_versions(&self) -> Result<Vec<String>, Error> {
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
         // TODO - do a real chain query
         Ok(get_compatible_versions())
     }
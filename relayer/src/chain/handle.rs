@@ -4,7 +4,7 @@ use crossbeam_channel as channel;
 
 use ibc::{
     ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader},
-    ics03_connection::connection::ConnectionEnd,
+    ics03_connection::{connection::ConnectionEnd, version::Version},
     ics04_channel::channel::ChannelEnd,
     ics24_host::identifier::{ChannelId, ConnectionId, PortId},
     proofs::Proofs,
@@ -131,7 +131,7 @@ pub enum HandleInput {
     },
 
     QueryCompatibleVersions {
-        reply_to: ReplyTo<Vec<String>>,
+        reply_to: ReplyTo<Vec<Version>>,
     },
 
     QueryConnection {
@@ -212,7 +212,7 @@ pub trait ChainHandle: Clone + Send + Sync {
 
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
-    fn query_compatible_versions(&self) -> Result<Vec<String>, Error>;
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error>;
 
     fn query_connection(
         &self,
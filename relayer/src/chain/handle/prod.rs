@@ -4,7 +4,7 @@ use crossbeam_channel as channel;
 
 use ibc::{
     ics02_client::client_def::{AnyClientState, AnyConsensusState, AnyHeader},
-    ics03_connection::connection::ConnectionEnd,
+    ics03_connection::{connection::ConnectionEnd, version::Version},
     ics04_channel::channel::ChannelEnd,
     ics23_commitment::commitment::CommitmentPrefix,
     ics23_commitment::merkle::MerkleProof,
@@ -145,7 +145,7 @@ impl ChainHandle for ProdChainHandle {
         self.send(|reply_to| HandleInput::QueryCommitmentPrefix { reply_to })
     }
 
-    fn query_compatible_versions(&self) -> Result<Vec<String>, Error> {
+    fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
         self.send(|reply_to| HandleInput::QueryCompatibleVersions { reply_to })
     }
 
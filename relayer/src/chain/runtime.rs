@@ -10,7 +10,7 @@ use ibc::{
         header::Header,
         state::{ClientState, ConsensusState},
     },
-    ics03_connection::connection::ConnectionEnd,
+    ics03_connection::{connection::ConnectionEnd, version::Version},
     ics04_channel::channel::ChannelEnd,
     ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
     ics24_host::identifier::ChannelId,
@@ -498,7 +498,7 @@ impl<C: Chain> ChainRuntime<C> {
         Ok(())
     }
 
-    fn query_compatible_versions(&self, reply_to: ReplyTo<Vec<String>>) -> Result<(), Error> {
+    fn query_compatible_versions(&self, reply_to: ReplyTo<Vec<Version>>) -> Result<(), Error> {
         let versions = self.chain.query_compatible_versions();
 
         reply_to
@@ -48,7 +48,6 @@ pub fn build_create_client(
     // Build client create message with the data from source chain at latest height.
     let latest_height = src_chain.query_latest_height()?;
     Ok(MsgCreateAnyClient::new(
-        dst_client_id,
         src_chain.build_client_state(latest_height)?.wrap_any(),
         src_chain.build_consensus_state(latest_height)?.wrap_any(),
         signer,
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use prost_types::Any;
 
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
@@ -70,9 +72,9 @@ pub fn build_conn_init(
     // Build the domain type message
     let new_msg = MsgConnectionOpenInit {
         client_id: opts.dst_client_id.clone(),
-        connection_id: opts.dst_connection_id.clone(),
         counterparty,
         version: dst_chain.query_compatible_versions()?[0].clone(),
+        delay_period: Duration::from_secs(0),
         signer,
     };
 
@@ -156,6 +158,7 @@ fn validated_expected_connection(
         opts.dst_client_id.clone(),
         counterparty,
         src_chain.query_compatible_versions()?,
+        Duration::from_secs(0),
     )
     .unwrap();
 
@@ -269,6 +272,7 @@ pub fn build_conn_try(
         counterparty: dst_expected_connection.counterparty(),
         counterparty_versions,
         proofs,
+        delay_period: src_connection.delay_period(),
         signer,
     };
 